@@ -0,0 +1,475 @@
+use base64::{engine::general_purpose, Engine as _};
+use bech32::{ToBase32, Variant};
+use cosmwasm_std::{Env, StdError, Uint128};
+use ripemd::Ripemd160;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::error::ContractError;
+use crate::types::{AttestationVersion, Config, KeyType, SignerChange, TokenMetadata};
+
+/// The domain tag that prefixes every v2 attestation to separate it from
+/// messages signed for any other purpose
+pub const ATTESTATION_DOMAIN: &str = "asteroid-bridge/v2";
+
+/// The message type tag for linking a CFT-20 token
+pub const LINK_TOKEN_MESSAGE_TYPE: &str = "link_token";
+
+/// The message type tag for receiving CFT-20 tokens from the Hub
+pub const RECEIVE_MESSAGE_TYPE: &str = "receive";
+
+/// The message type tag for approving a change to the signer set
+pub const SIGNER_CHANGE_MESSAGE_TYPE: &str = "signer_change";
+
+/// The message type tag for rotating the key of a signer
+pub const ROTATE_SIGNER_KEY_MESSAGE_TYPE: &str = "rotate_signer_key";
+
+/// The message type tag for updating the metadata of a linked token
+pub const UPDATE_TOKEN_METADATA_MESSAGE_TYPE: &str = "update_token_metadata";
+
+/// The message type tag for approving the slashing of a signer
+pub const SLASH_SIGNER_MESSAGE_TYPE: &str = "slash_signer";
+
+/// Holds the fields signers attest to for a single message
+///
+/// The attestation can be encoded in two versions
+/// * **V1** The plain concatenation of the field values, kept for compatibility
+/// * **V2** The sha256 digest of the domain tag, the message type and every
+///   field name and value, each prefixed with its length as a 4 byte
+///   big-endian integer
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attestation {
+    /// The type of the message being attested
+    message_type: &'static str,
+    /// The attested fields as (name, value) in the order they are signed
+    fields: Vec<(&'static str, String)>,
+    /// The fields left out of the v1 encoding, which keeps the format signers
+    /// used before these fields were attested
+    v2_only_fields: &'static [&'static str],
+}
+
+impl Attestation {
+    /// Build the attestation to link a CFT-20 token
+    /// Every metadata field is attested as it ends up in the denom metadata.
+    /// The name and image are only attested in v2, the v1 format is kept as
+    /// {source_chain_id}{ticker}{decimals}{chain_id}{contract_address} since
+    /// appending them without separators would let them be re-split
+    pub fn link_token(env: &Env, source_chain_id: &str, token: &TokenMetadata) -> Self {
+        Attestation {
+            message_type: LINK_TOKEN_MESSAGE_TYPE,
+            fields: vec![
+                ("source_chain_id", source_chain_id.to_string()),
+                ("ticker", token.ticker.clone()),
+                ("name", token.name.clone()),
+                ("image_url", token.image_url.clone()),
+                ("decimals", token.decimals.to_string()),
+                ("chain_id", env.block.chain_id.clone()),
+                ("contract_address", env.contract.address.to_string()),
+            ],
+            v2_only_fields: &["name", "image_url"],
+        }
+    }
+
+    /// Build the attestation to update the metadata of a linked token
+    /// The nonce of the token binds the attestation to a single update
+    pub fn update_token_metadata(
+        env: &Env,
+        source_chain_id: &str,
+        token: &TokenMetadata,
+        nonce: u64,
+    ) -> Self {
+        Attestation {
+            message_type: UPDATE_TOKEN_METADATA_MESSAGE_TYPE,
+            fields: vec![
+                ("source_chain_id", source_chain_id.to_string()),
+                ("ticker", token.ticker.clone()),
+                ("name", token.name.clone()),
+                ("image_url", token.image_url.clone()),
+                ("decimals", token.decimals.to_string()),
+                ("nonce", nonce.to_string()),
+                ("chain_id", env.block.chain_id.clone()),
+                ("contract_address", env.contract.address.to_string()),
+            ],
+            v2_only_fields: &[],
+        }
+    }
+
+    /// Build the attestation to receive CFT-20 tokens from the Hub
+    /// The v1 format is {source_chain_id}{transaction_hash}{ticker}{amount}{chain_id}{contract_address}{destination_addr}
+    pub fn receive(
+        env: &Env,
+        source_chain_id: &str,
+        transaction_hash: &str,
+        ticker: &str,
+        amount: Uint128,
+        destination_addr: &str,
+    ) -> Self {
+        Attestation {
+            message_type: RECEIVE_MESSAGE_TYPE,
+            fields: vec![
+                ("source_chain_id", source_chain_id.to_string()),
+                ("transaction_hash", transaction_hash.to_string()),
+                ("ticker", ticker.to_string()),
+                ("amount", amount.to_string()),
+                ("chain_id", env.block.chain_id.clone()),
+                ("contract_address", env.contract.address.to_string()),
+                ("destination_addr", destination_addr.to_string()),
+            ],
+            v2_only_fields: &[],
+        }
+    }
+
+    /// Build the attestation to approve a signer change proposal
+    /// The change is attested as its JSON encoding and the proposal is bound
+    /// to the signer epoch it was made in
+    pub fn signer_change(
+        env: &Env,
+        proposal_id: u64,
+        signer_epoch: u64,
+        change: &SignerChange,
+    ) -> Result<Self, StdError> {
+        Ok(Attestation {
+            message_type: SIGNER_CHANGE_MESSAGE_TYPE,
+            fields: vec![
+                ("proposal_id", proposal_id.to_string()),
+                ("signer_epoch", signer_epoch.to_string()),
+                (
+                    "change",
+                    serde_json_wasm::to_string(change)
+                        .map_err(|e| StdError::serialize_err("SignerChange", e))?,
+                ),
+                ("chain_id", env.block.chain_id.clone()),
+                ("contract_address", env.contract.address.to_string()),
+            ],
+            v2_only_fields: &[],
+        })
+    }
+
+    /// Build the attestation the old key of a signer signs to rotate to a new key
+    pub fn rotate_signer_key(
+        env: &Env,
+        old_public_key_base64: &str,
+        new_public_key_base64: &str,
+        new_key_type: KeyType,
+        nonce: u64,
+    ) -> Self {
+        Attestation {
+            message_type: ROTATE_SIGNER_KEY_MESSAGE_TYPE,
+            fields: vec![
+                ("old_public_key", old_public_key_base64.to_string()),
+                ("new_public_key", new_public_key_base64.to_string()),
+                ("new_key_type", new_key_type.as_str().to_string()),
+                ("nonce", nonce.to_string()),
+                ("chain_id", env.block.chain_id.clone()),
+                ("contract_address", env.contract.address.to_string()),
+            ],
+            v2_only_fields: &[],
+        }
+    }
+
+    /// Build the attestation the signers sign to approve slashing a signer
+    /// for the evidence of a transaction
+    pub fn slash_signer(env: &Env, name: &str, transaction_hash: &str) -> Self {
+        Attestation {
+            message_type: SLASH_SIGNER_MESSAGE_TYPE,
+            fields: vec![
+                ("name", name.to_string()),
+                ("transaction_hash", transaction_hash.to_string()),
+                ("chain_id", env.block.chain_id.clone()),
+                ("contract_address", env.contract.address.to_string()),
+            ],
+            v2_only_fields: &[],
+        }
+    }
+
+    /// Append an optional field to the attestation, the field is only
+    /// attested when a value is present
+    pub fn with_optional<T: ToString>(mut self, name: &'static str, value: Option<T>) -> Self {
+        if let Some(value) = value {
+            self.fields.push((name, value.to_string()));
+        }
+        self
+    }
+
+    /// Encode the attestation into the bytes the signers sign
+    pub fn encode(&self, version: AttestationVersion) -> Vec<u8> {
+        match version {
+            AttestationVersion::V1 => self
+                .fields
+                .iter()
+                .filter(|(name, _)| !self.v2_only_fields.contains(name))
+                .map(|(_, value)| value.as_str())
+                .collect::<String>()
+                .into_bytes(),
+            AttestationVersion::V2 => {
+                let mut preimage = vec![];
+                push_length_prefixed(&mut preimage, ATTESTATION_DOMAIN.as_bytes());
+                push_length_prefixed(&mut preimage, self.message_type.as_bytes());
+                for (name, value) in &self.fields {
+                    push_length_prefixed(&mut preimage, name.as_bytes());
+                    push_length_prefixed(&mut preimage, value.as_bytes());
+                }
+                Sha256::digest(preimage).to_vec()
+            }
+        }
+    }
+}
+
+/// Append the bytes prefixed with their length as a 4 byte big-endian integer
+fn push_length_prefixed(buffer: &mut Vec<u8>, bytes: &[u8]) {
+    buffer.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+    buffer.extend_from_slice(bytes);
+}
+
+/// The amino type of the ADR-036 message wrapping arbitrary signed data
+pub const ADR036_MSG_TYPE: &str = "sign/MsgSignData";
+
+/// The bech32 prefix of the source chain accounts signing ADR-036 sign-docs
+pub const ADR036_ADDRESS_PREFIX: &str = "cosmos";
+
+/// The amino JSON sign-doc of an ADR-036 off-chain signature
+/// The fields are declared in alphabetical order as amino JSON requires
+/// sorted keys
+#[derive(Serialize)]
+struct Adr036SignDoc<'a> {
+    account_number: &'static str,
+    chain_id: &'static str,
+    fee: Adr036Fee,
+    memo: &'static str,
+    msgs: Vec<Adr036Msg<'a>>,
+    sequence: &'static str,
+}
+
+#[derive(Serialize)]
+struct Adr036Fee {
+    amount: Vec<String>,
+    gas: &'static str,
+}
+
+#[derive(Serialize)]
+struct Adr036Msg<'a> {
+    #[serde(rename = "type")]
+    msg_type: &'static str,
+    value: Adr036MsgValue<'a>,
+}
+
+#[derive(Serialize)]
+struct Adr036MsgValue<'a> {
+    data: String,
+    signer: &'a str,
+}
+
+/// Build the ADR-036 amino JSON sign-doc wallets like Keplr or a Ledger
+/// produce when signing the attestation bytes as arbitrary data
+///
+/// The signature of the sign-doc is made over its sha256 digest, the same as
+/// any other secp256k1 signature verified by the contract
+pub fn adr036_sign_doc(signer_address: &str, data: &[u8]) -> Result<Vec<u8>, StdError> {
+    let sign_doc = Adr036SignDoc {
+        account_number: "0",
+        chain_id: "",
+        fee: Adr036Fee {
+            amount: vec![],
+            gas: "0",
+        },
+        memo: "",
+        msgs: vec![Adr036Msg {
+            msg_type: ADR036_MSG_TYPE,
+            value: Adr036MsgValue {
+                data: general_purpose::STANDARD.encode(data),
+                signer: signer_address,
+            },
+        }],
+        sequence: "0",
+    };
+
+    serde_json_wasm::to_vec(&sign_doc).map_err(|e| StdError::serialize_err("Adr036SignDoc", e))
+}
+
+/// Derive the source chain address of a secp256k1 public key, the signer of
+/// the sign-docs made with that key
+/// The address is the bech32 encoding of ripemd160(sha256(public key))
+pub fn adr036_signer_address(public_key: &[u8]) -> Result<String, StdError> {
+    let address = Ripemd160::digest(Sha256::digest(public_key));
+    bech32::encode(ADR036_ADDRESS_PREFIX, address.to_base32(), Variant::Bech32)
+        .map_err(|e| StdError::generic_err(format!("Invalid signer address: {}", e)))
+}
+
+/// Resolve the attestation version requested in a message and ensure it is
+/// still accepted by the contract
+pub fn resolve_attestation_version(
+    config: &Config,
+    version: Option<AttestationVersion>,
+) -> Result<AttestationVersion, ContractError> {
+    let version = version.unwrap_or_default();
+    if version == AttestationVersion::V1 && !config.v1_attestations_enabled {
+        return Err(ContractError::AttestationVersionDisabled {});
+    }
+    Ok(version)
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    use cosmwasm_std::testing::mock_env;
+
+    #[test]
+    fn test_attestation_encoding() {
+        let env = mock_env();
+
+        let attestation = Attestation::receive(
+            &env,
+            "cosmoshub-4",
+            "TXHASH",
+            "TICKER",
+            Uint128::from(1000u64),
+            "neutron1destination",
+        );
+
+        // The v1 encoding is the plain concatenation of the fields
+        assert_eq!(
+            attestation.encode(AttestationVersion::V1),
+            format!(
+                "cosmoshub-4TXHASHTICKER1000{}{}neutron1destination",
+                env.block.chain_id, env.contract.address
+            )
+            .into_bytes()
+        );
+
+        // The v2 encoding is a sha256 digest
+        assert_eq!(attestation.encode(AttestationVersion::V2).len(), 32);
+
+        // Different field splits produce the same v1 bytes but not the same v2 bytes
+        let shifted = Attestation::receive(
+            &env,
+            "cosmoshub-4",
+            "TXHASHT",
+            "ICKER",
+            Uint128::from(1000u64),
+            "neutron1destination",
+        );
+        assert_eq!(
+            attestation.encode(AttestationVersion::V1),
+            shifted.encode(AttestationVersion::V1)
+        );
+        assert_ne!(
+            attestation.encode(AttestationVersion::V2),
+            shifted.encode(AttestationVersion::V2)
+        );
+
+        // The message type separates the domains of the attestations
+        let mut other_type = attestation.clone();
+        other_type.message_type = LINK_TOKEN_MESSAGE_TYPE;
+        assert_ne!(
+            attestation.encode(AttestationVersion::V2),
+            other_type.encode(AttestationVersion::V2)
+        );
+
+        // Optional fields are only attested when present
+        assert_eq!(
+            attestation
+                .clone()
+                .with_optional::<u64>("signer_epoch", None),
+            attestation
+        );
+        assert_ne!(
+            attestation
+                .clone()
+                .with_optional("signer_epoch", Some(1u64))
+                .encode(AttestationVersion::V2),
+            attestation.encode(AttestationVersion::V2)
+        );
+    }
+
+    #[test]
+    fn test_link_token_attestation() {
+        let env = mock_env();
+        let token = TokenMetadata {
+            ticker: "TICKER".to_string(),
+            name: "Token".to_string(),
+            image_url: "https://example.com".to_string(),
+            decimals: 6,
+        };
+        let attestation = Attestation::link_token(&env, "cosmoshub-4", &token);
+
+        // The v1 encoding is the legacy format without the name and image
+        assert_eq!(
+            attestation.encode(AttestationVersion::V1),
+            format!(
+                "cosmoshub-4TICKER6{}{}",
+                env.block.chain_id, env.contract.address
+            )
+            .into_bytes()
+        );
+
+        // The name and the image are attested along with the ticker in v2
+        for tampered in [
+            TokenMetadata {
+                name: "Fake".to_string(),
+                ..token.clone()
+            },
+            TokenMetadata {
+                image_url: "https://fake.com".to_string(),
+                ..token.clone()
+            },
+        ] {
+            let tampered = Attestation::link_token(&env, "cosmoshub-4", &tampered);
+            assert_ne!(
+                tampered.encode(AttestationVersion::V2),
+                attestation.encode(AttestationVersion::V2)
+            );
+        }
+
+        // Moving the boundary between the ticker and the name, or between
+        // the name and the image, attests another token in both versions
+        for resplit in [
+            TokenMetadata {
+                ticker: "TICKERT".to_string(),
+                name: "oken".to_string(),
+                ..token.clone()
+            },
+            TokenMetadata {
+                name: "Tokenhttps://".to_string(),
+                image_url: "example.com".to_string(),
+                ..token.clone()
+            },
+        ] {
+            let resplit_attestation = Attestation::link_token(&env, "cosmoshub-4", &resplit);
+            assert_ne!(
+                resplit_attestation.encode(AttestationVersion::V2),
+                attestation.encode(AttestationVersion::V2)
+            );
+            if resplit.ticker != token.ticker {
+                assert_ne!(
+                    resplit_attestation.encode(AttestationVersion::V1),
+                    attestation.encode(AttestationVersion::V1)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_adr036_sign_doc() {
+        // The sign-doc must match what wallets produce byte for byte
+        let sign_doc = adr036_sign_doc("cosmos1signer", b"attestation").unwrap();
+        assert_eq!(
+            String::from_utf8(sign_doc).unwrap(),
+            "{\"account_number\":\"0\",\"chain_id\":\"\",\"fee\":{\"amount\":[],\"gas\":\"0\"},\
+            \"memo\":\"\",\"msgs\":[{\"type\":\"sign/MsgSignData\",\"value\":\
+            {\"data\":\"YXR0ZXN0YXRpb24=\",\"signer\":\"cosmos1signer\"}}],\"sequence\":\"0\"}"
+        );
+    }
+
+    #[test]
+    fn test_adr036_signer_address() {
+        // The address a Cosmos Hub wallet shows for the key
+        let public_key = general_purpose::STANDARD
+            .decode("AtQaCqFnshaZQp6rIkvAPyzThvCvXSDO+9AzbxVErqJP")
+            .unwrap();
+        assert_eq!(
+            adr036_signer_address(&public_key).unwrap(),
+            "cosmos1h806c7khnvmjlywdrkdgk2vrayy2mmvf9rxk2r"
+        );
+    }
+}
@@ -1,19 +1,54 @@
-use cosmwasm_std::{entry_point, DepsMut, Env, MessageInfo, Response};
-use cw2::set_contract_version;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    entry_point, Addr, Decimal, DepsMut, Env, MessageInfo, Order, Response, StdResult, Uint128,
+};
+use cw2::{get_contract_version, set_contract_version};
+use cw_storage_plus::{Item, Map};
+use semver::Version;
 
 use neutron_sdk::bindings::msg::NeutronMsg;
 use neutron_sdk::bindings::query::NeutronQuery;
+use osmosis_std::types::cosmos::bank::v1beta1::BankQuerier;
 
 use crate::error::ContractError;
 use crate::helpers::validate_channel;
 use crate::msg::{InstantiateMsg, MigrateMsg};
-use crate::state::CONFIG;
-use crate::types::{Config, MAX_IBC_TIMEOUT_SECONDS, MIN_IBC_TIMEOUT_SECONDS};
+use crate::state::{
+    signers, tokens, CONFIG, NEXT_ADMIN_ACTION_ID, NEXT_SIGNER_CHANGE_ID, SIGNER_EPOCH,
+};
+use crate::types::{
+    Config, KeyType, SignerInfo, SignerStatus, SignerThreshold, TokenInfo, TokenMetadata,
+    TokenStatus, DEFAULT_PENDING_RECEIVE_MAX_AGE_SECONDS, DEFAULT_SIGNER_EPOCH_GRACE_SECONDS,
+    DEFAULT_SIGNER_WEIGHT, DEFAULT_UNBONDING_PERIOD_SECONDS, MAX_IBC_TIMEOUT_SECONDS,
+    MIN_IBC_TIMEOUT_SECONDS,
+};
 
 /// Contract name that is used for migration
 const CONTRACT_NAME: &str = "asteroid-bridge";
 /// Contract version that is used for migration
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// The first version storing the signer and token registries, instances of
+/// earlier versions are migrated from the legacy layout
+const REGISTRY_VERSION: &str = "1.1.0";
+
+/// The config before the signer policies were added
+#[cw_serde]
+struct LegacyConfig {
+    owner: Addr,
+    bridge_chain_id: String,
+    bridge_ibc_channel: String,
+    ibc_timeout_seconds: u64,
+}
+
+/// The config as stored before the migration
+const LEGACY_CONFIG: Item<LegacyConfig> = Item::new("config");
+/// The public key -> name signers, replaced by the signer registry
+const LEGACY_SIGNERS: Map<&[u8], String> = Map::new("signers");
+/// The CFT-20 ticker -> TokenFactory denom mapping and its reverse, replaced
+/// by the token registry
+const LEGACY_TOKEN_MAPPING: Map<&str, String> = Map::new("token_mapping");
+/// The disabled tickers and denoms, replaced by the status in the token registry
+const LEGACY_DISABLED_TOKENS: Map<&str, bool> = Map::new("disabled_tokens");
 
 /// Instantiates the bridge contract, storing the config.
 /// Returns a `Response` object on successful execution or a `ContractError` on failure.
@@ -62,8 +97,19 @@ pub fn instantiate(
         bridge_chain_id: msg.bridge_chain_id.clone(),
         bridge_ibc_channel: msg.bridge_ibc_channel.clone(),
         ibc_timeout_seconds: msg.ibc_timeout_seconds,
+        signer_threshold: SignerThreshold::supermajority(),
+        signer_epoch_grace_seconds: DEFAULT_SIGNER_EPOCH_GRACE_SECONDS,
+        v1_attestations_enabled: true,
+        owner_signer_management: true,
+        admin_action_delay_seconds: 0,
+        pending_receive_max_age_seconds: DEFAULT_PENDING_RECEIVE_MAX_AGE_SECONDS,
+        min_signer_bond: Uint128::zero(),
+        unbonding_period_seconds: DEFAULT_UNBONDING_PERIOD_SECONDS,
+        treasury: None,
+        reward_fee_rate: Decimal::zero(),
     };
     CONFIG.save(deps.storage, &config)?;
+    SIGNER_EPOCH.save(deps.storage, &0)?;
 
     Ok(Response::default()
         .add_attribute("action", "instantiate")
@@ -72,7 +118,408 @@ pub fn instantiate(
 }
 
 /// Migrates the contract to a new version
+/// Instances from before the registries get the defaults of the new settings
+/// in their config, their signers are moved to the signer registry and their
+/// linked tokens are moved from the ticker <> denom mapping to the token registry
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    Err(ContractError::MigrationError {})
+pub fn migrate(mut deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    let version = get_contract_version(deps.storage)?;
+    if version.contract != CONTRACT_NAME {
+        return Err(ContractError::MigrationError {});
+    }
+
+    // The contract can't be migrated to an older version
+    let previous_version = parse_version(&version.version)?;
+    if previous_version > parse_version(CONTRACT_VERSION)? {
+        return Err(ContractError::MigrationError {});
+    }
+
+    let mut response = Response::default()
+        .add_attribute("action", "migrate")
+        .add_attribute("previous_version", version.version);
+    if previous_version < parse_version(REGISTRY_VERSION)? {
+        let (migrated_signers, migrated_tokens) = migrate_legacy_storage(deps.branch(), &env, msg)?;
+        response = response
+            .add_attribute("migrated_signers", migrated_signers.to_string())
+            .add_attribute("migrated_tokens", migrated_tokens.to_string());
+    }
+
+    // The counters added since start at 0, the ones already in use are kept
+    for counter in [SIGNER_EPOCH, NEXT_SIGNER_CHANGE_ID, NEXT_ADMIN_ACTION_ID] {
+        if counter.may_load(deps.storage)?.is_none() {
+            counter.save(deps.storage, &0)?;
+        }
+    }
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(response)
+}
+
+/// Parse a contract version as stored by cw2
+fn parse_version(version: &str) -> Result<Version, ContractError> {
+    Version::parse(version).map_err(|_| ContractError::MigrationError {})
+}
+
+/// Move the storage of an instance from before the registries to the current
+/// layout, returning the number of signers and tokens migrated
+fn migrate_legacy_storage(
+    deps: DepsMut,
+    env: &Env,
+    msg: MigrateMsg,
+) -> Result<(usize, u64), ContractError> {
+    // The settings added since keep the values a new instance starts with
+    let legacy_config = LEGACY_CONFIG.load(deps.storage)?;
+    let config = Config {
+        owner: legacy_config.owner,
+        bridge_chain_id: msg.bridge_chain_id.unwrap_or(legacy_config.bridge_chain_id),
+        bridge_ibc_channel: legacy_config.bridge_ibc_channel,
+        ibc_timeout_seconds: legacy_config.ibc_timeout_seconds,
+        signer_threshold: SignerThreshold::supermajority(),
+        signer_epoch_grace_seconds: DEFAULT_SIGNER_EPOCH_GRACE_SECONDS,
+        v1_attestations_enabled: true,
+        owner_signer_management: true,
+        admin_action_delay_seconds: 0,
+        pending_receive_max_age_seconds: DEFAULT_PENDING_RECEIVE_MAX_AGE_SECONDS,
+        min_signer_bond: Uint128::zero(),
+        unbonding_period_seconds: DEFAULT_UNBONDING_PERIOD_SECONDS,
+        treasury: None,
+        reward_fee_rate: Decimal::zero(),
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    // The signers could only hold ed25519 keys and all counted the same
+    // The legacy entries share the namespace of the registry, so they are
+    // removed before the registry loads them as signer info
+    let legacy_signers = LEGACY_SIGNERS
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (public_key, name) in &legacy_signers {
+        LEGACY_SIGNERS.remove(deps.storage, public_key);
+        signers().save(
+            deps.storage,
+            public_key,
+            &SignerInfo {
+                name: name.clone(),
+                key_type: KeyType::Ed25519,
+                weight: DEFAULT_SIGNER_WEIGHT,
+                contact_url: None,
+                added_at: env.block.time,
+                status: SignerStatus::Active,
+            },
+        )?;
+    }
+
+    let mapping = LEGACY_TOKEN_MAPPING
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut migrated = 0u64;
+    for (key, value) in mapping {
+        // Disabling a token flagged both its ticker and its denom
+        let disabled = LEGACY_DISABLED_TOKENS.has(deps.storage, &key);
+        LEGACY_TOKEN_MAPPING.remove(deps.storage, &key);
+        LEGACY_DISABLED_TOKENS.remove(deps.storage, &key);
+
+        // The reverse entries are keyed by the TokenFactory denom
+        if key.starts_with("factory/") {
+            continue;
+        }
+        let (ticker, denom) = (key, value);
+
+        // The metadata of the tokens was only kept in their denom metadata
+        let denom_metadata = BankQuerier::new(&deps.querier)
+            .denom_metadata(denom.clone())?
+            .metadata
+            .unwrap_or_default();
+        let metadata = TokenMetadata {
+            decimals: denom_metadata
+                .denom_units
+                .iter()
+                .find(|unit| unit.denom == ticker)
+                .map_or(0, |unit| unit.exponent),
+            ticker: ticker.clone(),
+            name: denom_metadata.name,
+            image_url: denom_metadata.uri,
+        };
+
+        let status = if disabled {
+            TokenStatus::Disabled
+        } else {
+            TokenStatus::Enabled
+        };
+        tokens().save(
+            deps.storage,
+            &ticker,
+            &TokenInfo {
+                metadata,
+                denom,
+                source_chain_id: config.bridge_chain_id.clone(),
+                link_height: env.block.height,
+                status,
+            },
+        )?;
+        migrated += 1;
+    }
+
+    Ok((legacy_signers.len(), migrated))
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    use std::marker::PhantomData;
+
+    use cosmwasm_std::testing::{mock_env, MockApi, MockStorage};
+    use cosmwasm_std::{
+        from_json, to_json_binary, Empty, OwnedDeps, Querier, QuerierResult, QueryRequest,
+        SystemError, SystemResult,
+    };
+    use osmosis_std::types::cosmos::bank::v1beta1::{
+        DenomUnit, Metadata, QueryDenomMetadataRequest, QueryDenomMetadataResponse,
+    };
+
+    /// Answers the denom metadata queries the way the bank module does for
+    /// the denoms the contract created
+    struct DenomMetadataQuerier {}
+
+    impl Querier for DenomMetadataQuerier {
+        fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+            let request: QueryRequest<Empty> = from_json(bin_request).unwrap();
+            match request {
+                QueryRequest::Stargate { path, data }
+                    if path == "/cosmos.bank.v1beta1.Query/DenomMetadata" =>
+                {
+                    let request: QueryDenomMetadataRequest = data.try_into().unwrap();
+                    let ticker = request.denom.rsplit('/').next().unwrap().to_string();
+                    let response = QueryDenomMetadataResponse {
+                        metadata: Some(Metadata {
+                            denom_units: vec![
+                                DenomUnit {
+                                    denom: request.denom.clone(),
+                                    exponent: 0,
+                                    aliases: vec![],
+                                },
+                                DenomUnit {
+                                    denom: ticker.clone(),
+                                    exponent: 6,
+                                    aliases: vec![],
+                                },
+                            ],
+                            base: request.denom,
+                            display: ticker.clone(),
+                            name: format!("{} Token", ticker),
+                            symbol: ticker,
+                            uri: "https://example.com".to_string(),
+                            ..Default::default()
+                        }),
+                    };
+                    SystemResult::Ok(to_json_binary(&response).into())
+                }
+                _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                    kind: "unsupported query".to_string(),
+                }),
+            }
+        }
+    }
+
+    #[test]
+    fn test_migrate_from_baseline() {
+        let mut deps = OwnedDeps {
+            storage: MockStorage::default(),
+            api: MockApi::default(),
+            querier: DenomMetadataQuerier {},
+            custom_query_type: PhantomData::<Empty>,
+        };
+        let env = mock_env();
+
+        // The storage of a deployed instance of the first release
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "1.0.0").unwrap();
+        LEGACY_CONFIG
+            .save(
+                deps.as_mut().storage,
+                &LegacyConfig {
+                    owner: Addr::unchecked("owner"),
+                    bridge_chain_id: "localgaia-1".to_string(),
+                    bridge_ibc_channel: "channel-0".to_string(),
+                    ibc_timeout_seconds: 10,
+                },
+            )
+            .unwrap();
+        for (seed, name) in [(1u8, "signer1"), (2u8, "signer2")] {
+            LEGACY_SIGNERS
+                .save(deps.as_mut().storage, &[seed; 32], &name.to_string())
+                .unwrap();
+        }
+        for ticker in ["TESTTOKEN", "OLDTOKEN"] {
+            let denom = format!("factory/contract0/{}", ticker);
+            LEGACY_TOKEN_MAPPING
+                .save(deps.as_mut().storage, ticker, &denom)
+                .unwrap();
+            LEGACY_TOKEN_MAPPING
+                .save(deps.as_mut().storage, &denom, &ticker.to_string())
+                .unwrap();
+        }
+        LEGACY_DISABLED_TOKENS
+            .save(deps.as_mut().storage, "OLDTOKEN", &true)
+            .unwrap();
+        LEGACY_DISABLED_TOKENS
+            .save(deps.as_mut().storage, "factory/contract0/OLDTOKEN", &true)
+            .unwrap();
+
+        let res = migrate(
+            deps.as_mut(),
+            env.clone(),
+            MigrateMsg {
+                bridge_chain_id: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            res.attributes
+                .iter()
+                .map(|attribute| (attribute.key.as_str(), attribute.value.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("action", "migrate"),
+                ("previous_version", "1.0.0"),
+                ("migrated_signers", "2"),
+                ("migrated_tokens", "2"),
+            ]
+        );
+
+        // The config keeps its values and starts the new settings at their defaults
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+        assert_eq!(config.owner, Addr::unchecked("owner"));
+        assert_eq!(config.bridge_chain_id, "localgaia-1");
+        assert_eq!(config.bridge_ibc_channel, "channel-0");
+        assert_eq!(config.ibc_timeout_seconds, 10);
+        assert_eq!(config.signer_threshold, SignerThreshold::supermajority());
+        assert_eq!(config.min_signer_bond, Uint128::zero());
+        assert_eq!(config.reward_fee_rate, Decimal::zero());
+        assert_eq!(SIGNER_EPOCH.load(deps.as_ref().storage).unwrap(), 0);
+        assert_eq!(NEXT_ADMIN_ACTION_ID.load(deps.as_ref().storage).unwrap(), 0);
+
+        // The signers are active ed25519 signers with the default weight
+        let signer = signers().load(deps.as_ref().storage, &[1u8; 32]).unwrap();
+        assert_eq!(
+            signer,
+            SignerInfo {
+                name: "signer1".to_string(),
+                key_type: KeyType::Ed25519,
+                weight: DEFAULT_SIGNER_WEIGHT,
+                contact_url: None,
+                added_at: env.block.time,
+                status: SignerStatus::Active,
+            }
+        );
+        let (public_key, _) = signers()
+            .idx
+            .name
+            .item(deps.as_ref().storage, "signer2".to_string())
+            .unwrap()
+            .unwrap();
+        assert_eq!(public_key, vec![2u8; 32]);
+
+        // The tokens are in the registry with the metadata of their denom
+        let token = tokens().load(deps.as_ref().storage, "TESTTOKEN").unwrap();
+        assert_eq!(
+            token,
+            TokenInfo {
+                metadata: TokenMetadata {
+                    ticker: "TESTTOKEN".to_string(),
+                    name: "TESTTOKEN Token".to_string(),
+                    image_url: "https://example.com".to_string(),
+                    decimals: 6,
+                },
+                denom: "factory/contract0/TESTTOKEN".to_string(),
+                source_chain_id: "localgaia-1".to_string(),
+                link_height: env.block.height,
+                status: TokenStatus::Enabled,
+            }
+        );
+        let (ticker, token) = tokens()
+            .idx
+            .denom
+            .item(
+                deps.as_ref().storage,
+                "factory/contract0/OLDTOKEN".to_string(),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(ticker, b"OLDTOKEN".to_vec());
+        assert_eq!(token.status, TokenStatus::Disabled);
+
+        for legacy_tokens in [
+            LEGACY_TOKEN_MAPPING
+                .keys(deps.as_ref().storage, None, None, Order::Ascending)
+                .count(),
+            LEGACY_DISABLED_TOKENS
+                .keys(deps.as_ref().storage, None, None, Order::Ascending)
+                .count(),
+        ] {
+            assert_eq!(legacy_tokens, 0);
+        }
+        assert_eq!(
+            get_contract_version(deps.as_ref().storage).unwrap().version,
+            CONTRACT_VERSION
+        );
+    }
+
+    #[test]
+    fn test_migrate_from_registry_version() {
+        let mut deps = OwnedDeps {
+            storage: MockStorage::default(),
+            api: MockApi::default(),
+            querier: DenomMetadataQuerier {},
+            custom_query_type: PhantomData::<Empty>,
+        };
+        let env = mock_env();
+
+        // An instance that already uses the registries keeps its storage and counters
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, REGISTRY_VERSION).unwrap();
+        SIGNER_EPOCH.save(deps.as_mut().storage, &5).unwrap();
+        NEXT_SIGNER_CHANGE_ID
+            .save(deps.as_mut().storage, &3)
+            .unwrap();
+
+        let res = migrate(
+            deps.as_mut(),
+            env.clone(),
+            MigrateMsg {
+                bridge_chain_id: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            res.attributes
+                .iter()
+                .map(|attribute| (attribute.key.as_str(), attribute.value.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("action", "migrate"),
+                ("previous_version", REGISTRY_VERSION),
+            ]
+        );
+        assert_eq!(SIGNER_EPOCH.load(deps.as_ref().storage).unwrap(), 5);
+        assert_eq!(
+            NEXT_SIGNER_CHANGE_ID.load(deps.as_ref().storage).unwrap(),
+            3
+        );
+        assert_eq!(NEXT_ADMIN_ACTION_ID.load(deps.as_ref().storage).unwrap(), 0);
+        assert!(CONFIG.may_load(deps.as_ref().storage).unwrap().is_none());
+
+        // The contract can't be migrated to an older version
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "99.0.0").unwrap();
+        let err = migrate(
+            deps.as_mut(),
+            env,
+            MigrateMsg {
+                bridge_chain_id: None,
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::MigrationError {});
+    }
 }
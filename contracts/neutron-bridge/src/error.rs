@@ -1,4 +1,4 @@
-use cosmwasm_std::{OverflowError, StdError, Uint128, VerificationError};
+use cosmwasm_std::{OverflowError, StdError, Timestamp, Uint128, VerificationError};
 use ed25519_dalek::SignatureError;
 use thiserror::Error;
 
@@ -23,9 +23,68 @@ pub enum ContractError {
     #[error("Insufficient valid signatures to confirm the message")]
     ThresholdNotMet {},
 
+    #[error("Insufficient valid signatures from the signers of {ticker} to confirm the message")]
+    TokenThresholdNotMet { ticker: String },
+
     #[error("Duplicated signatures are not allowed")]
     DuplicateSignatures {},
 
+    #[error("The signer '{signer}' is not part of the signer set")]
+    UnknownSigner { signer: String },
+
+    #[error("The signer '{name}' must have at least {required} untrn bonded")]
+    InsufficientSignerBond { name: String, required: Uint128 },
+
+    #[error("The signer '{name}' has no bond")]
+    SignerBondNotFound { name: String },
+
+    #[error("The attestation expired at {expires_at}")]
+    AttestationExpired { expires_at: Timestamp },
+
+    #[error("An attestation expiry can only be signed in the v2 encoding")]
+    AttestationExpiryRequiresV2 {},
+
+    #[error("The signer '{name}' has no rewards to claim")]
+    NoPendingRewards { name: String },
+
+    #[error("Only untrn can be bonded")]
+    InvalidBondFunds {},
+
+    #[error("The unbonded funds can't be withdrawn before {release_at}")]
+    UnbondingNotFinished { release_at: Timestamp },
+
+    #[error("Invalid slashing evidence: {reason}")]
+    InvalidSlashEvidence { reason: String },
+
+    #[error("The signer '{signer}' is suspended")]
+    SignerSuspended { signer: String },
+
+    #[error("The signature of the signer '{signer}' is not valid for this message")]
+    InvalidSignerSignature { signer: String },
+
+    #[error(
+        "Signatures for signer epoch {epoch} are not accepted, the current epoch is {current}"
+    )]
+    InvalidSignerEpoch { epoch: u64, current: u64 },
+
+    #[error("The owner can no longer change the signer set, signer changes must be approved by the signers")]
+    OwnerSignerManagementDisabled {},
+
+    #[error("The signer change proposal {proposal_id} does not exist")]
+    SignerChangeProposalNotFound { proposal_id: u64 },
+
+    #[error("The admin action {id} does not exist")]
+    AdminActionNotFound { id: u64 },
+
+    #[error("The admin action {id} can't be executed before {executable_at}")]
+    AdminActionNotReady { id: u64, executable_at: Timestamp },
+
+    #[error("Invalid signer key rotation nonce {nonce}, expected {expected}")]
+    InvalidSignerRotationNonce { nonce: u64, expected: u64 },
+
+    #[error("Attestations in the v1 encoding are no longer accepted")]
+    AttestationVersionDisabled {},
+
     #[error("This token has been disabled from bridging: {ticker}")]
     TokenDisabled { ticker: String },
 
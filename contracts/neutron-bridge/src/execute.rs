@@ -1,41 +1,91 @@
 use astroport::common::{claim_ownership, drop_ownership_proposal, propose_new_owner};
 use base64::{engine::general_purpose, Engine as _};
-use cosmwasm_std::{coin, entry_point, Coin, Reply, StdError, SubMsg, Uint128};
+use cosmwasm_std::{
+    coin, entry_point, BankMsg, Binary, Coin, Decimal, Deps, Order, Reply, StdError, StdResult,
+    Storage, SubMsg, SubMsgResult, Timestamp, Uint128,
+};
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
-use ed25519_dalek::{VerifyingKey, PUBLIC_KEY_LENGTH};
 
 use neutron_sdk::bindings::msg::{IbcFee, MsgIbcTransferResponse, NeutronMsg};
 use neutron_sdk::bindings::query::NeutronQuery;
 use neutron_sdk::query::min_ibc_fee::query_min_ibc_fee;
 use neutron_sdk::sudo::msg::RequestPacketTimeoutHeight;
-use osmosis_std::types::cosmos::bank::v1beta1::{DenomUnit, Metadata};
 use osmosis_std::types::osmosis::tokenfactory::v1beta1::{
-    MsgBurn, MsgCreateDenom, MsgCreateDenomResponse, MsgSetDenomMetadata,
+    MsgBurn, MsgCreateDenom, MsgCreateDenomResponse, MsgMint, TokenfactoryQuerier,
 };
 
-use crate::helpers::{build_mint_messages, validate_channel, verify_signatures};
+use crate::attestation::{resolve_attestation_version, Attestation};
+use crate::helpers::{
+    accrue_signer_rewards, attested_reward_fee_rate, build_denom_metadata_msg, build_mint_messages,
+    bump_signer_epoch, count_verified_signers, find_amount_tier, get_signer_threshold,
+    load_rotated_signer_keys, load_signer_key_history, load_signer_keys, load_token,
+    load_token_signer_keys, record_signer_participation, resolve_signer,
+    signer_threshold_reachable, token_signers_threshold_met, total_signer_weight,
+    validate_amount_tiers, validate_attestation_expiry, validate_channel, validate_contact_url,
+    validate_public_key, validate_receive, validate_signer_set_change, validate_signer_threshold,
+    validate_signer_weight, verify_signatures, verify_signer_approvals, verify_slash_evidence,
+    verify_token_signatures,
+};
 use crate::msg::ExecuteMsg;
 use crate::state::{
-    BRIDGE_CURRENT_PAYLOAD, BRIDGE_INFLIGHT, DISABLED_TOKENS, HANDLED_TRANSACTIONS,
-    OWNERSHIP_PROPOSAL, SIGNERS, TOKEN_MAPPING, TOKEN_METADATA,
+    signers, tokens, ADMIN_ACTIONS, AMOUNT_TIERS, BRIDGE_CURRENT_PAYLOAD, BRIDGE_INFLIGHT,
+    DENOM_CREATION_QUEUE, FAILED_TOKEN_LINKS, HANDLED_TRANSACTIONS, LINK_TOKEN_SIGNERS,
+    NEXT_ADMIN_ACTION_ID, NEXT_SIGNER_CHANGE_ID, OWNERSHIP_PROPOSAL, PENDING_RECEIVES,
+    PENDING_TOKEN_LINKS, ROTATED_SIGNER_KEYS, SIGNER_BONDS, SIGNER_CHANGE_PROPOSALS, SIGNER_EPOCH,
+    SIGNER_REWARDS, SIGNER_ROTATION_NONCES, SIGNER_STATS, SLASHED_EVIDENCE, TOKEN_METADATA_NONCES,
+    TOKEN_SIGNERS, TOKEN_SIGNER_THRESHOLD, TRANSACTION_SIGNERS,
 };
 use crate::types::{
-    BridgingAsset, Config, TokenMetadata, FEE_DENOM, IBC_REPLY_HANDLER_ID,
-    INSTANTIATE_DENOM_REPLY_ID, MAX_IBC_TIMEOUT_SECONDS, MIN_IBC_TIMEOUT_SECONDS,
+    AdminAction, Adr036Signature, AmountTier, AttestationVersion, BridgingAsset, Config,
+    FailedTokenLink, KeyType, PendingReceive, PendingTokenLink, QueuedAdminAction,
+    RotatedSignerKey, SignerBond, SignerChange, SignerChangeProposal, SignerInfo, SignerReference,
+    SignerSignature, SignerStats, SignerStatus, SignerThreshold, SlashEvidence, TokenInfo,
+    TokenLink, TokenLinkFailure, TokenMetadata, TokenStatus, DEFAULT_SIGNER_WEIGHT, FEE_DENOM,
+    IBC_REPLY_HANDLER_ID, INSTANTIATE_DENOM_REPLY_ID, MAX_ADMIN_ACTION_DELAY_SECONDS,
+    MAX_IBC_TIMEOUT_SECONDS, MAX_PENDING_RECEIVE_MAX_AGE_SECONDS, MAX_REWARD_FEE_RATE,
+    MAX_SIGNER_EPOCH_GRACE_SECONDS, MAX_SIGNER_KEY_OVERLAP_SECONDS, MAX_UNBONDING_PERIOD_SECONDS,
+    MIN_IBC_TIMEOUT_SECONDS, MIN_SIGNER_THRESHOLD,
 };
 use crate::{error::ContractError, state::CONFIG};
 
 /// Exposes all the execute functions available in the contract
 ///
 /// ## Executable Messages
-/// * **ExecuteMsg::LinkToken { source_chain_id, token,signatures } ** Link and enable a CFT-20 token to be bridged
+/// * **ExecuteMsg::LinkToken { source_chain_id, token, signatures, signer_signatures, adr036_signatures, signer_epoch, attestation_version } ** Link and enable a CFT-20 token to be bridged
+/// * **ExecuteMsg::LinkTokens { links }** Link and enable several CFT-20 tokens in one transaction
+/// * **ExecuteMsg::UpdateTokenMetadata { source_chain_id, token, signatures, signer_signatures, adr036_signatures, signer_epoch, attestation_version }** Update the metadata of a linked token attested by the signers, or overridden by the owner
 /// * **ExecuteMsg::EnableToken { ticker}** Enable a previously disabled token to being bridged again
 /// * **ExecuteMsg::DisableToken { ticker }** Disable a token from being bridged
-/// * **ExecuteMsg::Receive { source_chain_id, transaction_hash, ticker, amount, destination_addr, signatures }** Receive CFT-20 token message from the Hub
+/// * **ExecuteMsg::SetAmountTiers { ticker, tiers }** Set the signer weight required for larger transfers of a token
+/// * **ExecuteMsg::Receive { source_chain_id, transaction_hash, ticker, amount, destination_addr, signatures, signer_signatures, adr036_signatures, signer_epoch, attestation_version, token_signatures, expires_at }** Receive CFT-20 token message from the Hub
+/// * **ExecuteMsg::Attest { source_chain_id, transaction_hash, ticker, amount, destination_addr, signer_signatures, token_signer_signatures, signer_epoch, attestation_version, expires_at }** Collect signatures for a transfer from the Hub, minting once they reach the threshold
 /// * **ExecuteMsg::Send { destination_addr }** Send CFT-20 token back to the Hub
-/// * **ExecuteMsg::AddSigner { public_key_base64, name }** Adds a signer to the allowed list for signature verification
+/// * **ExecuteMsg::AddSigner { public_key_base64, name, key_type, weight, contact_url }** Adds a signer to the allowed list for signature verification
 /// * **ExecuteMsg::RemoveSigner { public_key_base64 }** Remove a signer from the allowed list for signature verification
-/// * **ExecuteMsg::UpdateConfig { bridge_ibc_channel, ibc_timeout_seconds }** Update the contract config
+/// * **ExecuteMsg::UpdateSignerWeight { public_key_base64, weight }** Update the voting weight of a signer
+/// * **ExecuteMsg::UpdateSignerStatus { public_key_base64, status }** Suspend or reactivate a signer
+/// * **ExecuteMsg::RotateSignerKey { old_public_key_base64, new_public_key_base64, new_key_type, nonce, signature, overlap_seconds }** Replace the key of a signer, authorised by the old key
+/// * **ExecuteMsg::SetSignerBondOwner { name, owner }** Register the address that owns the bond of a signer
+/// * **ExecuteMsg::BondSigner { name }** Post an untrn bond for a signer
+/// * **ExecuteMsg::UnbondSigner { name, amount }** Start unbonding part of the bond of a signer
+/// * **ExecuteMsg::WithdrawSignerBond { name }** Withdraw the unbonded funds of a signer after the unbonding period
+/// * **ExecuteMsg::ClaimRewards { name }** Claim the rewards accrued by a signer to the address that posted its bond
+/// * **ExecuteMsg::SlashSigner { name, evidence, signer_signatures }** Slash the bond of a signer that signed conflicting transfers
+/// * **ExecuteMsg::ProposeSignerChange { change, signer_signatures }** Propose a change to the signer set approved by the signers
+/// * **ExecuteMsg::ApproveSignerChange { proposal_id, signer_signatures }** Approve a pending signer change, applying it once the signer threshold approved
+/// * **ExecuteMsg::DisableOwnerSignerManagement {}** Permanently hand the management of the signer set over to the signers
+/// * **ExecuteMsg::AddTokenSigner { ticker, public_key_base64, name, key_type, weight }** Adds a signer to the own signer set of a token
+/// * **ExecuteMsg::RemoveTokenSigner { ticker, public_key_base64 }** Remove a signer from the own signer set of a token
+/// * **ExecuteMsg::SetTokenSignerThreshold { ticker, signer_threshold }** Set the threshold policy of the own signer set of a token
+/// * **ExecuteMsg::UpdateConfig { bridge_ibc_channel, ibc_timeout_seconds, signer_threshold, signer_epoch_grace_seconds, v1_attestations_enabled, admin_action_delay_seconds, pending_receive_max_age_seconds, min_signer_bond, unbonding_period_seconds, treasury, reward_fee_rate }** Update the contract config
+/// * **ExecuteMsg::ExecuteAdminAction { id }** Execute a queued admin action once its delay has passed
+/// * **ExecuteMsg::CancelAdminAction { id }** Cancel a queued admin action
+///
+/// EnableToken, AddSigner, RemoveSigner, UpdateSignerWeight, SetSignerBondOwner,
+/// UpdateConfig, UpdateTokenMetadata without signatures and UpdateSignerStatus
+/// reactivating a signer are admin actions, they are queued when an admin action delay is
+/// configured.
+/// UpdateSignerStatus suspending a signer is applied immediately to respond to incidents
 /// * **ExecuteMsg::ProposeNewOwner { owner, expires_in }** Propose a new owner for the contract
 /// * **ExecuteMsg::DropOwnershipProposal {}** Remove the ownership transfer proposal
 /// * **ExecuteMsg::ClaimOwnership {}** Claim contract ownership
@@ -51,9 +101,58 @@ pub fn execute(
             source_chain_id,
             token,
             signatures,
-        } => link_token(deps, env, source_chain_id, token, signatures),
-        ExecuteMsg::EnableToken { ticker } => enable_token(deps, env, info, ticker),
+            signer_signatures,
+            adr036_signatures,
+            signer_epoch,
+            attestation_version,
+        } => link_token(
+            deps,
+            env,
+            source_chain_id,
+            token,
+            signatures,
+            signer_signatures.unwrap_or_default(),
+            adr036_signatures.unwrap_or_default(),
+            signer_epoch,
+            attestation_version,
+        ),
+        ExecuteMsg::LinkTokens { links } => link_tokens(deps, env, links),
+        ExecuteMsg::UpdateTokenMetadata {
+            source_chain_id,
+            token,
+            signatures,
+            signer_signatures,
+            adr036_signatures,
+            signer_epoch,
+            attestation_version,
+        } => {
+            // Without any signatures the owner overrides the metadata
+            if signatures.is_empty() && signer_signatures.is_none() && adr036_signatures.is_none() {
+                schedule_admin_action(deps, env, info, AdminAction::UpdateTokenMetadata { token })
+            } else {
+                update_token_metadata(
+                    deps,
+                    env,
+                    source_chain_id,
+                    token,
+                    signatures,
+                    signer_signatures.unwrap_or_default(),
+                    adr036_signatures.unwrap_or_default(),
+                    signer_epoch,
+                    attestation_version,
+                )
+            }
+        }
+        ExecuteMsg::EnableToken { ticker } => {
+            schedule_admin_action(deps, env, info, AdminAction::EnableToken { ticker })
+        }
         ExecuteMsg::DisableToken { ticker } => disable_token(deps, env, info, ticker),
+        ExecuteMsg::SetAmountTiers { ticker, tiers } => schedule_admin_action(
+            deps,
+            env,
+            info,
+            AdminAction::SetAmountTiers { ticker, tiers },
+        ),
         ExecuteMsg::Receive {
             source_chain_id,
             transaction_hash,
@@ -61,6 +160,12 @@ pub fn execute(
             amount,
             destination_addr,
             signatures,
+            signer_signatures,
+            adr036_signatures,
+            signer_epoch,
+            attestation_version,
+            token_signatures,
+            expires_at,
         } => bridge_receive(
             deps,
             env,
@@ -70,19 +175,211 @@ pub fn execute(
             amount,
             destination_addr,
             signatures,
+            signer_signatures.unwrap_or_default(),
+            adr036_signatures.unwrap_or_default(),
+            signer_epoch,
+            attestation_version,
+            token_signatures.unwrap_or_default(),
+            expires_at,
+        ),
+        ExecuteMsg::Attest {
+            source_chain_id,
+            transaction_hash,
+            ticker,
+            amount,
+            destination_addr,
+            signer_signatures,
+            token_signer_signatures,
+            signer_epoch,
+            attestation_version,
+            expires_at,
+        } => attest(
+            deps,
+            env,
+            source_chain_id,
+            transaction_hash,
+            ticker,
+            amount,
+            destination_addr,
+            signer_signatures,
+            token_signer_signatures,
+            signer_epoch,
+            attestation_version,
+            expires_at,
         ),
         ExecuteMsg::Send { destination_addr } => bridge_send(deps, env, info, destination_addr),
         ExecuteMsg::AddSigner {
             public_key_base64,
             name,
-        } => add_signer(deps, info, name, public_key_base64),
-        ExecuteMsg::RemoveSigner { public_key_base64 } => {
-            remove_signer(deps, env, info, public_key_base64)
-        }
+            key_type,
+            weight,
+            contact_url,
+        } => schedule_admin_action(
+            deps,
+            env,
+            info,
+            AdminAction::AddSigner {
+                public_key_base64,
+                name,
+                key_type,
+                weight,
+                contact_url,
+            },
+        ),
+        ExecuteMsg::RemoveSigner { public_key_base64 } => schedule_admin_action(
+            deps,
+            env,
+            info,
+            AdminAction::RemoveSigner { public_key_base64 },
+        ),
+        ExecuteMsg::UpdateSignerWeight {
+            public_key_base64,
+            weight,
+        } => schedule_admin_action(
+            deps,
+            env,
+            info,
+            AdminAction::UpdateSignerWeight {
+                public_key_base64,
+                weight,
+            },
+        ),
+        ExecuteMsg::UpdateSignerStatus {
+            public_key_base64,
+            status,
+        } => match status {
+            SignerStatus::Suspended => {
+                update_signer_status(deps, env, info, public_key_base64, status)
+            }
+            SignerStatus::Active => schedule_admin_action(
+                deps,
+                env,
+                info,
+                AdminAction::ReactivateSigner { public_key_base64 },
+            ),
+        },
+        ExecuteMsg::RotateSignerKey {
+            old_public_key_base64,
+            new_public_key_base64,
+            new_key_type,
+            nonce,
+            signature,
+            overlap_seconds,
+        } => rotate_signer_key(
+            deps,
+            env,
+            old_public_key_base64,
+            new_public_key_base64,
+            new_key_type.unwrap_or_default(),
+            nonce,
+            signature,
+            overlap_seconds.unwrap_or_default(),
+        ),
+        ExecuteMsg::SetSignerBondOwner { name, owner } => schedule_admin_action(
+            deps,
+            env,
+            info,
+            AdminAction::SetSignerBondOwner { name, owner },
+        ),
+        ExecuteMsg::BondSigner { name } => bond_signer(deps, info, name),
+        ExecuteMsg::UnbondSigner { name, amount } => unbond_signer(deps, env, info, name, amount),
+        ExecuteMsg::WithdrawSignerBond { name } => withdraw_signer_bond(deps, env, info, name),
+        ExecuteMsg::ClaimRewards { name } => claim_rewards(deps, info, name),
+        ExecuteMsg::SlashSigner {
+            name,
+            evidence,
+            signer_signatures,
+        } => slash_signer(
+            deps,
+            env,
+            info,
+            name,
+            evidence,
+            signer_signatures.unwrap_or_default(),
+        ),
+        ExecuteMsg::ProposeSignerChange {
+            change,
+            signer_signatures,
+        } => propose_signer_change(deps, env, info, change, signer_signatures),
+        ExecuteMsg::ApproveSignerChange {
+            proposal_id,
+            signer_signatures,
+        } => approve_signer_change(deps, env, proposal_id, signer_signatures),
+        ExecuteMsg::DisableOwnerSignerManagement {} => disable_owner_signer_management(deps, info),
+        ExecuteMsg::AddTokenSigner {
+            ticker,
+            public_key_base64,
+            name,
+            key_type,
+            weight,
+        } => schedule_admin_action(
+            deps,
+            env,
+            info,
+            AdminAction::AddTokenSigner {
+                ticker,
+                public_key_base64,
+                name,
+                key_type,
+                weight,
+            },
+        ),
+        ExecuteMsg::RemoveTokenSigner {
+            ticker,
+            public_key_base64,
+        } => schedule_admin_action(
+            deps,
+            env,
+            info,
+            AdminAction::RemoveTokenSigner {
+                ticker,
+                public_key_base64,
+            },
+        ),
+        ExecuteMsg::SetTokenSignerThreshold {
+            ticker,
+            signer_threshold,
+        } => schedule_admin_action(
+            deps,
+            env,
+            info,
+            AdminAction::SetTokenSignerThreshold {
+                ticker,
+                signer_threshold,
+            },
+        ),
         ExecuteMsg::UpdateConfig {
             bridge_ibc_channel,
             ibc_timeout_seconds,
-        } => update_config(deps, info, bridge_ibc_channel, ibc_timeout_seconds),
+            signer_threshold,
+            signer_epoch_grace_seconds,
+            v1_attestations_enabled,
+            admin_action_delay_seconds,
+            pending_receive_max_age_seconds,
+            min_signer_bond,
+            unbonding_period_seconds,
+            treasury,
+            reward_fee_rate,
+        } => schedule_admin_action(
+            deps,
+            env,
+            info,
+            AdminAction::UpdateConfig {
+                bridge_ibc_channel,
+                ibc_timeout_seconds,
+                signer_threshold,
+                signer_epoch_grace_seconds,
+                v1_attestations_enabled,
+                admin_action_delay_seconds,
+                pending_receive_max_age_seconds,
+                min_signer_bond,
+                unbonding_period_seconds,
+                treasury,
+                reward_fee_rate,
+            },
+        ),
+        ExecuteMsg::ExecuteAdminAction { id } => execute_admin_action(deps, env, info, id),
+        ExecuteMsg::CancelAdminAction { id } => cancel_admin_action(deps, info, id),
         ExecuteMsg::ProposeNewOwner { owner, expires_in } => {
             let config = CONFIG.load(deps.storage)?;
             propose_new_owner(
@@ -124,48 +421,32 @@ pub fn reply(
 ) -> Result<Response<NeutronMsg>, ContractError> {
     match msg.id {
         INSTANTIATE_DENOM_REPLY_ID => {
-            let MsgCreateDenomResponse { new_token_denom } = msg.result.try_into()?;
-
-            let metadata = TOKEN_METADATA.load(deps.storage)?;
-
-            let denom_metadata_msg = MsgSetDenomMetadata {
-                sender: env.contract.address.to_string(),
-                metadata: Some(Metadata {
-                    symbol: metadata.ticker.clone(),
-                    name: metadata.name,
-                    base: new_token_denom.clone(),
-                    display: metadata.ticker.clone(),
-                    denom_units: vec![
-                        DenomUnit {
-                            denom: new_token_denom.clone(),
-                            exponent: 0,
-                            aliases: vec![],
-                        },
-                        DenomUnit {
-                            denom: metadata.ticker.clone(),
-                            exponent: metadata.decimals,
-                            aliases: vec![],
-                        },
-                    ],
-                    description: format!(
-                        "{} is an Asteroid CFT-20 token bridged from the Cosmos Hub",
-                        metadata.ticker
-                    ),
-                    uri: metadata.image_url,
-                    uri_hash: "".to_string(),
-                }),
-            };
+            // The replies come back in the order the denoms were submitted
+            let mut queue = DENOM_CREATION_QUEUE
+                .may_load(deps.storage)?
+                .unwrap_or_default();
+            if queue.is_empty() {
+                return Err(StdError::generic_err("No denom is being created").into());
+            }
+            let subdenom = queue.remove(0);
+            if queue.is_empty() {
+                DENOM_CREATION_QUEUE.remove(deps.storage);
+            } else {
+                DENOM_CREATION_QUEUE.save(deps.storage, &queue)?;
+            }
 
-            // Save the mapping of TICKER <> DENOM both ways to ease lookups
-            // in both directions
-            TOKEN_MAPPING.save(deps.storage, &metadata.ticker, &new_token_denom)?;
-            TOKEN_MAPPING.save(deps.storage, &new_token_denom, &metadata.ticker)?;
-            TOKEN_METADATA.remove(deps.storage);
+            let pending_link = PENDING_TOKEN_LINKS.load(deps.storage, &subdenom)?;
+            PENDING_TOKEN_LINKS.remove(deps.storage, &subdenom);
 
-            Ok(Response::new()
-                .add_message(denom_metadata_msg)
-                .add_attribute("action", "set_denom_metadata")
-                .add_attribute("ticker", metadata.ticker))
+            match msg.result {
+                SubMsgResult::Err(error) => {
+                    handle_failed_denom_creation(deps, &env, pending_link, error)
+                }
+                result => {
+                    let MsgCreateDenomResponse { new_token_denom } = result.try_into()?;
+                    register_token(deps, &env, pending_link, new_token_denom)
+                }
+            }
         }
         IBC_REPLY_HANDLER_ID => {
             // Extract the channel and sequence ID from the IBC transfer
@@ -197,37 +478,149 @@ pub fn reply(
     }
 }
 
+/// Register a linked token with its TokenFactory denom and set the denom metadata
+fn register_token(
+    deps: DepsMut<NeutronQuery>,
+    env: &Env,
+    pending_link: PendingTokenLink,
+    denom: String,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let PendingTokenLink {
+        source_chain_id,
+        token,
+        signers: signer_names,
+    } = pending_link;
+
+    let denom_metadata_msg =
+        build_denom_metadata_msg(env.contract.address.to_string(), &denom, &token);
+
+    // Register the token, the denom index allows looking it up from
+    // the TokenFactory denom as well
+    let ticker = token.ticker.clone();
+    tokens().save(
+        deps.storage,
+        &ticker,
+        &TokenInfo {
+            metadata: token,
+            denom: denom.clone(),
+            source_chain_id,
+            link_height: env.block.height,
+            status: TokenStatus::Enabled,
+        },
+    )?;
+    FAILED_TOKEN_LINKS.remove(deps.storage, &ticker);
+
+    // The signers are only counted for a link that created its token
+    LINK_TOKEN_SIGNERS.save(deps.storage, &ticker, &signer_names)?;
+    record_signer_participation(deps.storage, env, &signer_names)?;
+
+    Ok(Response::new()
+        .add_message(denom_metadata_msg)
+        .add_attribute("action", "set_denom_metadata")
+        .add_attribute("ticker", ticker)
+        .add_attribute("denom", denom))
+}
+
+/// Handle a denom that couldn't be created for a token link
+///
+/// Creating a denom that already exists fails, if the bridge is still its admin
+/// the denom is adopted. Otherwise the failed link is recorded without
+/// aborting the transaction so relayers can act on it
+fn handle_failed_denom_creation(
+    deps: DepsMut<NeutronQuery>,
+    env: &Env,
+    pending_link: PendingTokenLink,
+    error: String,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let ticker = pending_link.token.ticker.clone();
+    let denom = format!("factory/{}/{}", env.contract.address, ticker);
+
+    let admin = TokenfactoryQuerier::new(&deps.querier)
+        .denom_authority_metadata(denom.clone())
+        .ok()
+        .and_then(|response| response.authority_metadata)
+        .map(|metadata| metadata.admin);
+    let reason = match admin {
+        Some(admin) if admin == env.contract.address.as_str() => {
+            return Ok(
+                register_token(deps, env, pending_link, denom)?.add_attribute("adopted", "true")
+            );
+        }
+        Some(_) => TokenLinkFailure::DenomAlreadyExists { denom },
+        None => TokenLinkFailure::DenomCreationFailed { error },
+    };
+
+    FAILED_TOKEN_LINKS.save(
+        deps.storage,
+        &ticker,
+        &FailedTokenLink {
+            source_chain_id: pending_link.source_chain_id,
+            token: pending_link.token,
+            reason: reason.clone(),
+            failed_at_height: env.block.height,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "link_token_failed")
+        .add_attribute("ticker", ticker)
+        .add_attribute("reason", reason.as_str()))
+}
+
 /// Enable the bridging of a CFT-20 token
 ///
 /// If this token doesn't have a corresponding TokenFactory token one will
-/// be created using the information provided.
+/// be created using the information provided. A denom the bridge already
+/// created for the ticker is adopted, any other failure is recorded as a
+/// failed link.
+#[allow(clippy::too_many_arguments)]
 fn link_token(
     deps: DepsMut<NeutronQuery>,
     env: Env,
     source_chain_id: String,
     token: TokenMetadata,
     signatures: Vec<String>,
+    signer_signatures: Vec<SignerSignature>,
+    adr036_signatures: Vec<Adr036Signature>,
+    signer_epoch: Option<u64>,
+    attestation_version: Option<AttestationVersion>,
 ) -> Result<Response<NeutronMsg>, ContractError> {
-    // If we already have this token, return an error
-    if TOKEN_MAPPING.has(deps.storage, &token.ticker) {
+    // If we already have this token or it is being linked, return an error
+    if tokens().may_load(deps.storage, &token.ticker)?.is_some()
+        || PENDING_TOKEN_LINKS.has(deps.storage, &token.ticker)
+    {
         return Err(ContractError::TokenAlreadyExists {
             ticker: token.ticker,
         });
     }
 
+    let config = CONFIG.load(deps.storage)?;
+    let attestation_version = resolve_attestation_version(&config, attestation_version)?;
+
     // Build the attestation message to verify the token information
-    // The format is {source_chain_id}{ticker}{decimals}{chain_id}{contract_address}
+    // The v1 format is {source_chain_id}{ticker}{decimals}{chain_id}{contract_address}
     // cosmoshub-4ticker8neutron-1neutron1xxxxx
-    let attestation = format!(
-        "{}{}{}{}{}",
-        source_chain_id, token.ticker, token.decimals, env.block.chain_id, env.contract.address
-    );
-
-    // Verify with current keys
-    verify_signatures(deps.as_ref(), attestation.as_bytes(), &signatures)?;
+    // If the signatures were made for a specific signer epoch, it is appended
+    // cosmoshub-4ticker8neutron-1neutron1xxxxx3
+    // The name and image are only attested in v2, a v1 link can't vouch for them
+    let attestation = Attestation::link_token(&env, &source_chain_id, &token)
+        .with_optional("signer_epoch", signer_epoch)
+        .encode(attestation_version);
+
+    // Verify with the keys of the requested signer epoch
+    let signer_names = verify_signatures(
+        deps.as_ref(),
+        &env,
+        &attestation,
+        &signatures,
+        &signer_signatures,
+        &adr036_signatures,
+        signer_epoch,
+        None,
+    )?;
 
     // If not, create the denom and set the metadata
-    let create_denom_msg = SubMsg::reply_on_success(
+    let create_denom_msg = SubMsg::reply_always(
         MsgCreateDenom {
             sender: env.contract.address.to_string(),
             subdenom: token.ticker.clone(),
@@ -235,9 +628,58 @@ fn link_token(
         INSTANTIATE_DENOM_REPLY_ID,
     );
 
-    TOKEN_METADATA.save(deps.storage, &token)?;
+    // The ticker is the subdenom, the reply finds the pending link from it
+    // The signers are only counted once the reply registers the token
+    let pending_link = PendingTokenLink {
+        source_chain_id,
+        token,
+        signers: signer_names.clone(),
+    };
+    PENDING_TOKEN_LINKS.save(deps.storage, &pending_link.token.ticker, &pending_link)?;
+    let mut queue = DENOM_CREATION_QUEUE
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    queue.push(pending_link.token.ticker);
+    DENOM_CREATION_QUEUE.save(deps.storage, &queue)?;
+
+    Ok(Response::new()
+        .add_submessage(create_denom_msg)
+        .add_attribute("signers", signer_names.join(",")))
+}
+
+/// Link several CFT-20 tokens, creating a TokenFactory denom for each of them
+fn link_tokens(
+    mut deps: DepsMut<NeutronQuery>,
+    env: Env,
+    links: Vec<TokenLink>,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    if links.is_empty() {
+        return Err(ContractError::InvalidConfiguration {
+            reason: "At least one token must be linked".to_string(),
+        });
+    }
+
+    let mut response = Response::new().add_attribute("action", "link_tokens");
+    for link in links {
+        let ticker = link.token.ticker.clone();
+        let linked = link_token(
+            deps.branch(),
+            env.clone(),
+            link.source_chain_id,
+            link.token,
+            link.signatures,
+            link.signer_signatures.unwrap_or_default(),
+            link.adr036_signatures.unwrap_or_default(),
+            link.signer_epoch,
+            link.attestation_version,
+        )?;
+        response = response
+            .add_submessages(linked.messages)
+            .add_attribute("ticker", ticker)
+            .add_attributes(linked.attributes);
+    }
 
-    Ok(Response::new().add_submessage(create_denom_msg))
+    Ok(response)
 }
 
 /// Enable a token for bridging if it was previously disabled
@@ -254,23 +696,113 @@ fn enable_token(
         return Err(ContractError::Unauthorized {});
     }
 
-    // If this token isn't in the disabled list, return an error
-    if !DISABLED_TOKENS.has(deps.storage, &ticker) {
+    // If this token isn't disabled, return an error
+    let Some(mut token) = tokens()
+        .may_load(deps.storage, &ticker)?
+        .filter(|token| token.status == TokenStatus::Disabled)
+    else {
         return Err(ContractError::InvalidConfiguration {
             reason: "This token is not disabled".to_string(),
         });
-    }
+    };
 
-    // We need to enable both the CFT-20 ticker and the TokenFactory denom
-    let matching_denom = TOKEN_MAPPING.load(deps.storage, &ticker)?;
-    DISABLED_TOKENS.remove(deps.storage, &ticker);
-    DISABLED_TOKENS.remove(deps.storage, &matching_denom);
+    token.status = TokenStatus::Enabled;
+    tokens().save(deps.storage, &ticker, &token)?;
 
     Ok(Response::new()
         .add_attribute("action", "enable_token")
         .add_attribute("ticker", ticker))
 }
 
+/// Update the metadata of a linked token attested by the signers
+#[allow(clippy::too_many_arguments)]
+fn update_token_metadata(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    source_chain_id: String,
+    token: TokenMetadata,
+    signatures: Vec<String>,
+    signer_signatures: Vec<SignerSignature>,
+    adr036_signatures: Vec<Adr036Signature>,
+    signer_epoch: Option<u64>,
+    attestation_version: Option<AttestationVersion>,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let attestation_version = resolve_attestation_version(&config, attestation_version)?;
+
+    // The signatures are bound to the current nonce of the token so they
+    // can't be replayed to revert a later update
+    let nonce = TOKEN_METADATA_NONCES
+        .may_load(deps.storage, &token.ticker)?
+        .unwrap_or_default();
+    let attestation = Attestation::update_token_metadata(&env, &source_chain_id, &token, nonce)
+        .with_optional("signer_epoch", signer_epoch)
+        .encode(attestation_version);
+    let signer_names = verify_signatures(
+        deps.as_ref(),
+        &env,
+        &attestation,
+        &signatures,
+        &signer_signatures,
+        &adr036_signatures,
+        signer_epoch,
+        None,
+    )?;
+    record_signer_participation(deps.storage, &env, &signer_names)?;
+
+    Ok(set_token_metadata(deps, &env, token)?.add_attribute("signers", signer_names.join(",")))
+}
+
+/// Override the metadata of a linked token without the signers
+fn override_token_metadata(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    token: TokenMetadata,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only owner can override the metadata
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    set_token_metadata(deps, &env, token)
+}
+
+/// Replace the stored metadata of a linked token and re-issue its denom metadata
+/// Every update bumps the nonce of the token, invalidating the signatures
+/// made for the previous metadata
+fn set_token_metadata(
+    deps: DepsMut<NeutronQuery>,
+    env: &Env,
+    token: TokenMetadata,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let mut linked = load_token(deps.storage, &token.ticker)?;
+
+    // The amounts bridged are in the base unit, the decimals are fixed on the Hub
+    if token.decimals != linked.metadata.decimals {
+        return Err(ContractError::InvalidConfiguration {
+            reason: "The decimals of a linked token can't change".to_string(),
+        });
+    }
+
+    linked.metadata = token.clone();
+    tokens().save(deps.storage, &token.ticker, &linked)?;
+    TOKEN_METADATA_NONCES.update(deps.storage, &token.ticker, |nonce| -> StdResult<_> {
+        Ok(nonce.unwrap_or_default() + 1)
+    })?;
+
+    Ok(Response::new()
+        .add_message(build_denom_metadata_msg(
+            env.contract.address.to_string(),
+            &linked.denom,
+            &token,
+        ))
+        .add_attribute("action", "update_token_metadata")
+        .add_attribute("ticker", token.ticker))
+}
+
 /// Disable a token for bridging
 fn disable_token(
     deps: DepsMut<NeutronQuery>,
@@ -285,28 +817,59 @@ fn disable_token(
         return Err(ContractError::Unauthorized {});
     }
 
+    // If this token doesn't exist, return an error
+    let mut token = load_token(deps.storage, &ticker)?;
+
     // If this token is already disabled, return an error
-    if DISABLED_TOKENS.has(deps.storage, &ticker) {
+    if token.status == TokenStatus::Disabled {
         return Err(ContractError::InvalidConfiguration {
             reason: "This token already disabled".to_string(),
         });
     }
 
-    // If this token doesn't exist, return an error
-    if !TOKEN_MAPPING.has(deps.storage, &ticker) {
-        return Err(ContractError::TokenDoesNotExist { ticker });
-    }
-    // We need to disable both the CFT-20 ticker and the TokenFactory denom
-    let matching_denom = TOKEN_MAPPING.load(deps.storage, &ticker)?;
-    DISABLED_TOKENS.save(deps.storage, &ticker, &true)?;
-    DISABLED_TOKENS.save(deps.storage, &matching_denom, &true)?;
+    // The status covers both the CFT-20 ticker and the TokenFactory denom
+    token.status = TokenStatus::Disabled;
+    tokens().save(deps.storage, &ticker, &token)?;
 
     Ok(Response::new()
         .add_attribute("action", "disable_token")
         .add_attribute("ticker", ticker))
 }
 
+/// Set the amount tiers of a token, replacing the previous ones
+fn set_amount_tiers(
+    deps: DepsMut<NeutronQuery>,
+    _env: Env,
+    info: MessageInfo,
+    ticker: String,
+    tiers: Vec<AmountTier>,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only owner can update the amount tiers
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // If this token doesn't exist, return an error
+    load_token(deps.storage, &ticker)?;
+
+    validate_amount_tiers(&tiers)?;
+
+    if tiers.is_empty() {
+        AMOUNT_TIERS.remove(deps.storage, &ticker);
+    } else {
+        AMOUNT_TIERS.save(deps.storage, &ticker, &tiers)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "set_amount_tiers")
+        .add_attribute("ticker", ticker)
+        .add_attribute("tiers", tiers.len().to_string()))
+}
+
 /// Receive tokens from the Hub and mint them to the destination address
+#[allow(clippy::too_many_arguments)]
 fn bridge_receive(
     deps: DepsMut<NeutronQuery>,
     env: Env,
@@ -316,62 +879,352 @@ fn bridge_receive(
     amount: Uint128,
     destination_addr: String,
     signatures: Vec<String>,
+    signer_signatures: Vec<SignerSignature>,
+    adr036_signatures: Vec<Adr036Signature>,
+    signer_epoch: Option<u64>,
+    attestation_version: Option<AttestationVersion>,
+    token_signatures: Vec<String>,
+    expires_at: Option<Timestamp>,
 ) -> Result<Response<NeutronMsg>, ContractError> {
-    // Check if the token is disabled
-    if DISABLED_TOKENS.has(deps.storage, &ticker) {
-        return Err(ContractError::TokenDisabled { ticker });
+    let config = CONFIG.load(deps.storage)?;
+    let attestation_version = resolve_attestation_version(&config, attestation_version)?;
+    validate_receive(
+        deps.as_ref(),
+        &transaction_hash,
+        &ticker,
+        amount,
+        &destination_addr,
+    )?;
+    validate_attestation_expiry(&env, expires_at, attestation_version)?;
+
+    // Build the attestation message to verify
+    // The v1 format is {source_chain_id}{transaction_hash_from_source_chain}{ticker}{amount}{local_chain_id}{contract_address}{destination_address}
+    // cosmoshub-4TXHASHticker80000neutron-1neutron1contractneutron1destination
+    // If the signatures were made for a specific signer epoch, it is appended
+    // cosmoshub-4TXHASHticker80000neutron-1neutron1contractneutron1destination3
+    // An expiry is only accepted in the v2 encoding, where it is its own field
+    // The v2 encoding also carries the reward fee rate deducted from the transfer
+    let reward_fee_rate = attested_reward_fee_rate(&config, attestation_version);
+    let attestation = Attestation::receive(
+        &env,
+        &source_chain_id,
+        &transaction_hash,
+        &ticker,
+        amount,
+        &destination_addr,
+    )
+    .with_optional("signer_epoch", signer_epoch)
+    .with_optional(
+        "expires_at",
+        expires_at.map(|expires_at| expires_at.seconds()),
+    )
+    .with_optional("reward_fee_rate", reward_fee_rate)
+    .encode(attestation_version);
+
+    // Larger transfers can require more of the signer weight
+    let amount_tiers = AMOUNT_TIERS
+        .may_load(deps.storage, &ticker)?
+        .unwrap_or_default();
+    let signer_names = verify_signatures(
+        deps.as_ref(),
+        &env,
+        &attestation,
+        &signatures,
+        &signer_signatures,
+        &adr036_signatures,
+        signer_epoch,
+        find_amount_tier(&amount_tiers, amount),
+    )?;
+    // Tokens with their own signers also need the quorum of those signers
+    verify_token_signatures(deps.as_ref(), &ticker, &attestation, &token_signatures)?;
+
+    complete_receive(
+        deps,
+        &env,
+        &transaction_hash,
+        &ticker,
+        amount,
+        destination_addr,
+        reward_fee_rate.unwrap_or_default(),
+        signer_names,
+    )
+}
+
+/// Collect the signatures of a transfer from the Hub one signer at a time
+///
+/// Every submitted signature is verified and stored with the public key of its
+/// signer, so the collected signatures are counted by key without verifying
+/// them again. The tokens are minted as soon as the collected signatures reach
+/// the threshold, a pending receive that expired is started over
+#[allow(clippy::too_many_arguments)]
+fn attest(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    source_chain_id: String,
+    transaction_hash: String,
+    ticker: String,
+    amount: Uint128,
+    destination_addr: String,
+    signer_signatures: Vec<SignerSignature>,
+    token_signer_signatures: Vec<SignerSignature>,
+    signer_epoch: Option<u64>,
+    attestation_version: Option<AttestationVersion>,
+    expires_at: Option<Timestamp>,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let attestation_version = resolve_attestation_version(&config, attestation_version)?;
+    validate_receive(
+        deps.as_ref(),
+        &transaction_hash,
+        &ticker,
+        amount,
+        &destination_addr,
+    )?;
+    validate_attestation_expiry(&env, expires_at, attestation_version)?;
+
+    if signer_signatures.is_empty() && token_signer_signatures.is_empty() {
+        return Err(ContractError::InvalidConfiguration {
+            reason: "At least one signature must be submitted".to_string(),
+        });
     }
-    // Check the amount sent, if 0, reject
-    if amount.is_zero() {
-        return Err(ContractError::ZeroAmount {});
+
+    // Signatures attesting different transfers for the same transaction are
+    // collected separately, so a faulty signer attesting first can't block the
+    // transfer attested by the others
+    let reward_fee_rate = attested_reward_fee_rate(&config, attestation_version);
+    let max_expires_at = env
+        .block
+        .time
+        .plus_seconds(config.pending_receive_max_age_seconds);
+    let mut candidates = PENDING_RECEIVES
+        .may_load(deps.storage, &transaction_hash)?
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|pending| pending.expires_at > env.block.time)
+        .collect::<Vec<_>>();
+    let index = candidates.iter().position(|pending| {
+        pending.source_chain_id == source_chain_id
+            && pending.ticker == ticker
+            && pending.amount == amount
+            && pending.destination_addr == destination_addr
+            && pending.signer_epoch == signer_epoch
+            && pending.attestation_expires_at == expires_at
+            && pending.attestation_version == attestation_version
+            && pending.reward_fee_rate == reward_fee_rate
+    });
+    let mut pending = match index {
+        Some(index) => candidates.remove(index),
+        None => PendingReceive {
+            source_chain_id: source_chain_id.clone(),
+            ticker: ticker.clone(),
+            amount,
+            destination_addr: destination_addr.clone(),
+            signer_epoch,
+            attestation_expires_at: expires_at,
+            attestation_version,
+            reward_fee_rate,
+            signer_signatures: vec![],
+            signers: vec![],
+            token_signatures: vec![],
+            token_signers: vec![],
+            created_at: env.block.time,
+            // Signatures aren't collected past the expiry of the attestation
+            expires_at: expires_at
+                .map_or(max_expires_at, |expires_at| expires_at.min(max_expires_at)),
+        },
+    };
+
+    let attestation = Attestation::receive(
+        &env,
+        &source_chain_id,
+        &transaction_hash,
+        &ticker,
+        amount,
+        &destination_addr,
+    )
+    .with_optional("signer_epoch", signer_epoch)
+    .with_optional(
+        "expires_at",
+        expires_at.map(|expires_at| expires_at.seconds()),
+    )
+    .with_optional("reward_fee_rate", reward_fee_rate)
+    .encode(attestation_version);
+
+    // The signatures are stored with the key of their signer, the index of a
+    // signer can change before the threshold is reached
+    let mut allowed_keys = load_signer_keys(deps.as_ref(), &env, &config, signer_epoch)?;
+    let current_epoch = SIGNER_EPOCH.load(deps.storage)?;
+    if signer_epoch.unwrap_or(current_epoch) == current_epoch {
+        allowed_keys.extend(load_rotated_signer_keys(deps.storage, &env)?);
     }
-    // Check destination address, if invalid, reject
-    if deps.api.addr_validate(&destination_addr).is_err() {
-        return Err(ContractError::InvalidDestinationAddr {});
+    verify_signer_approvals(
+        deps.as_ref(),
+        &attestation,
+        &signer_signatures,
+        &allowed_keys,
+    )?;
+    for signer_signature in signer_signatures {
+        let (public_key, signer) = resolve_signer(&allowed_keys, &signer_signature.signer)?;
+
+        // Signers are only counted once, including through their rotated keys,
+        // and can't attest two different transfers for the same transaction
+        if pending.signers.contains(&signer.name)
+            || candidates
+                .iter()
+                .any(|candidate| candidate.signers.contains(&signer.name))
+        {
+            return Err(ContractError::DuplicateSignatures {});
+        }
+        pending.signers.push(signer.name.clone());
+        pending.signer_signatures.push(SignerSignature {
+            signer: SignerReference::PublicKey {
+                public_key_base64: general_purpose::STANDARD.encode(public_key),
+            },
+            signature: signer_signature.signature,
+        });
     }
-    // Check the ticker, if it doesn't exist activate needs to be called first
-    if !TOKEN_MAPPING.has(deps.storage, &ticker) {
-        return Err(ContractError::TokenDoesNotExist { ticker });
+
+    let token_keys = load_token_signer_keys(deps.storage, &ticker)?;
+    let approving_keys = verify_signer_approvals(
+        deps.as_ref(),
+        &attestation,
+        &token_signer_signatures,
+        &token_keys,
+    )?;
+    for (public_key, signer_signature) in approving_keys.into_iter().zip(token_signer_signatures) {
+        let public_key = Binary::from(public_key);
+        if pending.token_signers.contains(&public_key)
+            || candidates
+                .iter()
+                .any(|candidate| candidate.token_signers.contains(&public_key))
+        {
+            return Err(ContractError::DuplicateSignatures {});
+        }
+        pending.token_signers.push(public_key);
+        pending.token_signatures.push(signer_signature.signature);
     }
-    // Check if we've processed this transaction already
-    if HANDLED_TRANSACTIONS.has(deps.storage, &transaction_hash) {
-        return Err(ContractError::TransactionAlreadyHandled { transaction_hash });
+
+    // Only the submitted signatures were verified, the collected ones are
+    // counted by the key of their signer
+    let mut signer_keys = vec![];
+    for signer_signature in &pending.signer_signatures {
+        if let SignerReference::PublicKey { public_key_base64 } = &signer_signature.signer {
+            signer_keys.push(general_purpose::STANDARD.decode(public_key_base64)?);
+        }
     }
-    // Store the transaction hash to prevent replay attacks
-    HANDLED_TRANSACTIONS.save(deps.storage, &transaction_hash, &true)?;
+    let amount_tiers = AMOUNT_TIERS
+        .may_load(deps.storage, &ticker)?
+        .unwrap_or_default();
+    let signer_names = count_verified_signers(
+        deps.as_ref(),
+        &env,
+        &signer_keys,
+        signer_epoch,
+        find_amount_tier(&amount_tiers, amount),
+    )?;
+    let token_threshold_met =
+        token_signers_threshold_met(deps.storage, &ticker, &pending.token_signers)?;
+
+    let (Some(signer_names), true) = (signer_names, token_threshold_met) else {
+        let response = Response::default()
+            .add_attribute("action", "attest")
+            .add_attribute("transaction_hash", &transaction_hash)
+            .add_attribute("signers", pending.signers.join(","))
+            .add_attribute("expires_at", pending.expires_at.seconds().to_string());
+        candidates.insert(index.unwrap_or(candidates.len()), pending);
+        PENDING_RECEIVES.save(deps.storage, &transaction_hash, &candidates)?;
+
+        return Ok(response);
+    };
 
-    // Build the attestation message to verify
-    // The format is {source_chain_id}{transaction_hash_from_source_chain}{ticker}{amount}{local_chain_id}{contract_address}{destination_address}
-    // cosmoshub-4TXHASHticker80000neutron-1neutron1contractneutron1destination
-    let attestation = format!(
-        "{}{}{}{}{}{}{}",
-        source_chain_id,
-        transaction_hash,
-        ticker,
+    complete_receive(
+        deps,
+        &env,
+        &transaction_hash,
+        &ticker,
         amount,
-        env.block.chain_id,
-        env.contract.address,
-        destination_addr
-    );
-
-    verify_signatures(deps.as_ref(), attestation.as_bytes(), &signatures)?;
+        destination_addr,
+        reward_fee_rate.unwrap_or_default(),
+        signer_names,
+    )
+}
 
-    let tokenfactory_denom = TOKEN_MAPPING.load(deps.storage, &ticker)?;
+/// Mint the tokens of a verified transfer from the Hub to the destination
+#[allow(clippy::too_many_arguments)]
+fn complete_receive(
+    deps: DepsMut<NeutronQuery>,
+    env: &Env,
+    transaction_hash: &str,
+    ticker: &str,
+    amount: Uint128,
+    destination_addr: String,
+    reward_fee_rate: Decimal,
+    signer_names: Vec<String>,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    // Store the transaction hash to prevent replay attacks
+    HANDLED_TRANSACTIONS.save(deps.storage, transaction_hash, &true)?;
+    // Signatures still being collected for the transaction, including for
+    // other transfers, are no longer needed
+    PENDING_RECEIVES.remove(deps.storage, transaction_hash);
+
+    // Keep track of which signers attested the transaction
+    TRANSACTION_SIGNERS.save(deps.storage, transaction_hash, &signer_names)?;
+    record_signer_participation(deps.storage, env, &signer_names)?;
+
+    let tokenfactory_denom = tokens().load(deps.storage, ticker)?.denom;
+
+    // The counted signers are paid the attested share of the transfer as rewards
+    // Only bonded signers are paid, their rewards are claimed by the owner of
+    // the bond. Without any of them no fee is taken from the transfer
+    let mut rewarded_signers = vec![];
+    for name in &signer_names {
+        if SIGNER_BONDS
+            .may_load(deps.storage, name)?
+            .is_some_and(|bond| !bond.amount.is_zero())
+        {
+            rewarded_signers.push(name.clone());
+        }
+    }
+    let rewards = if rewarded_signers.is_empty() {
+        Uint128::zero()
+    } else {
+        amount * reward_fee_rate
+    };
+    accrue_signer_rewards(
+        deps.storage,
+        &rewarded_signers,
+        &tokenfactory_denom,
+        rewards,
+    )?;
 
     // If ticker already exists, mint new tokens to the destination
-    let coins_to_mint = coin(amount.u128(), tokenfactory_denom);
+    let coins_to_mint = coin((amount - rewards).u128(), &tokenfactory_denom);
 
-    let mint_messages = build_mint_messages(
+    let mut mint_messages = build_mint_messages(
         env.contract.address.to_string(),
         coins_to_mint.clone(),
         destination_addr.clone(),
     );
 
+    // The rewards are held by the contract until the signers claim them
+    if !rewards.is_zero() {
+        mint_messages.push(
+            MsgMint {
+                sender: env.contract.address.to_string(),
+                amount: Some(coin(rewards.u128(), &tokenfactory_denom).into()),
+                mint_to_address: env.contract.address.to_string(),
+            }
+            .into(),
+        );
+    }
+
     Ok(Response::default()
         .add_messages(mint_messages)
         .add_attribute("action", "bridge_receive")
         .add_attribute("tokens", coins_to_mint.to_string())
-        .add_attribute("destination", destination_addr))
+        .add_attribute("destination", destination_addr)
+        .add_attribute("signers", signer_names.join(","))
+        .add_attribute("rewards", rewards.to_string()))
 }
 
 /// Return tokens to the Hub
@@ -397,7 +1250,10 @@ fn bridge_send(
         if coin.denom == FEE_DENOM {
             fee_coin = coin.clone();
         }
-        if TOKEN_MAPPING.has(deps.storage, &coin.denom) {
+        if matches!(
+            tokens().idx.denom.item(deps.storage, coin.denom.clone()),
+            Ok(Some(_))
+        ) {
             bridging_coin = coin.clone();
         }
     });
@@ -412,11 +1268,16 @@ fn bridge_send(
         info.funds.iter().collect::<Vec<_>>()
     ));
 
-    // Check the mapping for this token, fail if no mapping exists
-    let cft20_denom = TOKEN_MAPPING.load(deps.storage, &bridging_coin.denom)?;
+    // Check the registry for this token, fail if it isn't linked
+    let (_, token) = tokens()
+        .idx
+        .denom
+        .item(deps.storage, bridging_coin.denom.clone())?
+        .ok_or_else(|| StdError::not_found("TokenInfo"))?;
+    let cft20_denom = token.metadata.ticker;
 
     // Check if the token is disabled
-    if DISABLED_TOKENS.has(deps.storage, &cft20_denom) {
+    if token.status == TokenStatus::Disabled {
         return Err(ContractError::TokenDisabled {
             ticker: cft20_denom,
         });
@@ -513,113 +1374,1269 @@ fn bridge_send(
 /// Add a signer to the list of allowed public keys
 /// Verifies that the public key can be loaded and in the correct format
 /// as well as checks for duplicate keys
+#[allow(clippy::too_many_arguments)]
 fn add_signer(
     deps: DepsMut<NeutronQuery>,
+    env: Env,
     info: MessageInfo,
     name: String,
     public_key_base64: String,
+    key_type: KeyType,
+    weight: u64,
+    contact_url: Option<String>,
 ) -> Result<Response<NeutronMsg>, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
-    // Only owner can update the config
+    // Only owner can update the signers, until it handed this over to the signers
     if info.sender != config.owner {
         return Err(ContractError::Unauthorized {});
     }
+    if !config.owner_signer_management {
+        return Err(ContractError::OwnerSignerManagementDisabled {});
+    }
+
+    save_signer(
+        deps,
+        env,
+        name,
+        public_key_base64,
+        key_type,
+        weight,
+        contact_url,
+    )
+}
+
+/// Add a signer to the signer set and start a new signer epoch
+fn save_signer(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    name: String,
+    public_key_base64: String,
+    key_type: KeyType,
+    weight: u64,
+    contact_url: Option<String>,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    validate_signer_weight(weight)?;
+    validate_contact_url(&contact_url)?;
+
+    // Signers must have posted the minimum bond before being added
+    let config = CONFIG.load(deps.storage)?;
+    let bonded = SIGNER_BONDS
+        .may_load(deps.storage, &name)?
+        .map(|bond| bond.amount)
+        .unwrap_or_default();
+    if bonded < config.min_signer_bond {
+        return Err(ContractError::InsufficientSignerBond {
+            name,
+            required: config.min_signer_bond,
+        });
+    }
+
+    // Decode the base64 encoded public key
+    let public_key = match general_purpose::STANDARD.decode(public_key_base64.as_bytes()) {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            return Err(ContractError::InvalidConfiguration {
+                reason: "Key could not be decoded".to_string(),
+            })
+        }
+    };
+
+    // Verify that the format for the key is correct before adding it
+    validate_public_key(&public_key, key_type)?;
+
+    // Ensure this key isn't loaded yet
+    if signers().has(deps.storage, &public_key) {
+        return Err(ContractError::InvalidConfiguration {
+            reason: "The public key has already been loaded".to_string(),
+        });
+    }
+
+    // Check that the name isn't already in use
+    if signers()
+        .idx
+        .name
+        .item(deps.storage, name.clone())?
+        .is_some()
+    {
+        return Err(ContractError::InvalidConfiguration {
+            reason: format!("The name '{}' is already linked to a public key", name),
+        });
+    }
+
+    // Signers start without participation, a signer re-added under the same
+    // name keeps its previous stats
+    if !SIGNER_STATS.has(deps.storage, &name) {
+        SIGNER_STATS.save(deps.storage, &name, &SignerStats::default())?;
+    }
+
+    // Keep the current signer set in the history before changing it
+    let signer_epoch = bump_signer_epoch(deps.storage, env.block.time)?;
+    signers().save(
+        deps.storage,
+        &public_key,
+        &SignerInfo {
+            name: name.clone(),
+            key_type,
+            weight,
+            contact_url,
+            added_at: env.block.time,
+            status: SignerStatus::Active,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "add_signer")
+        .add_attribute("name", name)
+        .add_attribute("public_key", public_key_base64)
+        .add_attribute("key_type", key_type.as_str())
+        .add_attribute("weight", weight.to_string())
+        .add_attribute("signer_epoch", signer_epoch.to_string()))
+}
+
+/// Remove a signer from the list of allowed public keys
+fn remove_signer(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    public_key_base64: String,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only owner can update the signers, until it handed this over to the signers
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    if !config.owner_signer_management {
+        return Err(ContractError::OwnerSignerManagementDisabled {});
+    }
+
+    delete_signer(deps, env, public_key_base64)
+}
+
+/// Remove a signer from the signer set and start a new signer epoch
+fn delete_signer(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    public_key_base64: String,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    // Decode the base64 encoded public key
+    let public_key = match general_purpose::STANDARD.decode(public_key_base64.as_bytes()) {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            return Err(ContractError::InvalidConfiguration {
+                reason: "Key could not be decoded".to_string(),
+            })
+        }
+    };
+
+    if !signers().has(deps.storage, &public_key) {
+        return Err(ContractError::InvalidConfiguration {
+            reason: "Key to remove doesn't exist".to_string(),
+        });
+    }
+
+    // Keep the current signer set in the history before changing it
+    let config = CONFIG.load(deps.storage)?;
+    let was_reachable = signer_threshold_reachable(deps.storage, &config.signer_threshold)?;
+    let signer_epoch = bump_signer_epoch(deps.storage, env.block.time)?;
+    signers().remove(deps.storage, &public_key)?;
+
+    // The remaining signers must still be able to meet the threshold
+    validate_signer_set_change(deps.storage, &config.signer_threshold, was_reachable)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "remove_signer")
+        .add_attribute("public_key", public_key_base64)
+        .add_attribute("signer_epoch", signer_epoch.to_string()))
+}
+
+/// Update the voting weight of a signer
+fn update_signer_weight(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    public_key_base64: String,
+    weight: u64,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only owner can update the signers, until it handed this over to the signers
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    if !config.owner_signer_management {
+        return Err(ContractError::OwnerSignerManagementDisabled {});
+    }
+
+    save_signer_weight(deps, env, public_key_base64, weight)
+}
+
+/// Update the voting weight of a signer and start a new signer epoch
+fn save_signer_weight(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    public_key_base64: String,
+    weight: u64,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    validate_signer_weight(weight)?;
+
+    // Decode the base64 encoded public key
+    let public_key = match general_purpose::STANDARD.decode(public_key_base64.as_bytes()) {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            return Err(ContractError::InvalidConfiguration {
+                reason: "Key could not be decoded".to_string(),
+            })
+        }
+    };
+
+    let Some(mut signer) = signers().may_load(deps.storage, &public_key)? else {
+        return Err(ContractError::InvalidConfiguration {
+            reason: "Key to update doesn't exist".to_string(),
+        });
+    };
+
+    // Keep the current signer set in the history before changing it
+    let config = CONFIG.load(deps.storage)?;
+    let was_reachable = signer_threshold_reachable(deps.storage, &config.signer_threshold)?;
+    let signer_epoch = bump_signer_epoch(deps.storage, env.block.time)?;
+    signer.weight = weight;
+    signers().save(deps.storage, &public_key, &signer)?;
+
+    // The signers must still be able to meet the threshold with the new weight
+    validate_signer_set_change(deps.storage, &config.signer_threshold, was_reachable)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "update_signer_weight")
+        .add_attribute("public_key", public_key_base64)
+        .add_attribute("weight", weight.to_string())
+        .add_attribute("signer_epoch", signer_epoch.to_string()))
+}
+
+/// Suspend or reactivate a signer
+fn update_signer_status(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    public_key_base64: String,
+    status: SignerStatus,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only owner can update the signers, until it handed this over to the signers
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    if !config.owner_signer_management {
+        return Err(ContractError::OwnerSignerManagementDisabled {});
+    }
+
+    save_signer_status(deps, env, public_key_base64, status)
+}
+
+/// Update the status of a signer and start a new signer epoch
+fn save_signer_status(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    public_key_base64: String,
+    status: SignerStatus,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    // Decode the base64 encoded public key
+    let public_key = match general_purpose::STANDARD.decode(public_key_base64.as_bytes()) {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            return Err(ContractError::InvalidConfiguration {
+                reason: "Key could not be decoded".to_string(),
+            })
+        }
+    };
+
+    let Some(mut signer) = signers().may_load(deps.storage, &public_key)? else {
+        return Err(ContractError::InvalidConfiguration {
+            reason: "Key to update doesn't exist".to_string(),
+        });
+    };
+    if signer.status == status {
+        return Err(ContractError::InvalidConfiguration {
+            reason: format!("The signer is already {}", status.as_str()),
+        });
+    }
+
+    // Keep the current signer set in the history before changing it
+    // A suspension isn't held to the signer threshold, a compromised signer must
+    // always be suspendable even when the others can't meet the threshold alone
+    let signer_epoch = bump_signer_epoch(deps.storage, env.block.time)?;
+    signer.status = status;
+    signers().save(deps.storage, &public_key, &signer)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "update_signer_status")
+        .add_attribute("name", signer.name)
+        .add_attribute("public_key", public_key_base64)
+        .add_attribute("status", status.as_str())
+        .add_attribute("signer_epoch", signer_epoch.to_string()))
+}
+
+/// Add a signer to the own signer set of a token
+#[allow(clippy::too_many_arguments)]
+fn add_token_signer(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    ticker: String,
+    name: String,
+    public_key_base64: String,
+    key_type: KeyType,
+    weight: u64,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only owner can update the token signers
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // If this token doesn't exist, return an error
+    load_token(deps.storage, &ticker)?;
+
+    validate_signer_weight(weight)?;
+
+    // Decode the base64 encoded public key
+    let public_key = match general_purpose::STANDARD.decode(public_key_base64.as_bytes()) {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            return Err(ContractError::InvalidConfiguration {
+                reason: "Key could not be decoded".to_string(),
+            })
+        }
+    };
+
+    // Verify that the format for the key is correct before adding it
+    validate_public_key(&public_key, key_type)?;
+
+    // Ensure this key isn't loaded for the token yet
+    if TOKEN_SIGNERS.has(deps.storage, (&ticker, &public_key)) {
+        return Err(ContractError::InvalidConfiguration {
+            reason: "The public key has already been loaded".to_string(),
+        });
+    }
+
+    TOKEN_SIGNERS.save(
+        deps.storage,
+        (&ticker, &public_key),
+        &SignerInfo {
+            name: name.clone(),
+            key_type,
+            weight,
+            contact_url: None,
+            added_at: env.block.time,
+            status: SignerStatus::Active,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "add_token_signer")
+        .add_attribute("ticker", ticker)
+        .add_attribute("name", name)
+        .add_attribute("public_key", public_key_base64)
+        .add_attribute("key_type", key_type.as_str())
+        .add_attribute("weight", weight.to_string()))
+}
+
+/// Remove a signer from the own signer set of a token
+fn remove_token_signer(
+    deps: DepsMut<NeutronQuery>,
+    _env: Env,
+    info: MessageInfo,
+    ticker: String,
+    public_key_base64: String,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only owner can update the token signers
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Decode the base64 encoded public key
+    let public_key = match general_purpose::STANDARD.decode(public_key_base64.as_bytes()) {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            return Err(ContractError::InvalidConfiguration {
+                reason: "Key could not be decoded".to_string(),
+            })
+        }
+    };
+
+    if !TOKEN_SIGNERS.has(deps.storage, (&ticker, &public_key)) {
+        return Err(ContractError::InvalidConfiguration {
+            reason: "Key to remove doesn't exist".to_string(),
+        });
+    }
+    TOKEN_SIGNERS.remove(deps.storage, (&ticker, &public_key));
+
+    Ok(Response::default()
+        .add_attribute("action", "remove_token_signer")
+        .add_attribute("ticker", ticker)
+        .add_attribute("public_key", public_key_base64))
+}
+
+/// Set the policy used to calculate the weight required from the own signers of a token
+fn set_token_signer_threshold(
+    deps: DepsMut<NeutronQuery>,
+    _env: Env,
+    info: MessageInfo,
+    ticker: String,
+    signer_threshold: SignerThreshold,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only owner can update the token signers
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // If this token doesn't exist, return an error
+    load_token(deps.storage, &ticker)?;
+
+    validate_signer_threshold(&signer_threshold)?;
+    TOKEN_SIGNER_THRESHOLD.save(deps.storage, &ticker, &signer_threshold)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "set_token_signer_threshold")
+        .add_attribute("ticker", ticker))
+}
+
+/// Replace the key of a signer with a new key, keeping its name and weight
+/// The rotation is authorised by a signature of the old key over the new key
+/// and the rotation nonce of the signer, the old key can stay accepted for
+/// an overlap period
+#[allow(clippy::too_many_arguments)]
+fn rotate_signer_key(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    old_public_key_base64: String,
+    new_public_key_base64: String,
+    new_key_type: KeyType,
+    nonce: u64,
+    signature: String,
+    overlap_seconds: u64,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    if overlap_seconds > MAX_SIGNER_KEY_OVERLAP_SECONDS {
+        return Err(ContractError::InvalidConfiguration {
+            reason: format!(
+                "The signer key overlap can't be more than {} seconds",
+                MAX_SIGNER_KEY_OVERLAP_SECONDS
+            ),
+        });
+    }
+
+    // Decode the base64 encoded public keys
+    let (Ok(old_public_key), Ok(new_public_key)) = (
+        general_purpose::STANDARD.decode(old_public_key_base64.as_bytes()),
+        general_purpose::STANDARD.decode(new_public_key_base64.as_bytes()),
+    ) else {
+        return Err(ContractError::InvalidConfiguration {
+            reason: "Key could not be decoded".to_string(),
+        });
+    };
+
+    let Some(signer) = signers().may_load(deps.storage, &old_public_key)? else {
+        return Err(ContractError::UnknownSigner {
+            signer: old_public_key_base64,
+        });
+    };
+
+    // Verify that the format for the new key is correct before adding it
+    validate_public_key(&new_public_key, new_key_type)?;
+    if signers().has(deps.storage, &new_public_key) {
+        return Err(ContractError::InvalidConfiguration {
+            reason: "The public key has already been loaded".to_string(),
+        });
+    }
+
+    // Every rotation of a signer uses the next nonce so a rotation can't be replayed
+    let expected_nonce = SIGNER_ROTATION_NONCES
+        .may_load(deps.storage, &signer.name)?
+        .unwrap_or_default();
+    if nonce != expected_nonce {
+        return Err(ContractError::InvalidSignerRotationNonce {
+            nonce,
+            expected: expected_nonce,
+        });
+    }
+
+    // The old key must sign the rotation attestation
+    let attestation = Attestation::rotate_signer_key(
+        &env,
+        &old_public_key_base64,
+        &new_public_key_base64,
+        new_key_type,
+        nonce,
+    )
+    .encode(AttestationVersion::V2);
+    verify_signer_approvals(
+        deps.as_ref(),
+        &attestation,
+        &[SignerSignature {
+            signer: SignerReference::PublicKey {
+                public_key_base64: old_public_key_base64.clone(),
+            },
+            signature,
+        }],
+        &[(old_public_key.clone(), signer.clone())],
+    )?;
+    SIGNER_ROTATION_NONCES.save(deps.storage, &signer.name, &(nonce + 1))?;
+
+    // Swap the keys in a single signer epoch
+    let signer_epoch = bump_signer_epoch(deps.storage, env.block.time)?;
+    signers().remove(deps.storage, &old_public_key)?;
+    signers().save(
+        deps.storage,
+        &new_public_key,
+        &SignerInfo {
+            key_type: new_key_type,
+            ..signer.clone()
+        },
+    )?;
+
+    if overlap_seconds > 0 {
+        ROTATED_SIGNER_KEYS.save(
+            deps.storage,
+            &old_public_key,
+            &RotatedSignerKey {
+                new_public_key: Binary::from(new_public_key),
+                expires_at: env.block.time.plus_seconds(overlap_seconds),
+            },
+        )?;
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "rotate_signer_key")
+        .add_attribute("name", signer.name)
+        .add_attribute("old_public_key", old_public_key_base64)
+        .add_attribute("new_public_key", new_public_key_base64)
+        .add_attribute("key_type", new_key_type.as_str())
+        .add_attribute("overlap_seconds", overlap_seconds.to_string())
+        .add_attribute("signer_epoch", signer_epoch.to_string()))
+}
+
+/// Register the address that owns the bond of a signer
+/// Binding the bond to an owner chosen by the owner of the contract keeps
+/// anyone else from claiming the bond and the rewards of a signer name
+fn set_signer_bond_owner(
+    deps: DepsMut<NeutronQuery>,
+    info: MessageInfo,
+    name: String,
+    owner: String,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only owner can set the owner of a bond
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let owner = deps.api.addr_validate(&owner)?;
+
+    // The funds of a bond stay with the address that posted them
+    let bond = match SIGNER_BONDS.may_load(deps.storage, &name)? {
+        Some(bond) if !(bond.amount + bond.unbonding_amount).is_zero() => {
+            return Err(ContractError::InvalidConfiguration {
+                reason: "The bond of the signer still holds funds".to_string(),
+            });
+        }
+        Some(bond) => SignerBond { owner, ..bond },
+        None => SignerBond {
+            owner,
+            amount: Uint128::zero(),
+            unbonding_amount: Uint128::zero(),
+            unbonding_release_at: None,
+        },
+    };
+    SIGNER_BONDS.save(deps.storage, &name, &bond)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "set_signer_bond_owner")
+        .add_attribute("name", name)
+        .add_attribute("owner", bond.owner))
+}
+
+/// Post an untrn bond for a signer
+fn bond_signer(
+    deps: DepsMut<NeutronQuery>,
+    info: MessageInfo,
+    name: String,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    // Only untrn can be bonded
+    let [funds] = info.funds.as_slice() else {
+        return Err(ContractError::InvalidBondFunds {});
+    };
+    if funds.denom != FEE_DENOM || funds.amount.is_zero() {
+        return Err(ContractError::InvalidBondFunds {});
+    }
+
+    // Only the registered owner of the bond can post it
+    let Some(mut bond) = SIGNER_BONDS.may_load(deps.storage, &name)? else {
+        return Err(ContractError::SignerBondNotFound { name });
+    };
+    if bond.owner != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    bond.amount += funds.amount;
+    SIGNER_BONDS.save(deps.storage, &name, &bond)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "bond_signer")
+        .add_attribute("name", name)
+        .add_attribute("amount", funds.amount.to_string())
+        .add_attribute("bonded", bond.amount.to_string()))
+}
+
+/// Start unbonding part of the bond of a signer
+fn unbond_signer(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    amount: Uint128,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let Some(mut bond) = SIGNER_BONDS.may_load(deps.storage, &name)? else {
+        return Err(ContractError::SignerBondNotFound { name });
+    };
+
+    // Only the owner of the bond can unbond it
+    if bond.owner != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    if amount.is_zero() || amount > bond.amount {
+        return Err(ContractError::InvalidConfiguration {
+            reason: format!("The amount to unbond must be between 1 and {}", bond.amount),
+        });
+    }
+
+    // Signers in the signer set must keep the minimum bond
+    let remaining = bond.amount - amount;
+    if remaining < config.min_signer_bond
+        && signers()
+            .idx
+            .name
+            .item(deps.storage, name.clone())?
+            .is_some()
+    {
+        return Err(ContractError::InsufficientSignerBond {
+            name,
+            required: config.min_signer_bond,
+        });
+    }
+
+    // Every unbonding restarts the unbonding period of all unbonding funds
+    let release_at = env.block.time.plus_seconds(config.unbonding_period_seconds);
+    bond.amount = remaining;
+    bond.unbonding_amount += amount;
+    bond.unbonding_release_at = Some(release_at);
+    SIGNER_BONDS.save(deps.storage, &name, &bond)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "unbond_signer")
+        .add_attribute("name", name)
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("release_at", release_at.seconds().to_string()))
+}
+
+/// Withdraw the unbonded funds of a signer once the unbonding period ended
+fn withdraw_signer_bond(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let Some(mut bond) = SIGNER_BONDS.may_load(deps.storage, &name)? else {
+        return Err(ContractError::SignerBondNotFound { name });
+    };
+
+    // Only the owner of the bond can withdraw it
+    if bond.owner != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    let Some(release_at) = bond.unbonding_release_at else {
+        return Err(ContractError::InvalidConfiguration {
+            reason: "There are no unbonded funds to withdraw".to_string(),
+        });
+    };
+    if env.block.time < release_at {
+        return Err(ContractError::UnbondingNotFinished { release_at });
+    }
+
+    let amount = bond.unbonding_amount;
+    bond.unbonding_amount = Uint128::zero();
+    bond.unbonding_release_at = None;
+    SIGNER_BONDS.save(deps.storage, &name, &bond)?;
+
+    Ok(Response::default()
+        .add_message(BankMsg::Send {
+            to_address: bond.owner.to_string(),
+            amount: vec![coin(amount.u128(), FEE_DENOM)],
+        })
+        .add_attribute("action", "withdraw_signer_bond")
+        .add_attribute("name", name)
+        .add_attribute("amount", amount.to_string()))
+}
+
+/// Claim the rewards accrued by a signer, only the address that posted the
+/// bond of the signer can claim them and receives them
+fn claim_rewards(
+    deps: DepsMut<NeutronQuery>,
+    info: MessageInfo,
+    name: String,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let Some(bond) = SIGNER_BONDS.may_load(deps.storage, &name)? else {
+        return Err(ContractError::SignerBondNotFound { name });
+    };
+    if bond.owner != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
 
-    // Decode the base64 encoded public key
-    let public_key = match general_purpose::STANDARD.decode(public_key_base64.as_bytes()) {
-        Ok(bytes) => bytes,
-        Err(_) => {
-            return Err(ContractError::InvalidConfiguration {
-                reason: "Key could not be decoded".to_string(),
-            })
-        }
+    let rewards = SIGNER_REWARDS
+        .prefix(&name)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    if rewards.is_empty() {
+        return Err(ContractError::NoPendingRewards { name });
+    }
+
+    let mut claimed = vec![];
+    for (denom, amount) in rewards {
+        SIGNER_REWARDS.remove(deps.storage, (&name, &denom));
+        claimed.push(coin(amount.u128(), denom));
+    }
+
+    Ok(Response::default()
+        .add_message(BankMsg::Send {
+            to_address: bond.owner.to_string(),
+            amount: claimed.clone(),
+        })
+        .add_attribute("action", "claim_rewards")
+        .add_attribute("name", name)
+        .add_attribute(
+            "rewards",
+            claimed
+                .iter()
+                .map(|coin| coin.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        ))
+}
+
+/// Slash the whole bond of a signer that signed two different transfers for
+/// the same transaction
+///
+/// The owner can slash on its own, anyone else needs the signers to approve
+/// the slash signer attestation. The bond, including the funds still
+/// unbonding, is sent to the treasury and the signer is suspended
+fn slash_signer(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    evidence: SlashEvidence,
+    signer_signatures: Vec<SignerSignature>,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.owner {
+        let attestation = Attestation::slash_signer(&env, &name, &evidence.transaction_hash)
+            .encode(AttestationVersion::V2);
+        verify_signatures(
+            deps.as_ref(),
+            &env,
+            &attestation,
+            &[],
+            &signer_signatures,
+            &[],
+            None,
+            None,
+        )?;
+    }
+
+    let Some(treasury) = config.treasury else {
+        return Err(ContractError::InvalidConfiguration {
+            reason: "No treasury is configured to receive slashed bonds".to_string(),
+        });
     };
 
-    // Verify that the format for the key is correct before adding it
-    let public_key_bytes: [u8; PUBLIC_KEY_LENGTH] = match public_key.clone().try_into() {
-        Ok(bytes) => bytes,
-        Err(_) => {
-            return Err(ContractError::InvalidConfiguration {
-                reason: "Invalid public key length".to_string(),
-            });
+    // The same evidence can't be used twice
+    if SLASHED_EVIDENCE.has(deps.storage, (&name, &evidence.transaction_hash)) {
+        return Err(ContractError::InvalidSlashEvidence {
+            reason: "The evidence has already been used".to_string(),
+        });
+    }
+
+    let keys = load_signer_key_history(deps.storage, &name)?;
+    if keys.is_empty() {
+        return Err(ContractError::UnknownSigner { signer: name });
+    }
+    verify_slash_evidence(deps.as_ref(), &env, &name, &keys, &evidence)?;
+
+    let mut bond = SIGNER_BONDS
+        .may_load(deps.storage, &name)?
+        .filter(|bond| !(bond.amount + bond.unbonding_amount).is_zero())
+        .ok_or_else(|| ContractError::SignerBondNotFound { name: name.clone() })?;
+    let slashed = bond.amount + bond.unbonding_amount;
+    bond.amount = Uint128::zero();
+    bond.unbonding_amount = Uint128::zero();
+    bond.unbonding_release_at = None;
+    SIGNER_BONDS.save(deps.storage, &name, &bond)?;
+    SLASHED_EVIDENCE.save(deps.storage, (&name, &evidence.transaction_hash), &true)?;
+
+    // A slashed signer no longer counts toward the threshold, even if the
+    // others can't meet it without the signer
+    let response = match signers().idx.name.item(deps.storage, name.clone())? {
+        Some((public_key, signer)) if signer.is_active() => save_signer_status(
+            deps,
+            env,
+            general_purpose::STANDARD.encode(&public_key),
+            SignerStatus::Suspended,
+        )?,
+        _ => Response::default(),
+    };
+
+    Ok(response
+        .add_message(BankMsg::Send {
+            to_address: treasury.to_string(),
+            amount: vec![coin(slashed.u128(), FEE_DENOM)],
+        })
+        .add_attribute("action", "slash_signer")
+        .add_attribute("name", name)
+        .add_attribute("transaction_hash", evidence.transaction_hash)
+        .add_attribute("slashed", slashed.to_string()))
+}
+
+/// Propose a change to the signer set along with the first approvals
+fn propose_signer_change(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    change: SignerChange,
+    signer_signatures: Vec<SignerSignature>,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    // Only proposals backed by a signer are stored
+    if signer_signatures.is_empty() {
+        return Err(ContractError::InvalidConfiguration {
+            reason: "A signer change must be approved by at least one signer".to_string(),
+        });
+    }
+
+    let proposal_id = NEXT_SIGNER_CHANGE_ID
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    NEXT_SIGNER_CHANGE_ID.save(deps.storage, &(proposal_id + 1))?;
+
+    let proposal = SignerChangeProposal {
+        change,
+        signer_epoch: SIGNER_EPOCH.load(deps.storage)?,
+        approvals: vec![],
+        proposer: info.sender.clone(),
+    };
+    let response =
+        record_signer_change_approvals(deps, env, proposal_id, proposal, signer_signatures)?;
+
+    Ok(response
+        .add_attribute("action", "propose_signer_change")
+        .add_attribute("proposer", info.sender))
+}
+
+/// Approve a pending signer change proposal
+fn approve_signer_change(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    proposal_id: u64,
+    signer_signatures: Vec<SignerSignature>,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let Some(proposal) = SIGNER_CHANGE_PROPOSALS.may_load(deps.storage, proposal_id)? else {
+        return Err(ContractError::SignerChangeProposalNotFound { proposal_id });
+    };
+    let response =
+        record_signer_change_approvals(deps, env, proposal_id, proposal, signer_signatures)?;
+
+    Ok(response.add_attribute("action", "approve_signer_change"))
+}
+
+/// Verify and record the approvals of a signer change proposal
+/// Once the approving signers carry the signer threshold, the change is applied
+/// and the proposal removed, otherwise the proposal is stored with the approvals
+fn record_signer_change_approvals(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    proposal_id: u64,
+    mut proposal: SignerChangeProposal,
+    signer_signatures: Vec<SignerSignature>,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Approvals are only accepted from the signer set the proposal was made
+    // in, any other change to the signers makes the proposal stale
+    let current_epoch = SIGNER_EPOCH.load(deps.storage)?;
+    if proposal.signer_epoch != current_epoch {
+        return Err(ContractError::InvalidSignerEpoch {
+            epoch: proposal.signer_epoch,
+            current: current_epoch,
+        });
+    }
+
+    // The signers approve the proposal through the v2 signer change attestation
+    let attestation =
+        Attestation::signer_change(&env, proposal_id, proposal.signer_epoch, &proposal.change)?
+            .encode(AttestationVersion::V2);
+    let allowed_keys = load_signer_keys(deps.as_ref(), &env, &config, None)?;
+    let approving_keys = verify_signer_approvals(
+        deps.as_ref(),
+        &attestation,
+        &signer_signatures,
+        &allowed_keys,
+    )?;
+    for public_key in approving_keys {
+        let public_key = Binary::from(public_key);
+        if proposal.approvals.contains(&public_key) {
+            return Err(ContractError::DuplicateSignatures {});
+        }
+        proposal.approvals.push(public_key);
+    }
+
+    let total_weight = allowed_keys
+        .iter()
+        .filter(|(_, signer)| signer.is_active())
+        .map(|(_, signer)| signer.weight)
+        .sum();
+    let approved_weight: u64 = allowed_keys
+        .iter()
+        .filter(|(key, signer)| {
+            signer.is_active()
+                && proposal
+                    .approvals
+                    .iter()
+                    .any(|approval| approval.as_slice() == key.as_slice())
+        })
+        .map(|(_, signer)| signer.weight)
+        .sum();
+    let threshold = get_signer_threshold(&config.signer_threshold, total_weight);
+
+    if (proposal.approvals.len() as u64) < MIN_SIGNER_THRESHOLD || approved_weight < threshold {
+        SIGNER_CHANGE_PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+        return Ok(Response::default()
+            .add_attribute("proposal_id", proposal_id.to_string())
+            .add_attribute("approved_weight", approved_weight.to_string())
+            .add_attribute("threshold", threshold.to_string()));
+    }
+
+    // The signers approved the change, apply it to the signer set
+    SIGNER_CHANGE_PROPOSALS.remove(deps.storage, proposal_id);
+    let response = match proposal.change {
+        SignerChange::AddSigner {
+            public_key_base64,
+            name,
+            key_type,
+            weight,
+            contact_url,
+        } => save_signer(
+            deps,
+            env,
+            name,
+            public_key_base64,
+            key_type.unwrap_or_default(),
+            weight.unwrap_or(DEFAULT_SIGNER_WEIGHT),
+            contact_url,
+        )?,
+        SignerChange::RemoveSigner { public_key_base64 } => {
+            delete_signer(deps, env, public_key_base64)?
+        }
+        SignerChange::UpdateSignerWeight {
+            public_key_base64,
+            weight,
+        } => save_signer_weight(deps, env, public_key_base64, weight)?,
+        SignerChange::UpdateSignerStatus {
+            public_key_base64,
+            status,
+        } => save_signer_status(deps, env, public_key_base64, status)?,
+        SignerChange::UpdateSignerThreshold { signer_threshold } => {
+            let mut config = config;
+            set_signer_threshold(deps.storage, &mut config, signer_threshold)?;
+            CONFIG.save(deps.storage, &config)?;
+            Response::default()
         }
     };
-    VerifyingKey::from_bytes(&public_key_bytes)?;
 
-    // Ensure this key isn't loaded yet
-    if SIGNERS.has(deps.storage, &public_key) {
+    Ok(response
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("approved_weight", approved_weight.to_string())
+        .add_attribute("threshold", threshold.to_string())
+        .add_attribute("applied", "true"))
+}
+
+/// Permanently hand the management of the signer set over to the signers
+fn disable_owner_signer_management(
+    deps: DepsMut<NeutronQuery>,
+    info: MessageInfo,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    // Only owner can update the config
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if !config.owner_signer_management {
+        return Err(ContractError::OwnerSignerManagementDisabled {});
+    }
+
+    // The signers must be able to approve changes without the owner
+    if !signer_threshold_reachable(deps.storage, &config.signer_threshold)? {
         return Err(ContractError::InvalidConfiguration {
-            reason: "The public key has already been loaded".to_string(),
+            reason: "The signer threshold can't be met by the current signers".to_string(),
         });
     }
 
-    // Check that the name isn't already in use
-    // Note that with an excessive amount of signers, this may run out of gas
-    SIGNERS
-        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
-        .try_for_each(|item| {
-            if let Ok((_, signer_name)) = item {
-                if signer_name == name {
-                    return Err(ContractError::InvalidConfiguration {
-                        reason: format!("The name '{}' is already linked to a public key", name),
-                    });
-                }
-            }
-            Ok(())
-        })?;
+    config.owner_signer_management = false;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::default().add_attribute("action", "disable_owner_signer_management"))
+}
+
+/// Validate and set the signer threshold in the config
+/// The threshold is validated against the currently loaded signers so the
+/// bridge can never end up in a state where it can't be satisfied
+fn set_signer_threshold(
+    storage: &dyn Storage,
+    config: &mut Config,
+    signer_threshold: SignerThreshold,
+) -> Result<(), ContractError> {
+    validate_signer_threshold(&signer_threshold)?;
+
+    if !signer_threshold_reachable(storage, &signer_threshold)? {
+        return Err(ContractError::InvalidConfiguration {
+            reason: "The signer threshold can't be met by the current signers".to_string(),
+        });
+    }
+    config.signer_threshold = signer_threshold;
+    Ok(())
+}
 
-    SIGNERS.save(deps.storage, &public_key, &name)?;
+/// Queue an admin action, or apply it right away when no delay is configured
+fn schedule_admin_action(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    action: AdminAction,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only owner can queue admin actions
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if config.admin_action_delay_seconds == 0 {
+        return apply_admin_action(deps, env, info, action);
+    }
+
+    let id = NEXT_ADMIN_ACTION_ID
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    NEXT_ADMIN_ACTION_ID.save(deps.storage, &(id + 1))?;
+
+    // The action can only be executed once the delay has passed, giving
+    // users and watchers time to react
+    let executable_at = env
+        .block
+        .time
+        .plus_seconds(config.admin_action_delay_seconds);
+    ADMIN_ACTIONS.save(
+        deps.storage,
+        id,
+        &QueuedAdminAction {
+            action: action.clone(),
+            queued_at: env.block.time,
+            executable_at,
+        },
+    )?;
 
     Ok(Response::default()
-        .add_attribute("action", "add_signer")
-        .add_attribute("name", name)
-        .add_attribute("public_key", public_key_base64))
+        .add_attribute("action", "queue_admin_action")
+        .add_attribute("admin_action", action.as_str())
+        .add_attribute("id", id.to_string())
+        .add_attribute("executable_at", executable_at.seconds().to_string()))
 }
 
-/// Remove a signer from the list of allowed public keys
-fn remove_signer(
+/// Execute a queued admin action once its delay has passed
+fn execute_admin_action(
     deps: DepsMut<NeutronQuery>,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
-    public_key_base64: String,
+    id: u64,
 ) -> Result<Response<NeutronMsg>, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
-    // Only owner can update the config
+    // Only owner can execute admin actions
     if info.sender != config.owner {
         return Err(ContractError::Unauthorized {});
     }
 
-    // Decode the base64 encoded public key
-    let public_key = match general_purpose::STANDARD.decode(public_key_base64.as_bytes()) {
-        Ok(bytes) => bytes,
-        Err(_) => {
-            return Err(ContractError::InvalidConfiguration {
-                reason: "Key could not be decoded".to_string(),
-            })
-        }
+    let Some(queued_action) = ADMIN_ACTIONS.may_load(deps.storage, id)? else {
+        return Err(ContractError::AdminActionNotFound { id });
     };
-
-    if !SIGNERS.has(deps.storage, &public_key) {
-        return Err(ContractError::InvalidConfiguration {
-            reason: "Key to remove doesn't exist".to_string(),
+    if env.block.time < queued_action.executable_at {
+        return Err(ContractError::AdminActionNotReady {
+            id,
+            executable_at: queued_action.executable_at,
         });
     }
+    ADMIN_ACTIONS.remove(deps.storage, id);
+
+    let response = apply_admin_action(deps, env, info, queued_action.action)?;
+    Ok(response.add_attribute("admin_action_id", id.to_string()))
+}
+
+/// Cancel a queued admin action
+fn cancel_admin_action(
+    deps: DepsMut<NeutronQuery>,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only owner can cancel admin actions
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
 
-    SIGNERS.remove(deps.storage, &public_key);
+    let Some(queued_action) = ADMIN_ACTIONS.may_load(deps.storage, id)? else {
+        return Err(ContractError::AdminActionNotFound { id });
+    };
+    ADMIN_ACTIONS.remove(deps.storage, id);
 
     Ok(Response::default()
-        .add_attribute("action", "remove_signer")
-        .add_attribute("public_key", public_key_base64))
+        .add_attribute("action", "cancel_admin_action")
+        .add_attribute("admin_action", queued_action.action.as_str())
+        .add_attribute("id", id.to_string()))
+}
+
+/// Apply an admin action, the action itself checks it is still allowed
+fn apply_admin_action(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    action: AdminAction,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    match action {
+        AdminAction::UpdateConfig {
+            bridge_ibc_channel,
+            ibc_timeout_seconds,
+            signer_threshold,
+            signer_epoch_grace_seconds,
+            v1_attestations_enabled,
+            admin_action_delay_seconds,
+            pending_receive_max_age_seconds,
+            min_signer_bond,
+            unbonding_period_seconds,
+            treasury,
+            reward_fee_rate,
+        } => update_config(
+            deps,
+            info,
+            bridge_ibc_channel,
+            ibc_timeout_seconds,
+            signer_threshold,
+            signer_epoch_grace_seconds,
+            v1_attestations_enabled,
+            admin_action_delay_seconds,
+            pending_receive_max_age_seconds,
+            min_signer_bond,
+            unbonding_period_seconds,
+            treasury,
+            reward_fee_rate,
+        ),
+        AdminAction::AddSigner {
+            public_key_base64,
+            name,
+            key_type,
+            weight,
+            contact_url,
+        } => add_signer(
+            deps,
+            env,
+            info,
+            name,
+            public_key_base64,
+            key_type.unwrap_or_default(),
+            weight.unwrap_or(DEFAULT_SIGNER_WEIGHT),
+            contact_url,
+        ),
+        AdminAction::RemoveSigner { public_key_base64 } => {
+            remove_signer(deps, env, info, public_key_base64)
+        }
+        AdminAction::UpdateSignerWeight {
+            public_key_base64,
+            weight,
+        } => update_signer_weight(deps, env, info, public_key_base64, weight),
+        AdminAction::SetSignerBondOwner { name, owner } => {
+            set_signer_bond_owner(deps, info, name, owner)
+        }
+        AdminAction::ReactivateSigner { public_key_base64 } => {
+            update_signer_status(deps, env, info, public_key_base64, SignerStatus::Active)
+        }
+        AdminAction::EnableToken { ticker } => enable_token(deps, env, info, ticker),
+        AdminAction::UpdateTokenMetadata { token } => {
+            override_token_metadata(deps, env, info, token)
+        }
+        AdminAction::SetAmountTiers { ticker, tiers } => {
+            set_amount_tiers(deps, env, info, ticker, tiers)
+        }
+        AdminAction::AddTokenSigner {
+            ticker,
+            public_key_base64,
+            name,
+            key_type,
+            weight,
+        } => add_token_signer(
+            deps,
+            env,
+            info,
+            ticker,
+            name,
+            public_key_base64,
+            key_type.unwrap_or_default(),
+            weight.unwrap_or(DEFAULT_SIGNER_WEIGHT),
+        ),
+        AdminAction::RemoveTokenSigner {
+            ticker,
+            public_key_base64,
+        } => remove_token_signer(deps, env, info, ticker, public_key_base64),
+        AdminAction::SetTokenSignerThreshold {
+            ticker,
+            signer_threshold,
+        } => set_token_signer_threshold(deps, env, info, ticker, signer_threshold),
+    }
 }
 
 /// Update the Bridge config
+#[allow(clippy::too_many_arguments)]
 fn update_config(
     deps: DepsMut<NeutronQuery>,
     info: MessageInfo,
     bridge_ibc_channel: Option<String>,
     ibc_timeout_seconds: Option<u64>,
+    signer_threshold: Option<SignerThreshold>,
+    signer_epoch_grace_seconds: Option<u64>,
+    v1_attestations_enabled: Option<bool>,
+    admin_action_delay_seconds: Option<u64>,
+    pending_receive_max_age_seconds: Option<u64>,
+    min_signer_bond: Option<Uint128>,
+    unbonding_period_seconds: Option<u64>,
+    treasury: Option<String>,
+    reward_fee_rate: Option<Decimal>,
 ) -> Result<Response<NeutronMsg>, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
 
@@ -655,6 +2672,95 @@ fn update_config(
         config.ibc_timeout_seconds = ibc_timeout_seconds;
     }
 
+    // Once handed over, only the signers can change the signer threshold
+    if let Some(signer_threshold) = signer_threshold {
+        if !config.owner_signer_management {
+            return Err(ContractError::OwnerSignerManagementDisabled {});
+        }
+        set_signer_threshold(deps.storage, &mut config, signer_threshold)?;
+    }
+
+    // Validate the maximum grace period for a replaced signer set
+    if let Some(signer_epoch_grace_seconds) = signer_epoch_grace_seconds {
+        if signer_epoch_grace_seconds > MAX_SIGNER_EPOCH_GRACE_SECONDS {
+            return Err(ContractError::InvalidConfiguration {
+                reason: format!(
+                    "The signer epoch grace period can't be more than {} seconds",
+                    MAX_SIGNER_EPOCH_GRACE_SECONDS
+                ),
+            });
+        }
+        config.signer_epoch_grace_seconds = signer_epoch_grace_seconds;
+    }
+
+    // Allow disabling the legacy attestations once all signers moved to v2
+    if let Some(v1_attestations_enabled) = v1_attestations_enabled {
+        config.v1_attestations_enabled = v1_attestations_enabled;
+    }
+
+    // Validate the maximum delay of admin actions
+    if let Some(admin_action_delay_seconds) = admin_action_delay_seconds {
+        if admin_action_delay_seconds > MAX_ADMIN_ACTION_DELAY_SECONDS {
+            return Err(ContractError::InvalidConfiguration {
+                reason: format!(
+                    "The admin action delay can't be more than {} seconds",
+                    MAX_ADMIN_ACTION_DELAY_SECONDS
+                ),
+            });
+        }
+        config.admin_action_delay_seconds = admin_action_delay_seconds;
+    }
+
+    // Validate the maximum age of pending receives
+    if let Some(pending_receive_max_age_seconds) = pending_receive_max_age_seconds {
+        if pending_receive_max_age_seconds == 0
+            || pending_receive_max_age_seconds > MAX_PENDING_RECEIVE_MAX_AGE_SECONDS
+        {
+            return Err(ContractError::InvalidConfiguration {
+                reason: format!(
+                    "The pending receive age must be between 1 and {} seconds",
+                    MAX_PENDING_RECEIVE_MAX_AGE_SECONDS
+                ),
+            });
+        }
+        config.pending_receive_max_age_seconds = pending_receive_max_age_seconds;
+    }
+
+    // Signers already in the signer set keep their current bond
+    if let Some(min_signer_bond) = min_signer_bond {
+        config.min_signer_bond = min_signer_bond;
+    }
+
+    // Validate the maximum unbonding period
+    if let Some(unbonding_period_seconds) = unbonding_period_seconds {
+        if unbonding_period_seconds > MAX_UNBONDING_PERIOD_SECONDS {
+            return Err(ContractError::InvalidConfiguration {
+                reason: format!(
+                    "The unbonding period can't be more than {} seconds",
+                    MAX_UNBONDING_PERIOD_SECONDS
+                ),
+            });
+        }
+        config.unbonding_period_seconds = unbonding_period_seconds;
+    }
+
+    if let Some(treasury) = treasury {
+        config.treasury = Some(deps.api.addr_validate(&treasury)?);
+    }
+
+    // Validate the maximum share of transfers paid as rewards
+    if let Some(reward_fee_rate) = reward_fee_rate {
+        if reward_fee_rate > MAX_REWARD_FEE_RATE {
+            return Err(ContractError::InvalidConfiguration {
+                reason: format!(
+                    "The reward fee rate can't be more than {}",
+                    MAX_REWARD_FEE_RATE
+                ),
+            });
+        }
+        config.reward_fee_rate = reward_fee_rate;
+    }
+
     CONFIG.save(deps.storage, &config)?;
 
     Ok(Response::default().add_attribute("action", "update_config"))
@@ -713,19 +2819,22 @@ mod testing {
         )
         .unwrap();
 
-        TOKEN_MAPPING
+        tokens()
             .save(
                 deps.as_mut().storage,
                 "TESTTOKEN",
-                &"factory/contract0/TESTTOKEN".to_string(),
-            )
-            .unwrap();
-
-        TOKEN_MAPPING
-            .save(
-                deps.as_mut().storage,
-                "factory/contract0/TESTTOKEN",
-                &"TESTTOKEN".to_string(),
+                &TokenInfo {
+                    metadata: TokenMetadata {
+                        ticker: "TESTTOKEN".to_string(),
+                        name: "TestToken".to_string(),
+                        image_url: "https://example.com".to_string(),
+                        decimals: 6,
+                    },
+                    denom: "factory/contract0/TESTTOKEN".to_string(),
+                    source_chain_id: "localgaia-1".to_string(),
+                    link_height: env.block.height,
+                    status: TokenStatus::Enabled,
+                },
             )
             .unwrap();
 
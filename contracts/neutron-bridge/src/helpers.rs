@@ -1,22 +1,59 @@
 use base64::{engine::general_purpose, Engine as _};
 use cosmwasm_std::{
-    BankMsg, ChannelResponse, Coin, CosmosMsg, Deps, IbcQuery, Order, QuerierWrapper,
+    BankMsg, Binary, ChannelResponse, Coin, CosmosMsg, Decimal, Deps, Env, IbcQuery, Order,
+    QuerierWrapper, StdResult, Storage, Timestamp, Uint128,
 };
+use ed25519_dalek::{VerifyingKey, PUBLIC_KEY_LENGTH};
 use neutron_sdk::bindings::{msg::NeutronMsg, query::NeutronQuery};
-use osmosis_std::types::osmosis::tokenfactory::v1beta1::MsgMint;
+use osmosis_std::types::cosmos::bank::v1beta1::{DenomUnit, Metadata};
+use osmosis_std::types::osmosis::tokenfactory::v1beta1::{MsgMint, MsgSetDenomMetadata};
+use sha2::{Digest, Sha256};
 
-use crate::{error::ContractError, state::SIGNERS, types::MIN_SIGNER_THRESHOLD};
+use crate::{
+    attestation::{adr036_sign_doc, adr036_signer_address, Attestation},
+    error::ContractError,
+    state::{
+        signers, tokens, CONFIG, HANDLED_TRANSACTIONS, ROTATED_SIGNER_KEYS, SIGNER_EPOCH,
+        SIGNER_REWARDS, SIGNER_SET_HISTORY, SIGNER_STATS, TOKEN_SIGNERS, TOKEN_SIGNER_THRESHOLD,
+    },
+    types::{
+        Adr036Signature, AmountTier, AttestationVersion, Config, KeyType, SignatureKind,
+        SignatureMatch, SignerInfo, SignerReference, SignerSet, SignerSignature, SignerThreshold,
+        SlashEvidence, TokenInfo, TokenMetadata, TokenStatus, MAX_AMOUNT_TIERS,
+        MAX_SIGNER_CONTACT_URL_LENGTH, MAX_SIGNER_WEIGHT, MIN_SIGNER_THRESHOLD,
+        SECP256K1_PUBLIC_KEY_LENGTH,
+    },
+};
 
-/// Verify the signatures against the current loaded public keys
-/// Once we reach the valid threshold, we return Ok
+/// Verify the signatures against the public keys of the given signer epoch
+/// If no epoch is given, the current loaded public keys are used
+/// ed25519 keys verify the message itself, secp256k1 keys verify its sha256 digest
+/// and both count toward the same threshold
+/// Signer signatures name the key that signed and are checked exactly once,
+/// the ed25519 ones together in a single batch. An unknown key or a signature
+/// that doesn't match its key is rejected
+/// ADR-036 signatures name their secp256k1 key and are verified over the
+/// sign-doc wrapping the message, each key is only counted once
+/// Every valid signature adds the weight of its signer, once the total weight
+/// reaches the threshold and at least MIN_SIGNER_THRESHOLD signers signed, we return
+/// the names of the signers that were counted. Signatures left once the threshold
+/// is met aren't verified, signer signatures are always all counted
+/// If an amount tier is given and it requires more weight than the configured
+/// threshold, the weight of the tier is required instead
 /// If we don't have enough valid signatures, we return Err
+#[allow(clippy::too_many_arguments)]
 pub fn verify_signatures(
     deps: Deps<NeutronQuery>,
+    env: &Env,
     message: &[u8],
     signatures: &[String],
-) -> Result<(), ContractError> {
+    signer_signatures: &[SignerSignature],
+    adr036_signatures: &[Adr036Signature],
+    signer_epoch: Option<u64>,
+    amount_tier: Option<&AmountTier>,
+) -> Result<Vec<String>, ContractError> {
     // If no signatures were sent, fail the verification
-    if signatures.is_empty() {
+    if signatures.is_empty() && signer_signatures.is_empty() && adr036_signatures.is_empty() {
         return Err(ContractError::ThresholdNotMet {});
     }
 
@@ -28,19 +65,147 @@ pub fn verify_signatures(
         return Err(ContractError::DuplicateSignatures {});
     }
 
-    // Calculate the threshold based on the number of signers
-    let keys = SIGNERS.keys(deps.storage, None, None, Order::Ascending);
-    let majority_threshold = get_supermajority_threshold(keys.count());
+    // Every ADR-036 signature must come from a different key
+    let mut adr036_keys = adr036_signatures
+        .iter()
+        .map(|signature| signature.public_key_base64.as_str())
+        .collect::<Vec<_>>();
+    adr036_keys.sort();
+    adr036_keys.dedup();
+    if adr036_keys.len() != adr036_signatures.len() {
+        return Err(ContractError::DuplicateSignatures {});
+    }
+
+    // Load the allowed public keys for the requested signer epoch
+    let (allowed_keys, weight_threshold) =
+        load_verification_keys(deps, env, signer_epoch, amount_tier)?;
 
-    // If the number of unique signatures are less than the threshold, fail the verification
-    if unique_signatures.len() < majority_threshold.into() {
+    // Regardless of their weight, a minimum amount of signers must sign
+    // If less signatures were sent, fail the verification
+    let signatures_count =
+        unique_signatures.len() + signer_signatures.len() + adr036_signatures.len();
+    if (signatures_count as u64) < MIN_SIGNER_THRESHOLD {
         return Err(ContractError::ThresholdNotMet {});
     }
 
-    // Load the current allowed public keys
-    let allowed_keys = SIGNERS.keys(deps.storage, None, None, Order::Ascending);
+    let message_hash = Sha256::digest(message);
+    let mut verified_signatures = 0u64;
+    let mut verified_weight = 0u64;
+    // Signers are counted by name, a rotated signer signing with both its
+    // keys is only counted once
+    let mut verified_signers: Vec<&str> = vec![];
+    let threshold_met = |verified_signatures: u64, verified_weight: u64| {
+        verified_signatures >= MIN_SIGNER_THRESHOLD && verified_weight >= weight_threshold
+    };
 
-    let mut verified_signatures = 0;
+    // Resolve the key of every signature paired with its signer
+    let mut paired_signatures = vec![];
+    for signer_signature in signer_signatures {
+        let (allowed_key, signer) = resolve_signer(&allowed_keys, &signer_signature.signer)?;
+
+        // The same signer can't be named twice
+        if verified_signers.contains(&signer.name.as_str()) {
+            return Err(ContractError::DuplicateSignatures {});
+        }
+        verified_signers.push(&signer.name);
+
+        let signature = general_purpose::STANDARD.decode(&signer_signature.signature)?;
+        paired_signatures.push((signer_signature, allowed_key, signer, signature));
+    }
+
+    // secp256k1 signatures can't be batched and are checked one by one
+    let (ed25519_signatures, secp256k1_signatures): (Vec<_>, Vec<_>) = paired_signatures
+        .iter()
+        .partition(|(_, _, signer, _)| signer.key_type == KeyType::Ed25519);
+    for (signer_signature, public_key, _, signature) in &secp256k1_signatures {
+        if !deps
+            .api
+            .secp256k1_verify(&message_hash, signature, public_key)
+            .unwrap_or(false)
+        {
+            return Err(ContractError::InvalidSignerSignature {
+                signer: signer_signature.signer.to_string(),
+            });
+        }
+    }
+
+    // All ed25519 signatures are checked in one batch, which is much cheaper
+    // per signature. If the batch fails, the signatures are checked one by one
+    // to find the signer that made the invalid signature
+    if !ed25519_signatures.is_empty() {
+        let signatures = ed25519_signatures
+            .iter()
+            .map(|(_, _, _, signature)| signature.as_slice())
+            .collect::<Vec<_>>();
+        let public_keys = ed25519_signatures
+            .iter()
+            .map(|(_, public_key, _, _)| public_key.as_slice())
+            .collect::<Vec<_>>();
+        if !deps
+            .api
+            .ed25519_batch_verify(&[message], &signatures, &public_keys)?
+        {
+            for (signer_signature, public_key, _, signature) in &ed25519_signatures {
+                if !deps.api.ed25519_verify(message, signature, public_key)? {
+                    return Err(ContractError::InvalidSignerSignature {
+                        signer: signer_signature.signer.to_string(),
+                    });
+                }
+            }
+        }
+    }
+
+    for (_, _, signer, _) in &paired_signatures {
+        verified_signatures += 1;
+        verified_weight += signer.weight;
+    }
+    if threshold_met(verified_signatures, verified_weight) {
+        return Ok(verified_signers
+            .iter()
+            .map(|name| name.to_string())
+            .collect());
+    }
+
+    // Verify the ADR-036 signatures, they name the key that signed so
+    // each of them is only checked once
+    for adr036_signature in adr036_signatures {
+        let signer_reference = SignerReference::PublicKey {
+            public_key_base64: adr036_signature.public_key_base64.clone(),
+        };
+        let (allowed_key, signer) = resolve_signer(&allowed_keys, &signer_reference)?;
+
+        // Only secp256k1 keys can sign ADR-036 sign-docs
+        if signer.key_type != KeyType::Secp256k1 {
+            return Err(ContractError::InvalidSignerSignature {
+                signer: signer_reference.to_string(),
+            });
+        }
+
+        // Signers that already signed aren't counted twice
+        if verified_signers.contains(&signer.name.as_str()) {
+            continue;
+        }
+
+        // The sign-doc is rebuilt for the address of the signer's own key,
+        // so a sign-doc signed for any other account isn't accepted
+        let sign_doc = adr036_sign_doc(&adr036_signer_address(allowed_key)?, message)?;
+        let signature = general_purpose::STANDARD.decode(&adr036_signature.signature)?;
+        if deps
+            .api
+            .secp256k1_verify(&Sha256::digest(sign_doc), &signature, allowed_key)
+            .unwrap_or(false)
+        {
+            verified_signers.push(&signer.name);
+            verified_signatures += 1;
+            verified_weight += signer.weight;
+            if threshold_met(verified_signatures, verified_weight) {
+                return Ok(verified_signers
+                    .iter()
+                    .map(|name| name.to_string())
+                    .collect());
+            }
+        }
+    }
 
     // Decode signatures from base64
     let decoded_signatures: Result<Vec<_>, _> = unique_signatures
@@ -54,18 +219,31 @@ pub fn verify_signatures(
     // and thus we should not keep too many keys loaded
     //
     // While there is a possbility for this to be unbounded, the number of keys
-    // will be small. The decision to use this method vs
-    // sending keys with their signatures was made to simplify interactions
-    // with the contract
-    for loaded_key in allowed_keys {
-        let allowed_key = loaded_key?;
-
+    // will be small. Relayers that want to avoid this cost can send
+    // signer signatures instead, which name the key of every signature
+    for (allowed_key, signer) in &allowed_keys {
+        // Signers that already signed or are suspended aren't counted
+        if verified_signers.contains(&signer.name.as_str()) || !signer.is_active() {
+            continue;
+        }
         for signature in &decoded_signatures {
-            let is_valid = deps.api.ed25519_verify(message, signature, &allowed_key)?;
+            let is_valid = verify_signature_with_key(
+                deps,
+                message,
+                &message_hash,
+                signature,
+                allowed_key,
+                &signer.key_type,
+            )?;
             if is_valid {
+                verified_signers.push(&signer.name);
                 verified_signatures += 1;
-                if verified_signatures == majority_threshold {
-                    return Ok(());
+                verified_weight += signer.weight;
+                if threshold_met(verified_signatures, verified_weight) {
+                    return Ok(verified_signers
+                        .iter()
+                        .map(|name| name.to_string())
+                        .collect());
                 }
                 // We can move on to the next key and signatures
                 break;
@@ -76,6 +254,564 @@ pub fn verify_signatures(
     Err(ContractError::ThresholdNotMet {})
 }
 
+/// Load the keys signatures are verified against for the given signer epoch,
+/// along with the weight the valid signatures must reach
+/// The threshold is based on the configured policy and the total weight of
+/// the active signers, or on the amount tier if it requires more weight
+pub fn load_verification_keys(
+    deps: Deps<NeutronQuery>,
+    env: &Env,
+    signer_epoch: Option<u64>,
+    amount_tier: Option<&AmountTier>,
+) -> Result<(Vec<(Vec<u8>, SignerInfo)>, u64), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut allowed_keys = load_signer_keys(deps, env, &config, signer_epoch)?;
+    let total_weight = allowed_keys
+        .iter()
+        .filter(|(_, signer)| signer.is_active())
+        .map(|(_, signer)| signer.weight)
+        .sum();
+
+    // Keys rotated out of the current signer set are still accepted during
+    // their overlap period, they count as the signer that rotated them
+    let current_epoch = SIGNER_EPOCH.load(deps.storage)?;
+    if signer_epoch.unwrap_or(current_epoch) == current_epoch {
+        allowed_keys.extend(load_rotated_signer_keys(deps.storage, env)?);
+    }
+
+    let mut weight_threshold = get_signer_threshold(&config.signer_threshold, total_weight);
+    if let Some(amount_tier) = amount_tier {
+        let tier_threshold = SignerThreshold::Percentage {
+            percentage: amount_tier.percentage,
+        };
+        weight_threshold =
+            weight_threshold.max(get_signer_threshold(&tier_threshold, total_weight));
+    }
+    Ok((allowed_keys, weight_threshold))
+}
+
+/// Match every submitted signature to the signer that made it, without
+/// failing on the first invalid one
+/// Unlike verify_signatures, every signature is checked and a malformed one
+/// just doesn't match, so this is only meant to diagnose a submission
+pub fn match_signatures(
+    deps: Deps<NeutronQuery>,
+    message: &[u8],
+    signatures: &[String],
+    signer_signatures: &[SignerSignature],
+    adr036_signatures: &[Adr036Signature],
+    allowed_keys: &[(Vec<u8>, SignerInfo)],
+) -> Vec<SignatureMatch> {
+    let message_hash = Sha256::digest(message);
+    let mut matches = vec![];
+
+    for (index, signature) in signatures.iter().enumerate() {
+        let mut signer = None;
+        if let Ok(signature) = general_purpose::STANDARD.decode(signature) {
+            for (allowed_key, allowed_signer) in allowed_keys {
+                if allowed_signer.is_active()
+                    && verify_signature_with_key(
+                        deps,
+                        message,
+                        &message_hash,
+                        &signature,
+                        allowed_key,
+                        &allowed_signer.key_type,
+                    )
+                    .unwrap_or(false)
+                {
+                    signer = Some(allowed_signer.name.clone());
+                    break;
+                }
+            }
+        }
+        matches.push(SignatureMatch {
+            kind: SignatureKind::Signature,
+            index: index as u32,
+            signer,
+        });
+    }
+
+    for (index, signer_signature) in signer_signatures.iter().enumerate() {
+        let mut signer = None;
+        if let (Ok((allowed_key, allowed_signer)), Ok(signature)) = (
+            resolve_signer(allowed_keys, &signer_signature.signer),
+            general_purpose::STANDARD.decode(&signer_signature.signature),
+        ) {
+            if verify_signature_with_key(
+                deps,
+                message,
+                &message_hash,
+                &signature,
+                allowed_key,
+                &allowed_signer.key_type,
+            )
+            .unwrap_or(false)
+            {
+                signer = Some(allowed_signer.name.clone());
+            }
+        }
+        matches.push(SignatureMatch {
+            kind: SignatureKind::SignerSignature,
+            index: index as u32,
+            signer,
+        });
+    }
+
+    for (index, adr036_signature) in adr036_signatures.iter().enumerate() {
+        let mut signer = None;
+        let public_key = general_purpose::STANDARD
+            .decode(&adr036_signature.public_key_base64)
+            .unwrap_or_default();
+        let allowed = allowed_keys.iter().find(|(key, signer)| {
+            key == &public_key && signer.key_type == KeyType::Secp256k1 && signer.is_active()
+        });
+        if let (Some((allowed_key, allowed_signer)), Ok(signature)) = (
+            allowed,
+            general_purpose::STANDARD.decode(&adr036_signature.signature),
+        ) {
+            if let Ok(sign_doc) = adr036_signer_address(allowed_key)
+                .and_then(|signer_address| adr036_sign_doc(&signer_address, message))
+            {
+                if deps
+                    .api
+                    .secp256k1_verify(&Sha256::digest(sign_doc), &signature, allowed_key)
+                    .unwrap_or(false)
+                {
+                    signer = Some(allowed_signer.name.clone());
+                }
+            }
+        }
+        matches.push(SignatureMatch {
+            kind: SignatureKind::Adr036Signature,
+            index: index as u32,
+            signer,
+        });
+    }
+
+    matches
+}
+
+/// Find the key and the signer info of a signer reference in the signer set
+/// Suspended signers are rejected
+pub fn resolve_signer<'a>(
+    allowed_keys: &'a [(Vec<u8>, SignerInfo)],
+    signer: &SignerReference,
+) -> Result<&'a (Vec<u8>, SignerInfo), ContractError> {
+    let resolved = match signer {
+        SignerReference::PublicKey { public_key_base64 } => {
+            let public_key = general_purpose::STANDARD.decode(public_key_base64)?;
+            allowed_keys.iter().find(|(key, _)| key == &public_key)
+        }
+        SignerReference::Index { index } => allowed_keys.get(*index as usize),
+    }
+    .ok_or_else(|| ContractError::UnknownSigner {
+        signer: signer.to_string(),
+    })?;
+
+    if !resolved.1.is_active() {
+        return Err(ContractError::SignerSuspended {
+            signer: signer.to_string(),
+        });
+    }
+    Ok(resolved)
+}
+
+/// Verify the approvals of signers over a message one by one
+/// Every signature must come from a different signer of the signer set and
+/// be valid, the public keys of the approving signers are returned
+pub fn verify_signer_approvals(
+    deps: Deps<NeutronQuery>,
+    message: &[u8],
+    signer_signatures: &[SignerSignature],
+    allowed_keys: &[(Vec<u8>, SignerInfo)],
+) -> Result<Vec<Vec<u8>>, ContractError> {
+    let message_hash = Sha256::digest(message);
+    let mut approving_keys: Vec<Vec<u8>> = vec![];
+    for signer_signature in signer_signatures {
+        let (allowed_key, signer) = resolve_signer(allowed_keys, &signer_signature.signer)?;
+
+        // The same signer can't approve twice
+        if approving_keys.contains(allowed_key) {
+            return Err(ContractError::DuplicateSignatures {});
+        }
+
+        let signature = general_purpose::STANDARD.decode(&signer_signature.signature)?;
+        if !verify_signature_with_key(
+            deps,
+            message,
+            &message_hash,
+            &signature,
+            allowed_key,
+            &signer.key_type,
+        )? {
+            return Err(ContractError::InvalidSignerSignature {
+                signer: signer_signature.signer.to_string(),
+            });
+        }
+        approving_keys.push(allowed_key.clone());
+    }
+    Ok(approving_keys)
+}
+
+/// Count signers whose signatures were already verified by their public key
+/// The keys are looked up in the signer set of the given epoch, keys of removed
+/// or suspended signers aren't counted. Once the counted signers reach the
+/// threshold their names are returned
+pub fn count_verified_signers(
+    deps: Deps<NeutronQuery>,
+    env: &Env,
+    public_keys: &[Vec<u8>],
+    signer_epoch: Option<u64>,
+    amount_tier: Option<&AmountTier>,
+) -> Result<Option<Vec<String>>, ContractError> {
+    let (allowed_keys, weight_threshold) =
+        load_verification_keys(deps, env, signer_epoch, amount_tier)?;
+
+    let mut verified_signers: Vec<String> = vec![];
+    let mut verified_weight = 0u64;
+    for public_key in public_keys {
+        let Some((_, signer)) = allowed_keys.iter().find(|(key, _)| key == public_key) else {
+            continue;
+        };
+        // A rotated signer is only counted once
+        if verified_signers.contains(&signer.name) || !signer.is_active() {
+            continue;
+        }
+        verified_signers.push(signer.name.clone());
+        verified_weight += signer.weight;
+    }
+
+    if (verified_signers.len() as u64) < MIN_SIGNER_THRESHOLD || verified_weight < weight_threshold
+    {
+        return Ok(None);
+    }
+    Ok(Some(verified_signers))
+}
+
+/// Check whether the own signers of a token whose signatures were already
+/// verified reach the threshold of the token, counted by their public key
+/// Tokens without signers of their own don't require any signatures
+pub fn token_signers_threshold_met(
+    storage: &dyn Storage,
+    ticker: &str,
+    public_keys: &[Binary],
+) -> Result<bool, ContractError> {
+    let allowed_keys = load_token_signer_keys(storage, ticker)?;
+    if allowed_keys.is_empty() {
+        return Ok(true);
+    }
+
+    let policy = TOKEN_SIGNER_THRESHOLD
+        .may_load(storage, ticker)?
+        .unwrap_or_else(SignerThreshold::supermajority);
+    let total_weight = allowed_keys.iter().map(|(_, signer)| signer.weight).sum();
+    let weight_threshold = get_signer_threshold(&policy, total_weight);
+
+    let verified_keys = allowed_keys
+        .iter()
+        .filter(|(key, _)| {
+            public_keys
+                .iter()
+                .any(|public_key| public_key.as_slice() == key.as_slice())
+        })
+        .collect::<Vec<_>>();
+    let verified_weight: u64 = verified_keys.iter().map(|(_, signer)| signer.weight).sum();
+    Ok(verified_keys.len() as u64 >= MIN_SIGNER_THRESHOLD && verified_weight >= weight_threshold)
+}
+
+/// Verify the signatures of the own signers of a token against the message
+/// Tokens without signers of their own don't require any signatures
+/// Every valid signature adds the weight of its signer, once the total weight
+/// reaches the threshold of the token and at least MIN_SIGNER_THRESHOLD signers
+/// signed, we return Ok
+pub fn verify_token_signatures(
+    deps: Deps<NeutronQuery>,
+    ticker: &str,
+    message: &[u8],
+    signatures: &[String],
+) -> Result<(), ContractError> {
+    let allowed_keys = load_token_signer_keys(deps.storage, ticker)?;
+    if allowed_keys.is_empty() {
+        return Ok(());
+    }
+
+    // If duplicate signatures are sent, fail the verification
+    let mut unique_signatures = signatures.to_vec();
+    unique_signatures.sort();
+    unique_signatures.dedup();
+    if unique_signatures.len() != signatures.len() {
+        return Err(ContractError::DuplicateSignatures {});
+    }
+
+    let policy = TOKEN_SIGNER_THRESHOLD
+        .may_load(deps.storage, ticker)?
+        .unwrap_or_else(SignerThreshold::supermajority);
+    let total_weight = allowed_keys.iter().map(|(_, signer)| signer.weight).sum();
+    let weight_threshold = get_signer_threshold(&policy, total_weight);
+
+    let decoded_signatures: Result<Vec<_>, _> = unique_signatures
+        .iter()
+        .map(|sig| general_purpose::STANDARD.decode(sig))
+        .collect();
+    let decoded_signatures = decoded_signatures?;
+
+    let message_hash = Sha256::digest(message);
+    let mut verified_signatures = 0u64;
+    let mut verified_weight = 0u64;
+    for (allowed_key, signer) in &allowed_keys {
+        for signature in &decoded_signatures {
+            let is_valid = verify_signature_with_key(
+                deps,
+                message,
+                &message_hash,
+                signature,
+                allowed_key,
+                &signer.key_type,
+            )?;
+            if is_valid {
+                verified_signatures += 1;
+                verified_weight += signer.weight;
+                if verified_signatures >= MIN_SIGNER_THRESHOLD
+                    && verified_weight >= weight_threshold
+                {
+                    return Ok(());
+                }
+                break;
+            }
+        }
+    }
+    Err(ContractError::TokenThresholdNotMet {
+        ticker: ticker.to_string(),
+    })
+}
+
+/// Load every public key a signer has held, along with its key type
+/// Each rotation or removal starts a new signer epoch first, so the keys a
+/// signer no longer holds are all kept in the signer set history
+pub fn load_signer_key_history(
+    storage: &dyn Storage,
+    name: &str,
+) -> StdResult<Vec<(Vec<u8>, KeyType)>> {
+    let mut keys: Vec<(Vec<u8>, KeyType)> = vec![];
+    if let Some((public_key, signer)) = signers().idx.name.item(storage, name.to_string())? {
+        keys.push((public_key, signer.key_type));
+    }
+    for item in SIGNER_SET_HISTORY.range(storage, None, None, Order::Ascending) {
+        let (_, signer_set) = item?;
+        for (public_key, signer) in signer_set.signers {
+            if signer.name == name
+                && !keys
+                    .iter()
+                    .any(|(key, _)| key.as_slice() == public_key.as_slice())
+            {
+                keys.push((public_key.to_vec(), signer.key_type));
+            }
+        }
+    }
+    Ok(keys)
+}
+
+/// Verify that a signer signed two different transfers for the same transaction
+/// Each signature is checked against every key the signer has held, so rotating
+/// a key doesn't clear the signer of what it signed before
+pub fn verify_slash_evidence(
+    deps: Deps<NeutronQuery>,
+    env: &Env,
+    name: &str,
+    keys: &[(Vec<u8>, KeyType)],
+    evidence: &SlashEvidence,
+) -> Result<(), ContractError> {
+    let (first, second) = (&evidence.first, &evidence.second);
+    if first.ticker == second.ticker
+        && first.amount == second.amount
+        && first.destination_addr == second.destination_addr
+    {
+        return Err(ContractError::InvalidSlashEvidence {
+            reason: "The signed transfers don't conflict".to_string(),
+        });
+    }
+
+    for signed_receive in [first, second] {
+        validate_attestation_expiry_version(
+            signed_receive.expires_at,
+            signed_receive.attestation_version,
+        )?;
+        let message = Attestation::receive(
+            env,
+            &evidence.source_chain_id,
+            &evidence.transaction_hash,
+            &signed_receive.ticker,
+            signed_receive.amount,
+            &signed_receive.destination_addr,
+        )
+        .with_optional("signer_epoch", signed_receive.signer_epoch)
+        .with_optional(
+            "expires_at",
+            signed_receive
+                .expires_at
+                .map(|expires_at| expires_at.seconds()),
+        )
+        .with_optional("reward_fee_rate", signed_receive.reward_fee_rate)
+        .encode(signed_receive.attestation_version);
+        let signature = general_purpose::STANDARD.decode(&signed_receive.signature)?;
+        let message_hash = Sha256::digest(&message);
+        let mut is_valid = false;
+        for (public_key, key_type) in keys {
+            if verify_signature_with_key(
+                deps,
+                &message,
+                &message_hash,
+                &signature,
+                public_key,
+                key_type,
+            )? {
+                is_valid = true;
+                break;
+            }
+        }
+        if !is_valid {
+            return Err(ContractError::InvalidSlashEvidence {
+                reason: format!("The signature isn't valid for the signer '{}'", name),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Load the public keys and the signer info of the own signers of a token
+pub fn load_token_signer_keys(
+    storage: &dyn Storage,
+    ticker: &str,
+) -> StdResult<Vec<(Vec<u8>, SignerInfo)>> {
+    TOKEN_SIGNERS
+        .prefix(ticker)
+        .range(storage, None, None, Order::Ascending)
+        .collect()
+}
+
+/// Verify a single signature with the given key
+/// ed25519 keys verify the message itself, secp256k1 keys verify its sha256 digest
+fn verify_signature_with_key(
+    deps: Deps<NeutronQuery>,
+    message: &[u8],
+    message_hash: &[u8],
+    signature: &[u8],
+    public_key: &[u8],
+    key_type: &KeyType,
+) -> Result<bool, ContractError> {
+    let is_valid = match key_type {
+        KeyType::Ed25519 => deps.api.ed25519_verify(message, signature, public_key)?,
+        KeyType::Secp256k1 => deps
+            .api
+            .secp256k1_verify(message_hash, signature, public_key)
+            .unwrap_or(false),
+    };
+    Ok(is_valid)
+}
+
+/// Load the public keys and the signer info of the signer set for the given epoch
+/// The current signer set is always accepted while an earlier signer set is
+/// only accepted within the configured grace period after it was replaced.
+/// Signers of an earlier set that have since been suspended or removed, or
+/// whose key has since been rotated, are left out of it
+pub fn load_signer_keys(
+    deps: Deps<NeutronQuery>,
+    env: &Env,
+    config: &Config,
+    signer_epoch: Option<u64>,
+) -> Result<Vec<(Vec<u8>, SignerInfo)>, ContractError> {
+    let current_epoch = SIGNER_EPOCH.load(deps.storage)?;
+    let epoch = signer_epoch.unwrap_or(current_epoch);
+
+    if epoch == current_epoch {
+        let keys = signers()
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        return Ok(keys);
+    }
+
+    // Several signer sets can be replaced within the grace period, each of
+    // them can be used until its own grace period ends
+    let signer_set = if epoch < current_epoch {
+        SIGNER_SET_HISTORY.may_load(deps.storage, epoch)?
+    } else {
+        None
+    };
+    if let Some(signer_set) = signer_set {
+        let grace_end = signer_set
+            .replaced_at
+            .plus_seconds(config.signer_epoch_grace_seconds);
+        if env.block.time <= grace_end {
+            let mut keys = vec![];
+            for (key, signer) in signer_set.signers {
+                // The key must still belong to the same active signer
+                let Some(current) = signers().may_load(deps.storage, key.as_slice())? else {
+                    continue;
+                };
+                if current.name == signer.name && current.is_active() {
+                    keys.push((key.to_vec(), signer));
+                }
+            }
+            return Ok(keys);
+        }
+    }
+
+    Err(ContractError::InvalidSignerEpoch {
+        epoch,
+        current: current_epoch,
+    })
+}
+
+/// Load the keys rotated out of the current signer set whose overlap period
+/// hasn't ended yet, along with the signer info of the key that replaced them
+pub fn load_rotated_signer_keys(
+    storage: &dyn Storage,
+    env: &Env,
+) -> StdResult<Vec<(Vec<u8>, SignerInfo)>> {
+    let mut keys = vec![];
+    for item in ROTATED_SIGNER_KEYS.range(storage, None, None, Order::Ascending) {
+        let (old_key, rotated_key) = item?;
+        if env.block.time >= rotated_key.expires_at {
+            continue;
+        }
+        // The overlap ends early if the new key left the signer set
+        if let Some(signer) = signers().may_load(storage, &rotated_key.new_public_key)? {
+            if signer.is_active() {
+                keys.push((old_key, signer));
+            }
+        }
+    }
+    Ok(keys)
+}
+
+/// Store the current signer set in the history and bump the signer epoch
+/// This must be called before the signers are changed
+pub fn bump_signer_epoch(
+    storage: &mut dyn Storage,
+    block_time: Timestamp,
+) -> Result<u64, ContractError> {
+    let current_epoch = SIGNER_EPOCH.load(storage)?;
+
+    let signers = signers()
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(key, signer)| (Binary::from(key), signer)))
+        .collect::<StdResult<Vec<_>>>()?;
+    SIGNER_SET_HISTORY.save(
+        storage,
+        current_epoch,
+        &SignerSet {
+            signers,
+            replaced_at: block_time,
+        },
+    )?;
+
+    let new_epoch = current_epoch + 1;
+    SIGNER_EPOCH.save(storage, &new_epoch)?;
+
+    Ok(new_epoch)
+}
+
 /// Construct messages to mint and transfer TokenFactory tokens
 /// TokenFactory tokens must always be minted by the contract address
 pub fn build_mint_messages(
@@ -99,14 +835,355 @@ pub fn build_mint_messages(
     vec![mint_msg.into(), mint_transfer.into()]
 }
 
-/// Get the supermajority threshold for the current amount of signers
-/// The threshold is set to two-thirds of the signers rounded up to the nearest integer.
+/// Load a linked token from the registry by its CFT-20 ticker
+pub fn load_token(storage: &dyn Storage, ticker: &str) -> Result<TokenInfo, ContractError> {
+    tokens()
+        .may_load(storage, ticker)?
+        .ok_or_else(|| ContractError::TokenDoesNotExist {
+            ticker: ticker.to_string(),
+        })
+}
+
+/// Build the message setting the bank metadata of the TokenFactory denom of a token
+pub fn build_denom_metadata_msg(
+    contract_address: String,
+    denom: &str,
+    token: &TokenMetadata,
+) -> MsgSetDenomMetadata {
+    MsgSetDenomMetadata {
+        sender: contract_address,
+        metadata: Some(Metadata {
+            symbol: token.ticker.clone(),
+            name: token.name.clone(),
+            base: denom.to_string(),
+            display: token.ticker.clone(),
+            denom_units: vec![
+                DenomUnit {
+                    denom: denom.to_string(),
+                    exponent: 0,
+                    aliases: vec![],
+                },
+                DenomUnit {
+                    denom: token.ticker.clone(),
+                    exponent: token.decimals,
+                    aliases: vec![],
+                },
+            ],
+            description: format!(
+                "{} is an Asteroid CFT-20 token bridged from the Cosmos Hub",
+                token.ticker
+            ),
+            uri: token.image_url.clone(),
+            uri_hash: "".to_string(),
+        }),
+    }
+}
+
+/// Get the signer threshold as the weight required from the current signers
+/// A fixed threshold is used as is, a percentage of the total weight is rounded up
+/// to the nearest integer.
 /// If the threshold is less than the minimum threshold, we return the minimum threshold.
-pub fn get_supermajority_threshold(signers_count: usize) -> u8 {
-    let threshold = ((2 * signers_count + 2) / 3).max(MIN_SIGNER_THRESHOLD as usize);
+pub fn get_signer_threshold(policy: &SignerThreshold, total_weight: u64) -> u64 {
+    let threshold = match policy {
+        SignerThreshold::Fixed { weight } => *weight,
+        SignerThreshold::Percentage { percentage } => {
+            let numerator = total_weight as u128 * percentage.atomics().u128();
+            let denominator = 10u128.pow(percentage.decimal_places());
+            ((numerator + denominator - 1) / denominator)
+                .try_into()
+                .unwrap_or(u64::MAX)
+        }
+    };
 
     // Ensure the threshold is not less than MIN_SIGNER_THRESHOLD
-    threshold.try_into().unwrap_or(MIN_SIGNER_THRESHOLD)
+    threshold.max(MIN_SIGNER_THRESHOLD)
+}
+
+/// Get the total weight of the current active signers
+pub fn total_signer_weight(storage: &dyn Storage) -> StdResult<u64> {
+    signers()
+        .range(storage, None, None, Order::Ascending)
+        .filter(|item| !matches!(item, Ok((_, signer)) if !signer.is_active()))
+        .map(|item| item.map(|(_, signer)| signer.weight))
+        .sum()
+}
+
+/// Check if the current active signers can meet the signer threshold
+/// Besides carrying enough weight, there must be at least MIN_SIGNER_THRESHOLD
+/// active signers as a single signer is never enough
+pub fn signer_threshold_reachable(
+    storage: &dyn Storage,
+    policy: &SignerThreshold,
+) -> StdResult<bool> {
+    let mut count = 0u64;
+    let mut total_weight = 0u64;
+    for item in signers().range(storage, None, None, Order::Ascending) {
+        let (_, signer) = item?;
+        if signer.is_active() {
+            count += 1;
+            total_weight += signer.weight;
+        }
+    }
+
+    Ok(count >= MIN_SIGNER_THRESHOLD && get_signer_threshold(policy, total_weight) <= total_weight)
+}
+
+/// Ensure a change to the signer set keeps the signer threshold reachable
+/// A signer set that couldn't meet the threshold before the change, like one
+/// that is still being set up, isn't held to it
+pub fn validate_signer_set_change(
+    storage: &dyn Storage,
+    policy: &SignerThreshold,
+    was_reachable: bool,
+) -> Result<(), ContractError> {
+    if was_reachable && !signer_threshold_reachable(storage, policy)? {
+        return Err(ContractError::InvalidConfiguration {
+            reason: "The signer threshold can't be met by the current signers".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Credit the signers counted for an attestation with their participation
+pub fn record_signer_participation(
+    storage: &mut dyn Storage,
+    env: &Env,
+    signer_names: &[String],
+) -> StdResult<()> {
+    for name in signer_names {
+        let mut stats = SIGNER_STATS.may_load(storage, name)?.unwrap_or_default();
+        stats.attestations += 1;
+        stats.last_seen_height = Some(env.block.height);
+        stats.last_seen_time = Some(env.block.time);
+        SIGNER_STATS.save(storage, name, &stats)?;
+    }
+    Ok(())
+}
+
+/// Check that a transfer from the Hub can be received
+pub fn validate_receive(
+    deps: Deps<NeutronQuery>,
+    transaction_hash: &str,
+    ticker: &str,
+    amount: Uint128,
+    destination_addr: &str,
+) -> Result<(), ContractError> {
+    let token = tokens().may_load(deps.storage, ticker)?;
+    // Check if the token is disabled
+    if token.as_ref().map(|token| token.status) == Some(TokenStatus::Disabled) {
+        return Err(ContractError::TokenDisabled {
+            ticker: ticker.to_string(),
+        });
+    }
+    // Check the amount sent, if 0, reject
+    if amount.is_zero() {
+        return Err(ContractError::ZeroAmount {});
+    }
+    // Check destination address, if invalid, reject
+    if deps.api.addr_validate(destination_addr).is_err() {
+        return Err(ContractError::InvalidDestinationAddr {});
+    }
+    // Check the ticker, if it doesn't exist activate needs to be called first
+    if token.is_none() {
+        return Err(ContractError::TokenDoesNotExist {
+            ticker: ticker.to_string(),
+        });
+    }
+    // Check if we've processed this transaction already
+    if HANDLED_TRANSACTIONS.has(deps.storage, transaction_hash) {
+        return Err(ContractError::TransactionAlreadyHandled {
+            transaction_hash: transaction_hash.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Checks that an attestation with an expiry is still valid at the current block time
+pub fn validate_attestation_expiry(
+    env: &Env,
+    expires_at: Option<Timestamp>,
+    attestation_version: AttestationVersion,
+) -> Result<(), ContractError> {
+    validate_attestation_expiry_version(expires_at, attestation_version)?;
+    match expires_at {
+        Some(expires_at) if env.block.time >= expires_at => {
+            Err(ContractError::AttestationExpired { expires_at })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Ensure an attestation with an expiry is encoded in v2
+/// The v1 encoding concatenates the optional fields without a separator, so
+/// the digits of the signer epoch and the expiry could be split differently
+/// to drop the epoch and push the expiry out
+pub fn validate_attestation_expiry_version(
+    expires_at: Option<Timestamp>,
+    attestation_version: AttestationVersion,
+) -> Result<(), ContractError> {
+    if expires_at.is_some() && attestation_version == AttestationVersion::V1 {
+        return Err(ContractError::AttestationExpiryRequiresV2 {});
+    }
+    Ok(())
+}
+
+/// Get the reward fee rate the attestation of a receive carries
+/// The fee is only attested in the v2 encoding, a transfer attested in v1 is
+/// minted in full as its signers never agreed to a fee
+pub fn attested_reward_fee_rate(
+    config: &Config,
+    attestation_version: AttestationVersion,
+) -> Option<Decimal> {
+    (attestation_version == AttestationVersion::V2 && !config.reward_fee_rate.is_zero())
+        .then_some(config.reward_fee_rate)
+}
+
+/// Split the rewards of a transfer equally between the signers counted for it
+/// The remainder of the split goes to the first signers, one unit each
+pub fn accrue_signer_rewards(
+    storage: &mut dyn Storage,
+    signer_names: &[String],
+    denom: &str,
+    amount: Uint128,
+) -> StdResult<()> {
+    if amount.is_zero() || signer_names.is_empty() {
+        return Ok(());
+    }
+
+    let signer_count = Uint128::from(signer_names.len() as u128);
+    let share = amount / signer_count;
+    let remainder = (amount % signer_count).u128() as usize;
+    for (index, name) in signer_names.iter().enumerate() {
+        let reward = if index < remainder {
+            share + Uint128::one()
+        } else {
+            share
+        };
+        if reward.is_zero() {
+            continue;
+        }
+        SIGNER_REWARDS.update(storage, (name, denom), |rewards| -> StdResult<_> {
+            Ok(rewards.unwrap_or_default() + reward)
+        })?;
+    }
+    Ok(())
+}
+
+/// Checks that the contact URL of a signer is within the allowed length
+pub fn validate_contact_url(contact_url: &Option<String>) -> Result<(), ContractError> {
+    if let Some(contact_url) = contact_url {
+        if contact_url.is_empty() || contact_url.len() > MAX_SIGNER_CONTACT_URL_LENGTH {
+            return Err(ContractError::InvalidConfiguration {
+                reason: format!(
+                    "The contact URL must be between 1 and {} characters",
+                    MAX_SIGNER_CONTACT_URL_LENGTH
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Checks that a decoded public key is in the format required by its algorithm
+pub fn validate_public_key(public_key: &[u8], key_type: KeyType) -> Result<(), ContractError> {
+    match key_type {
+        KeyType::Ed25519 => {
+            let public_key_bytes: [u8; PUBLIC_KEY_LENGTH] = match public_key.try_into() {
+                Ok(bytes) => bytes,
+                Err(_) => {
+                    return Err(ContractError::InvalidConfiguration {
+                        reason: "Invalid public key length".to_string(),
+                    });
+                }
+            };
+            VerifyingKey::from_bytes(&public_key_bytes)?;
+        }
+        KeyType::Secp256k1 => {
+            // Only the compressed SEC1 encoding is accepted, the point itself
+            // is checked by the chain when a signature is verified
+            if public_key.len() != SECP256K1_PUBLIC_KEY_LENGTH
+                || !matches!(public_key[0], 0x02 | 0x03)
+            {
+                return Err(ContractError::InvalidConfiguration {
+                    reason: "Invalid secp256k1 public key".to_string(),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the voting weight of a signer is within the allowed bounds
+pub fn validate_signer_weight(weight: u64) -> Result<(), ContractError> {
+    if weight == 0 || weight > MAX_SIGNER_WEIGHT {
+        return Err(ContractError::InvalidConfiguration {
+            reason: format!(
+                "The signer weight must be between 1 and {}",
+                MAX_SIGNER_WEIGHT
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Checks that the amount tiers are monotonic, every tier must apply to larger
+/// amounts than the previous one and can't require less of the signer weight
+pub fn validate_amount_tiers(tiers: &[AmountTier]) -> Result<(), ContractError> {
+    if tiers.len() > MAX_AMOUNT_TIERS {
+        return Err(ContractError::InvalidConfiguration {
+            reason: format!(
+                "A token can't have more than {} amount tiers",
+                MAX_AMOUNT_TIERS
+            ),
+        });
+    }
+
+    for tier in tiers {
+        if tier.percentage.is_zero() || tier.percentage > Decimal::one() {
+            return Err(ContractError::InvalidConfiguration {
+                reason: "The amount tier percentage must be between 0 and 1".to_string(),
+            });
+        }
+    }
+
+    for tiers in tiers.windows(2) {
+        if tiers[1].min_amount <= tiers[0].min_amount || tiers[1].percentage < tiers[0].percentage {
+            return Err(ContractError::InvalidConfiguration {
+                reason: "The amount tiers must increase in amount and percentage".to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Find the amount tier that applies to a transfer, the tier with the largest
+/// minimum amount the transfer reaches
+pub fn find_amount_tier(tiers: &[AmountTier], amount: Uint128) -> Option<&AmountTier> {
+    tiers.iter().rev().find(|tier| amount >= tier.min_amount)
+}
+
+/// Checks that the signer threshold policy is within the allowed bounds
+pub fn validate_signer_threshold(policy: &SignerThreshold) -> Result<(), ContractError> {
+    match policy {
+        SignerThreshold::Fixed { weight } => {
+            if *weight < MIN_SIGNER_THRESHOLD {
+                return Err(ContractError::InvalidConfiguration {
+                    reason: format!(
+                        "The signer threshold can't be less than {}",
+                        MIN_SIGNER_THRESHOLD
+                    ),
+                });
+            }
+        }
+        SignerThreshold::Percentage { percentage } => {
+            if percentage.is_zero() || *percentage > Decimal::one() {
+                return Err(ContractError::InvalidConfiguration {
+                    reason: "The signer threshold percentage must be between 0 and 1".to_string(),
+                });
+            }
+        }
+    }
+    Ok(())
 }
 
 /// Checks that the given channel and port is valid
@@ -138,25 +1215,250 @@ pub fn validate_channel(
 mod testing {
     use super::*;
 
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::Addr;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    use crate::mock::{mock_neutron_dependencies, CryptoCallCounter};
+    use crate::types::{
+        SignerStatus, DEFAULT_PENDING_RECEIVE_MAX_AGE_SECONDS, DEFAULT_SIGNER_EPOCH_GRACE_SECONDS,
+        DEFAULT_SIGNER_WEIGHT,
+    };
+
+    const MESSAGE: &[u8] = b"attestation";
+
+    /// Loads the given amount of ed25519 signers and returns the keys of the
+    /// signers needed to reach the threshold
+    fn setup_signers(storage: &mut dyn Storage, count: u8) -> Vec<SigningKey> {
+        CONFIG
+            .save(
+                storage,
+                &Config {
+                    owner: Addr::unchecked("owner"),
+                    bridge_chain_id: "localgaia-1".to_string(),
+                    bridge_ibc_channel: "channel-0".to_string(),
+                    ibc_timeout_seconds: 10,
+                    signer_threshold: SignerThreshold::supermajority(),
+                    signer_epoch_grace_seconds: DEFAULT_SIGNER_EPOCH_GRACE_SECONDS,
+                    v1_attestations_enabled: true,
+                    owner_signer_management: true,
+                    admin_action_delay_seconds: 0,
+                    pending_receive_max_age_seconds: DEFAULT_PENDING_RECEIVE_MAX_AGE_SECONDS,
+                    min_signer_bond: Uint128::zero(),
+                    unbonding_period_seconds: 0,
+                    treasury: None,
+                    reward_fee_rate: Decimal::zero(),
+                },
+            )
+            .unwrap();
+        SIGNER_EPOCH.save(storage, &0).unwrap();
+
+        let mut keys = (1..=count)
+            .map(|seed| SigningKey::from_bytes(&[seed; 32]))
+            .collect::<Vec<_>>();
+        for (index, key) in keys.iter().enumerate() {
+            signers()
+                .save(
+                    storage,
+                    key.verifying_key().as_bytes(),
+                    &SignerInfo {
+                        name: format!("signer{}", index),
+                        key_type: KeyType::Ed25519,
+                        weight: DEFAULT_SIGNER_WEIGHT,
+                        contact_url: None,
+                        added_at: mock_env().block.time,
+                        status: SignerStatus::Active,
+                    },
+                )
+                .unwrap();
+        }
+
+        // Sign with the last keys in storage order, the worst case when
+        // every key is tried against every signature
+        keys.sort_by_key(|key| key.verifying_key().to_bytes());
+        let threshold = get_signer_threshold(&SignerThreshold::supermajority(), count.into());
+        keys.split_off(keys.len() - threshold as usize)
+    }
+
+    /// Verifies the signatures of the signers needed to reach the threshold and
+    /// returns the calls made to the crypto host functions
+    fn verification_calls(count: u8, paired: bool) -> CryptoCallCounter {
+        let mut deps = mock_neutron_dependencies(&[]);
+        let keys = setup_signers(&mut deps.storage, count);
+
+        let signatures = keys
+            .iter()
+            .map(|key| general_purpose::STANDARD.encode(key.sign(MESSAGE).to_bytes()))
+            .collect::<Vec<_>>();
+        let signer_signatures = keys
+            .iter()
+            .zip(&signatures)
+            .map(|(key, signature)| SignerSignature {
+                signer: SignerReference::PublicKey {
+                    public_key_base64: general_purpose::STANDARD
+                        .encode(key.verifying_key().as_bytes()),
+                },
+                signature: signature.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        let api = CryptoCallCounter::default();
+        let metered_deps = Deps {
+            storage: &deps.storage,
+            api: &api,
+            querier: QuerierWrapper::new(&deps.querier),
+        };
+        if paired {
+            verify_signatures(
+                metered_deps,
+                &mock_env(),
+                MESSAGE,
+                &[],
+                &signer_signatures,
+                &[],
+                None,
+                None,
+            )
+            .unwrap();
+        } else {
+            verify_signatures(
+                metered_deps,
+                &mock_env(),
+                MESSAGE,
+                &signatures,
+                &[],
+                &[],
+                None,
+                None,
+            )
+            .unwrap();
+        }
+
+        api
+    }
+
+    #[test]
+    fn test_batch_verification_host_calls() {
+        for count in [10u8, 30, 100] {
+            let threshold = get_signer_threshold(&SignerThreshold::supermajority(), count.into());
+
+            let one_by_one = verification_calls(count, false);
+            let batch = verification_calls(count, true);
+
+            // The whole quorum is checked in a single batch call
+            assert_eq!(batch.ed25519_batch_verify_calls.get(), 1);
+            assert_eq!(batch.ed25519_batch_signatures.get(), threshold);
+            assert_eq!(batch.ed25519_verify_calls.get(), 0);
+            // While unpaired signatures are tried against every key one by one
+            assert_eq!(one_by_one.ed25519_batch_verify_calls.get(), 0);
+            assert!(one_by_one.ed25519_verify_calls.get() > threshold);
+        }
+    }
+
+    #[test]
+    fn test_batch_verification_fallback() {
+        let mut deps = mock_neutron_dependencies(&[]);
+        let keys = setup_signers(&mut deps.storage, 10);
+
+        // The last signer signs a different message
+        let signer_signatures = keys
+            .iter()
+            .enumerate()
+            .map(|(index, key)| {
+                let message: &[u8] = if index == keys.len() - 1 {
+                    b"other"
+                } else {
+                    MESSAGE
+                };
+                SignerSignature {
+                    signer: SignerReference::PublicKey {
+                        public_key_base64: general_purpose::STANDARD
+                            .encode(key.verifying_key().as_bytes()),
+                    },
+                    signature: general_purpose::STANDARD.encode(key.sign(message).to_bytes()),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // The failed batch is checked one by one to find the offending signer
+        let err = verify_signatures(
+            deps.as_ref(),
+            &mock_env(),
+            MESSAGE,
+            &[],
+            &signer_signatures,
+            &[],
+            None,
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidSignerSignature {
+                signer: signer_signatures.last().unwrap().signer.to_string()
+            }
+        );
+    }
+
     #[test]
     fn test_threshold_calculation() {
-        // Test the threshold calculation
-        assert_eq!(get_supermajority_threshold(0), MIN_SIGNER_THRESHOLD);
-        assert_eq!(get_supermajority_threshold(1), MIN_SIGNER_THRESHOLD);
-        assert_eq!(get_supermajority_threshold(2), MIN_SIGNER_THRESHOLD);
-        assert_eq!(get_supermajority_threshold(3), 2);
-        assert_eq!(get_supermajority_threshold(4), 3);
-        assert_eq!(get_supermajority_threshold(5), 4);
-        assert_eq!(get_supermajority_threshold(6), 4);
-        assert_eq!(get_supermajority_threshold(7), 5);
-        assert_eq!(get_supermajority_threshold(8), 6);
-        assert_eq!(get_supermajority_threshold(9), 6);
-        assert_eq!(get_supermajority_threshold(10), 7);
-
-        assert_eq!(get_supermajority_threshold(50), 34);
-        assert_eq!(get_supermajority_threshold(51), 34);
-
-        assert_eq!(get_supermajority_threshold(99), 66);
-        assert_eq!(get_supermajority_threshold(100), 67);
+        // Test the default supermajority threshold calculation
+        let supermajority = SignerThreshold::supermajority();
+        assert_eq!(
+            get_signer_threshold(&supermajority, 0),
+            MIN_SIGNER_THRESHOLD
+        );
+        assert_eq!(
+            get_signer_threshold(&supermajority, 1),
+            MIN_SIGNER_THRESHOLD
+        );
+        assert_eq!(
+            get_signer_threshold(&supermajority, 2),
+            MIN_SIGNER_THRESHOLD
+        );
+        assert_eq!(get_signer_threshold(&supermajority, 3), 2);
+        assert_eq!(get_signer_threshold(&supermajority, 4), 3);
+        assert_eq!(get_signer_threshold(&supermajority, 5), 4);
+        assert_eq!(get_signer_threshold(&supermajority, 6), 4);
+        assert_eq!(get_signer_threshold(&supermajority, 7), 5);
+        assert_eq!(get_signer_threshold(&supermajority, 8), 6);
+        assert_eq!(get_signer_threshold(&supermajority, 9), 6);
+        assert_eq!(get_signer_threshold(&supermajority, 10), 7);
+
+        assert_eq!(get_signer_threshold(&supermajority, 50), 34);
+        assert_eq!(get_signer_threshold(&supermajority, 51), 34);
+
+        assert_eq!(get_signer_threshold(&supermajority, 99), 66);
+        assert_eq!(get_signer_threshold(&supermajority, 100), 67);
+
+        // Test a fixed M-of-N threshold
+        let fixed = SignerThreshold::Fixed { weight: 3 };
+        assert_eq!(get_signer_threshold(&fixed, 3), 3);
+        assert_eq!(get_signer_threshold(&fixed, 10), 3);
+
+        // Test a percentage threshold
+        let percentage = SignerThreshold::Percentage {
+            percentage: Decimal::percent(90),
+        };
+        assert_eq!(get_signer_threshold(&percentage, 2), MIN_SIGNER_THRESHOLD);
+        assert_eq!(get_signer_threshold(&percentage, 10), 9);
+        assert_eq!(get_signer_threshold(&percentage, 11), 10);
+
+        // The percentage applies to the total weight of the signers
+        assert_eq!(get_signer_threshold(&supermajority, 300), 200);
+        assert_eq!(get_signer_threshold(&percentage, 1000), 900);
+
+        // Thresholds below the minimum or percentages outside of (0, 1] are rejected
+        assert!(validate_signer_threshold(&SignerThreshold::Fixed { weight: 1 }).is_err());
+        assert!(validate_signer_threshold(&SignerThreshold::Percentage {
+            percentage: Decimal::zero()
+        })
+        .is_err());
+        assert!(validate_signer_threshold(&SignerThreshold::Percentage {
+            percentage: Decimal::percent(101)
+        })
+        .is_err());
+        assert!(validate_signer_threshold(&fixed).is_ok());
+        assert!(validate_signer_threshold(&percentage).is_ok());
     }
 }
@@ -1,3 +1,4 @@
+pub mod attestation;
 pub mod contract;
 pub mod error;
 pub mod execute;
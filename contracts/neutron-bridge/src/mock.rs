@@ -1,10 +1,12 @@
+use std::cell::Cell;
 #[cfg(test)]
 use std::marker::PhantomData;
 
 use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage};
 use cosmwasm_std::{
-    coins, from_json, to_json_binary, ChannelResponse, Coin, IbcChannel, IbcEndpoint, IbcOrder,
-    IbcQuery, OwnedDeps, Querier, QuerierResult, QueryRequest, SystemError, SystemResult,
+    coins, from_json, to_json_binary, Addr, Api, CanonicalAddr, ChannelResponse, Coin, IbcChannel,
+    IbcEndpoint, IbcOrder, IbcQuery, OwnedDeps, Querier, QuerierResult, QueryRequest,
+    RecoverPubkeyError, StdResult, SystemError, SystemResult, VerificationError,
 };
 use neutron_sdk::bindings::msg::IbcFee;
 use neutron_sdk::bindings::query::NeutronQuery;
@@ -87,3 +89,79 @@ impl WasmMockQuerier {
         WasmMockQuerier { base }
     }
 }
+
+/// CryptoCallCounter wraps the MockApi and counts the calls made to the
+/// crypto host functions, along with the signatures checked in batches
+#[derive(Default)]
+pub struct CryptoCallCounter {
+    base: MockApi,
+    pub secp256k1_verify_calls: Cell<u64>,
+    pub ed25519_verify_calls: Cell<u64>,
+    pub ed25519_batch_verify_calls: Cell<u64>,
+    pub ed25519_batch_signatures: Cell<u64>,
+}
+
+fn increment(counter: &Cell<u64>, amount: u64) {
+    counter.set(counter.get() + amount);
+}
+
+impl Api for CryptoCallCounter {
+    fn addr_validate(&self, human: &str) -> StdResult<Addr> {
+        self.base.addr_validate(human)
+    }
+
+    fn addr_canonicalize(&self, human: &str) -> StdResult<CanonicalAddr> {
+        self.base.addr_canonicalize(human)
+    }
+
+    fn addr_humanize(&self, canonical: &CanonicalAddr) -> StdResult<Addr> {
+        self.base.addr_humanize(canonical)
+    }
+
+    fn secp256k1_verify(
+        &self,
+        message_hash: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> Result<bool, VerificationError> {
+        increment(&self.secp256k1_verify_calls, 1);
+        self.base
+            .secp256k1_verify(message_hash, signature, public_key)
+    }
+
+    fn secp256k1_recover_pubkey(
+        &self,
+        message_hash: &[u8],
+        signature: &[u8],
+        recovery_param: u8,
+    ) -> Result<Vec<u8>, RecoverPubkeyError> {
+        self.base
+            .secp256k1_recover_pubkey(message_hash, signature, recovery_param)
+    }
+
+    fn ed25519_verify(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> Result<bool, VerificationError> {
+        increment(&self.ed25519_verify_calls, 1);
+        self.base.ed25519_verify(message, signature, public_key)
+    }
+
+    fn ed25519_batch_verify(
+        &self,
+        messages: &[&[u8]],
+        signatures: &[&[u8]],
+        public_keys: &[&[u8]],
+    ) -> Result<bool, VerificationError> {
+        increment(&self.ed25519_batch_verify_calls, 1);
+        increment(&self.ed25519_batch_signatures, signatures.len() as u64);
+        self.base
+            .ed25519_batch_verify(messages, signatures, public_keys)
+    }
+
+    fn debug(&self, message: &str) {
+        self.base.debug(message)
+    }
+}
@@ -1,7 +1,15 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Decimal, Timestamp, Uint128};
 
-use crate::types::{Config, QuerySignersResponse, QueryTokensResponse, TokenMetadata};
+use crate::types::{
+    Adr036Signature, AmountTier, AttestationRequest, AttestationVersion, KeyType, PendingReceive,
+    QueryAdminActionsResponse, QueryConfigResponse, QueryFailedTokenLinksResponse,
+    QueryPendingReceivesResponse, QueryPendingRewardsResponse, QuerySignerStatsResponse,
+    QuerySignersResponse, QueryTokenSignersResponse, QueryTokensResponse,
+    QueryVerifyAttestationResponse, SignerBond, SignerChange, SignerChangeProposal, SignerSet,
+    SignerSignature, SignerStatus, SignerThreshold, SlashEvidence, TokenInfo, TokenLink,
+    TokenMetadata,
+};
 
 /// Holds the parameters used for creating a Hub contract
 #[cw_serde]
@@ -17,10 +25,11 @@ pub struct InstantiateMsg {
 }
 
 /// The contract migration message
-/// We currently take no arguments for migrations
 #[cw_serde]
 pub struct MigrateMsg {
-    /// The chain ID this bridge is connected to
+    /// The chain ID the tokens of an instance from before 1.1.0 were linked
+    /// from, defaults to the chain ID in its config. It's never required and
+    /// only read when migrating from such an instance
     pub bridge_chain_id: Option<String>,
 }
 
@@ -34,7 +43,45 @@ pub enum ExecuteMsg {
         /// The metadata of the CFT-20 token
         token: TokenMetadata,
         /// The signatures of from the verifying parties
+        #[serde(default)]
+        signatures: Vec<String>,
+        /// The signatures paired with the signer that made them, each of them
+        /// is only verified against the key of its signer
+        signer_signatures: Option<Vec<SignerSignature>>,
+        /// Signatures from secp256k1 signers made with off-chain wallets over
+        /// an ADR-036 sign-doc wrapping the attestation
+        adr036_signatures: Option<Vec<Adr036Signature>>,
+        /// The signer set epoch the signatures were made for, if not provided
+        /// the signatures are verified against the current signer set
+        signer_epoch: Option<u64>,
+        /// The encoding of the signed attestation, defaults to v1. Only the
+        /// v2 encoding attests the name and image of the token
+        attestation_version: Option<AttestationVersion>,
+    },
+    /// Link several CFT-20 tokens in one transaction, every link carries its
+    /// own attestation
+    LinkTokens { links: Vec<TokenLink> },
+    /// Update the metadata of a linked token and its denom metadata. The
+    /// signers attest the new metadata, without any signatures the owner
+    /// overrides the metadata as an admin action
+    UpdateTokenMetadata {
+        /// The chain ID of the source chain
+        source_chain_id: String,
+        /// The new metadata of the CFT-20 token, the decimals can't change
+        token: TokenMetadata,
+        /// The signatures of from the verifying parties
+        #[serde(default)]
         signatures: Vec<String>,
+        /// The signatures paired with the signer that made them
+        signer_signatures: Option<Vec<SignerSignature>>,
+        /// Signatures from secp256k1 signers made with off-chain wallets over
+        /// an ADR-036 sign-doc wrapping the attestation
+        adr036_signatures: Option<Vec<Adr036Signature>>,
+        /// The signer set epoch the signatures were made for, if not provided
+        /// the signatures are verified against the current signer set
+        signer_epoch: Option<u64>,
+        /// The encoding of the signed attestation, defaults to v1
+        attestation_version: Option<AttestationVersion>,
     },
     // Enable a previously disabled token to being bridged again
     EnableToken {
@@ -46,7 +93,20 @@ pub enum ExecuteMsg {
         /// The ticker of the CFT-20 token
         ticker: String,
     },
+    /// Set the amount tiers of a token, larger transfers can require a larger
+    /// share of the signer weight. Sending no tiers removes them. This is an
+    /// admin action
+    SetAmountTiers {
+        /// The ticker of the CFT-20 token
+        ticker: String,
+        /// The tiers ordered by increasing minimum amount and percentage
+        tiers: Vec<AmountTier>,
+    },
     /// Receive CFT-20 token message from the Hub
+    /// When a reward fee rate is configured, its share of the amount is kept
+    /// as rewards for the bonded signers counted for the transfer and the
+    /// destination receives the rest. The fee is set by the contract config
+    /// and is not part of the signed attestation
     Receive {
         /// The chain ID of the source chain
         source_chain_id: String,
@@ -59,7 +119,56 @@ pub enum ExecuteMsg {
         /// The destination address to transfer the CFT-20-equivalent to
         destination_addr: String,
         /// The signatures of from the verifying parties
+        #[serde(default)]
         signatures: Vec<String>,
+        /// The signatures paired with the signer that made them, each of them
+        /// is only verified against the key of its signer
+        signer_signatures: Option<Vec<SignerSignature>>,
+        /// Signatures from secp256k1 signers made with off-chain wallets over
+        /// an ADR-036 sign-doc wrapping the attestation
+        adr036_signatures: Option<Vec<Adr036Signature>>,
+        /// The signer set epoch the signatures were made for, if not provided
+        /// the signatures are verified against the current signer set
+        signer_epoch: Option<u64>,
+        /// The encoding of the signed attestation, defaults to v1
+        attestation_version: Option<AttestationVersion>,
+        /// The signatures of the token's own signers over the same attestation,
+        /// only required when the token has its own signer set
+        token_signatures: Option<Vec<String>>,
+        /// The block time after which the signatures are no longer accepted,
+        /// the attestation never expires if not provided
+        expires_at: Option<Timestamp>,
+    },
+    /// Submit signatures over a receive attestation one signer at a time, the
+    /// signatures are collected per transaction hash and the tokens are minted
+    /// once they reach the threshold. Anyone can relay the signatures
+    /// The reward fee is deducted the same way as in Receive
+    Attest {
+        /// The chain ID of the source chain
+        source_chain_id: String,
+        /// The hash of the transaction on the origin chain
+        transaction_hash: String,
+        /// The ticker of the CFT-20 token
+        ticker: String,
+        /// The amount of CFT-20 tokens
+        amount: Uint128,
+        /// The destination address to transfer the CFT-20-equivalent to
+        destination_addr: String,
+        /// The signatures paired with the signer that made them
+        #[serde(default)]
+        signer_signatures: Vec<SignerSignature>,
+        /// The signatures of the token's own signers paired with the signer
+        /// that made them, only needed when the token has its own signer set
+        #[serde(default)]
+        token_signer_signatures: Vec<SignerSignature>,
+        /// The signer set epoch the signatures were made for, if not provided
+        /// the signatures are verified against the current signer set
+        signer_epoch: Option<u64>,
+        /// The encoding of the signed attestation, defaults to v1
+        attestation_version: Option<AttestationVersion>,
+        /// The block time after which the signatures are no longer accepted,
+        /// the attestation never expires if not provided
+        expires_at: Option<Timestamp>,
     },
     /// Send CFT-20 token back to the Hub
     Send {
@@ -68,11 +177,18 @@ pub enum ExecuteMsg {
     },
     /// Adds a signer to the allowed list for signature verification
     AddSigner {
-        /// The public key in base64. This is the raw key without the ASN.1
-        /// structure, that is, the last 32 bytes from the DER-encoded public key
+        /// The public key in base64. For ed25519 this is the raw key without the ASN.1
+        /// structure, that is, the last 32 bytes from the DER-encoded public key.
+        /// For secp256k1 this is the compressed SEC1 encoded key
         public_key_base64: String,
         /// A simple human name for the owner of the public key
         name: String,
+        /// The algorithm of the public key, defaults to ed25519
+        key_type: Option<KeyType>,
+        /// The voting weight of the signer toward the threshold, defaults to 1
+        weight: Option<u64>,
+        /// A URL to reach the operator of the signer
+        contact_url: Option<String>,
     },
     /// Remove a signer from the allowed list for signature verification
     RemoveSigner {
@@ -80,12 +196,174 @@ pub enum ExecuteMsg {
         /// AddSigner
         public_key_base64: String,
     },
+    /// Update the voting weight of a signer
+    UpdateSignerWeight {
+        /// The public key in base64 of the signer, in the same format as in
+        /// AddSigner
+        public_key_base64: String,
+        /// The new voting weight of the signer
+        weight: u64,
+    },
+    /// Suspend a signer or reactivate a suspended signer, the signatures of
+    /// suspended signers are not counted. A suspension applies immediately,
+    /// even when the remaining signers can't meet the threshold, a
+    /// reactivation is an admin action
+    UpdateSignerStatus {
+        /// The public key in base64 of the signer, in the same format as in
+        /// AddSigner
+        public_key_base64: String,
+        /// The new status of the signer
+        status: SignerStatus,
+    },
+    /// Replace the key of a signer, authorised by a signature of the old key
+    /// over the signer key rotation attestation. Anyone can relay the rotation
+    RotateSignerKey {
+        /// The current public key of the signer in base64
+        old_public_key_base64: String,
+        /// The new public key of the signer in base64, in the same format as in AddSigner
+        new_public_key_base64: String,
+        /// The algorithm of the new public key, defaults to ed25519
+        new_key_type: Option<KeyType>,
+        /// The rotation nonce of the signer, see QueryMsg::SignerRotationNonce
+        nonce: u64,
+        /// The signature of the old key over the rotation attestation in base64
+        signature: String,
+        /// The time in seconds the old key is still accepted next to the new key
+        overlap_seconds: Option<u64>,
+    },
+    /// Register the address that owns the bond of a signer, only it can post,
+    /// unbond and withdraw the bond and claim the rewards of the signer. The
+    /// owner of a bond that still holds funds can't be changed
+    SetSignerBondOwner {
+        /// The name of the signer
+        name: String,
+        /// The address owning the bond
+        owner: String,
+    },
+    /// Post an untrn bond for a signer, the bond can be posted before the
+    /// signer is added. Only the registered owner of the bond can post it
+    BondSigner {
+        /// The name of the signer
+        name: String,
+    },
+    /// Start unbonding part of the bond of a signer, the funds can be withdrawn
+    /// once the unbonding period ends. Signers in the signer set must keep
+    /// the minimum bond
+    UnbondSigner {
+        /// The name of the signer
+        name: String,
+        /// The amount of untrn to unbond
+        amount: Uint128,
+    },
+    /// Withdraw the unbonded funds of a signer once the unbonding period ended
+    WithdrawSignerBond {
+        /// The name of the signer
+        name: String,
+    },
+    /// Claim the rewards accrued by a signer, they are paid to the owner of
+    /// the bond of the signer
+    ClaimRewards {
+        /// The name of the signer
+        name: String,
+    },
+    /// Slash the whole bond of a signer that signed two different transfers
+    /// for the same transaction, the bond is sent to the treasury and the
+    /// signer is suspended. The transfers can be signed with any key the
+    /// signer has held, including keys it has since rotated
+    SlashSigner {
+        /// The name of the signer
+        name: String,
+        /// The two conflicting transfers signed by the signer
+        evidence: SlashEvidence,
+        /// The approvals of the signers over the slash signer attestation,
+        /// only required when the sender isn't the owner
+        signer_signatures: Option<Vec<SignerSignature>>,
+    },
+    /// Propose a change to the signer set, the change is applied once the
+    /// signers approving it carry the signer threshold
+    ProposeSignerChange {
+        /// The change to the signer set
+        change: SignerChange,
+        /// The approvals of the signers over the signer change attestation,
+        /// at least one signer must approve the proposal
+        signer_signatures: Vec<SignerSignature>,
+    },
+    /// Approve a pending signer change proposal, anyone can relay the approvals
+    ApproveSignerChange {
+        /// The ID of the signer change proposal
+        proposal_id: u64,
+        /// The approvals of the signers over the signer change attestation
+        signer_signatures: Vec<SignerSignature>,
+    },
+    /// Permanently hand the management of the signer set over to the signers,
+    /// the owner can no longer change the signers or the signer threshold
+    DisableOwnerSignerManagement {},
+    /// Adds a signer to the own signer set of a token, receiving the token
+    /// then also requires the quorum of its own signers. This is an admin action
+    AddTokenSigner {
+        /// The ticker of the CFT-20 token
+        ticker: String,
+        /// The public key in base64, in the same format as in AddSigner
+        public_key_base64: String,
+        /// A simple human name for the owner of the public key
+        name: String,
+        /// The algorithm of the public key, defaults to ed25519
+        key_type: Option<KeyType>,
+        /// The voting weight of the signer toward the token's threshold, defaults to 1
+        weight: Option<u64>,
+    },
+    /// Remove a signer from the own signer set of a token, this is an admin action
+    RemoveTokenSigner {
+        /// The ticker of the CFT-20 token
+        ticker: String,
+        /// The public key in base64 to remove
+        public_key_base64: String,
+    },
+    /// Set the policy used to calculate the weight required from the token's
+    /// own signers, defaults to a supermajority. This is an admin action
+    SetTokenSignerThreshold {
+        /// The ticker of the CFT-20 token
+        ticker: String,
+        /// The policy used to calculate the weight required
+        signer_threshold: SignerThreshold,
+    },
     /// Update the contract config
     UpdateConfig {
         /// The IBC channel to the Cosmos Hub
         bridge_ibc_channel: Option<String>,
         /// The timeout in seconds for IBC packets
         ibc_timeout_seconds: Option<u64>,
+        /// The policy used to calculate the amount of valid signatures required
+        signer_threshold: Option<SignerThreshold>,
+        /// The time in seconds a replaced signer set is still accepted
+        signer_epoch_grace_seconds: Option<u64>,
+        /// Whether attestations in the legacy v1 encoding are still accepted
+        v1_attestations_enabled: Option<bool>,
+        /// The time in seconds admin actions are queued before they can be executed
+        admin_action_delay_seconds: Option<u64>,
+        /// The time in seconds signatures are collected for a pending receive
+        pending_receive_max_age_seconds: Option<u64>,
+        /// The amount of untrn a signer must have bonded to be added
+        min_signer_bond: Option<Uint128>,
+        /// The time in seconds unbonded funds are locked
+        unbonding_period_seconds: Option<u64>,
+        /// The address receiving the slashed bonds
+        treasury: Option<String>,
+        /// The share of every received transfer paid to the bonded signers
+        /// counted for it, deducted from the amount minted to the destination.
+        /// The rate is part of the v2 receive attestation, so the signers agree
+        /// to it, transfers attested in v1 are minted in full
+        reward_fee_rate: Option<Decimal>,
+    },
+    /// Execute a queued admin action once its delay has passed
+    ExecuteAdminAction {
+        /// The ID of the queued admin action
+        id: u64,
+    },
+    /// Cancel a queued admin action
+    CancelAdminAction {
+        /// The ID of the queued admin action
+        id: u64,
     },
     /// Propose a new owner for the contract
     ProposeNewOwner {
@@ -105,24 +383,118 @@ pub enum ExecuteMsg {
 #[derive(QueryResponses)]
 pub enum QueryMsg {
     /// Returns the config of the Bridge
-    #[returns(Config)]
+    #[returns(QueryConfigResponse)]
     Config {},
-    /// Returns the allowed signers for signature verification
+    /// Returns the allowed signers for signature verification with their metadata
     #[returns(QuerySignersResponse)]
-    Signers {},
-    /// Returns all the tokens that have been added to the bridge
+    Signers {
+        /// The base64 public key to start after
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns a previous signer set by the epoch it was active in
+    #[returns(SignerSet)]
+    SignerSet { epoch: u64 },
+    /// Returns the tickers of all the tokens that have been added to the bridge
     #[returns(QueryTokensResponse)]
     Tokens {
         start_after: Option<String>,
         limit: Option<u32>,
     },
-    /// Returns the disabled tokens
+    /// Returns the tickers of the disabled tokens
     #[returns(QueryTokensResponse)]
     DisabledTokens {
         start_after: Option<String>,
         limit: Option<u32>,
     },
+    /// Returns the registry record of a linked token
+    #[returns(TokenInfo)]
+    Token { ticker: String },
+    /// Returns the registry record of a linked token by its TokenFactory denom
+    #[returns(TokenInfo)]
+    TokenByDenom { denom: String },
     /// Check if transaction has been processed
     #[returns(bool)]
     IsTransactionProcessed { transaction_hash: String },
+    /// Returns the signatures collected for a transaction through Attest, for
+    /// each transfer attested for it
+    #[returns(Vec<PendingReceive>)]
+    PendingReceive { transaction_hash: String },
+    /// Returns the token links whose denom couldn't be created, ordered by ticker
+    #[returns(QueryFailedTokenLinksResponse)]
+    FailedTokenLinks {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns the receives collecting signatures, ordered by transaction hash
+    #[returns(QueryPendingReceivesResponse)]
+    PendingReceives {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns the amount tiers of a token
+    #[returns(Vec<AmountTier>)]
+    AmountTiers { ticker: String },
+    /// Returns the participation of the signers, ordered by their name
+    #[returns(QuerySignerStatsResponse)]
+    SignerStats {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns the names of the signers counted for a processed Receive
+    #[returns(Vec<String>)]
+    TransactionSigners { transaction_hash: String },
+    /// Returns the names of the signers counted for linking a token
+    #[returns(Vec<String>)]
+    LinkTokenSigners { ticker: String },
+    /// Returns the bond of a signer
+    #[returns(SignerBond)]
+    SignerBond { name: String },
+    /// Returns the rewards a signer can claim
+    #[returns(QueryPendingRewardsResponse)]
+    PendingRewards { name: String },
+    /// Returns the nonce the next key rotation of a signer must use
+    #[returns(u64)]
+    SignerRotationNonce { name: String },
+    /// Returns the metadata of a linked token
+    #[returns(TokenMetadata)]
+    TokenMetadata { ticker: String },
+    /// Returns the nonce the next signed metadata update of a token must use
+    #[returns(u64)]
+    TokenMetadataNonce { ticker: String },
+    /// Returns the queued admin actions
+    #[returns(QueryAdminActionsResponse)]
+    AdminActions {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Returns a pending signer change proposal
+    #[returns(SignerChangeProposal)]
+    SignerChangeProposal { proposal_id: u64 },
+    /// Returns the own signers of a token, if it has any
+    #[returns(QueryTokenSignersResponse)]
+    TokenSigners { ticker: String },
+    /// Checks signatures against an attestation the way the contract would,
+    /// without submitting them
+    #[returns(QueryVerifyAttestationResponse)]
+    VerifyAttestation {
+        /// The message kind and the fields of the attestation
+        attestation: AttestationRequest,
+        /// The signatures of from the verifying parties
+        #[serde(default)]
+        signatures: Vec<String>,
+        /// The signatures paired with the signer that made them
+        signer_signatures: Option<Vec<SignerSignature>>,
+        /// Signatures from secp256k1 signers made with off-chain wallets over
+        /// an ADR-036 sign-doc wrapping the attestation
+        adr036_signatures: Option<Vec<Adr036Signature>>,
+        /// The signer set epoch the signatures were made for, if not provided
+        /// the signatures are verified against the current signer set
+        signer_epoch: Option<u64>,
+        /// The encoding of the signed attestation, defaults to v1
+        attestation_version: Option<AttestationVersion>,
+        /// The signatures of the token's own signers over the same attestation,
+        /// only checked for a receive of a token with its own signer set
+        token_signatures: Option<Vec<String>>,
+    },
 }
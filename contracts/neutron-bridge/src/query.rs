@@ -1,8 +1,28 @@
-use crate::state::{CONFIG, DISABLED_TOKENS, HANDLED_TRANSACTIONS, TOKEN_MAPPING};
-use crate::types::{QuerySignersResponse, QueryTokensResponse};
-use crate::{msg::QueryMsg, state::SIGNERS};
+use crate::attestation::{resolve_attestation_version, Attestation};
+use crate::error::ContractError;
+use crate::helpers::{
+    attested_reward_fee_rate, find_amount_tier, get_signer_threshold, load_token_signer_keys,
+    load_verification_keys, match_signatures, total_signer_weight, validate_attestation_expiry,
+    validate_receive, verify_signatures, verify_token_signatures,
+};
+use crate::state::{
+    tokens, ADMIN_ACTIONS, AMOUNT_TIERS, CONFIG, FAILED_TOKEN_LINKS, HANDLED_TRANSACTIONS,
+    LINK_TOKEN_SIGNERS, PENDING_RECEIVES, SIGNER_BONDS, SIGNER_CHANGE_PROPOSALS, SIGNER_EPOCH,
+    SIGNER_REWARDS, SIGNER_ROTATION_NONCES, SIGNER_SET_HISTORY, SIGNER_STATS,
+    TOKEN_METADATA_NONCES, TOKEN_SIGNER_THRESHOLD, TRANSACTION_SIGNERS,
+};
+use crate::types::{
+    Adr036Signature, AmountTier, AttestationRequest, AttestationVersion, QueryAdminActionsResponse,
+    QueryConfigResponse, QueryFailedTokenLinksResponse, QueryPendingReceivesResponse,
+    QueryPendingRewardsResponse, QuerySignerStatsResponse, QuerySignersResponse,
+    QueryTokenSignersResponse, QueryTokensResponse, QueryVerifyAttestationResponse,
+    SignerSignature, SignerThreshold, TokenInfo, TokenStatus,
+};
+use crate::{msg::QueryMsg, state::signers};
 use base64::{engine::general_purpose, Engine as _};
-use cosmwasm_std::{entry_point, to_json_binary, Binary, Deps, Env, Order, StdError, StdResult};
+use cosmwasm_std::{
+    coin, entry_point, to_json_binary, Binary, Deps, Env, Order, StdError, StdResult,
+};
 use cw_storage_plus::Bound;
 use neutron_sdk::bindings::query::NeutronQuery;
 
@@ -13,26 +33,38 @@ const DEFAULT_LIMIT: u32 = 10;
 /// Expose available contract queries.
 ///
 /// ## Queries
-/// * **QueryMsg::Config {}** Returns the config of the Bridge
-/// * **QueryMsg::Signers {}** Returns the current signers and their public keys in base64
-/// * **QueryMsg::Tokens { start_after, limit }** Returns the CFT-20 and TokenFactory tokens that can be bridged
-/// * **QueryMsg::DisabledTokens { start_after, limit }** Returns the CFT-20 and TokenFactory tokens that have been disabled from bridging},
+/// * **QueryMsg::Config {}** Returns the config of the Bridge and the effective signer threshold
+/// * **QueryMsg::Signers { start_after, limit }** Returns the current signers with their public keys in base64 and metadata, the signer threshold and the total weight of the active signers
+/// * **QueryMsg::SignerSet { epoch }** Returns a previous signer set by the epoch it was active in
+/// * **QueryMsg::Tokens { start_after, limit }** Returns the CFT-20 tickers of the linked tokens
+/// * **QueryMsg::DisabledTokens { start_after, limit }** Returns the CFT-20 tickers of the tokens that have been disabled from bridging
+/// * **QueryMsg::Token { ticker }** Returns the registry record of a linked token
+/// * **QueryMsg::TokenByDenom { denom }** Returns the registry record of a linked token by its TokenFactory denom
+/// * **QueryMsg::FailedTokenLinks { start_after, limit }** Returns the token links whose denom couldn't be created
+/// * **QueryMsg::PendingReceive { transaction_hash }** Returns the signatures collected for a transaction through Attest
+/// * **QueryMsg::PendingReceives { start_after, limit }** Returns the receives collecting signatures through Attest
+/// * **QueryMsg::AmountTiers { ticker }** Returns the amount tiers of a token
+/// * **QueryMsg::SignerStats { start_after, limit }** Returns the amount of attestations every signer was counted for and when it was last seen
+/// * **QueryMsg::TransactionSigners { transaction_hash }** Returns the signers counted for a processed Receive
+/// * **QueryMsg::LinkTokenSigners { ticker }** Returns the signers counted for linking a token
+/// * **QueryMsg::SignerBond { name }** Returns the bond of a signer with its unbonding funds
+/// * **QueryMsg::PendingRewards { name }** Returns the rewards a signer can claim
+/// * **QueryMsg::SignerRotationNonce { name }** Returns the nonce the next key rotation of a signer must use
+/// * **QueryMsg::TokenMetadata { ticker }** Returns the metadata of a linked token
+/// * **QueryMsg::TokenMetadataNonce { ticker }** Returns the nonce the next signed metadata update of a token must use
+/// * **QueryMsg::AdminActions { start_after, limit }** Returns the queued admin actions
+/// * **QueryMsg::SignerChangeProposal { proposal_id }** Returns a pending signer change proposal with its approvals
+/// * **QueryMsg::TokenSigners { ticker }** Returns the own signers of a token with their threshold
+/// * **QueryMsg::VerifyAttestation { attestation, signatures, signer_signatures, adr036_signatures, signer_epoch, attestation_version, token_signatures }** Returns the message to sign, the signer of every signature and whether they would pass the verification
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps<NeutronQuery>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps<NeutronQuery>, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
-        QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
-        QueryMsg::Signers {} => {
-            let signers: Result<Vec<_>, StdError> = SIGNERS
-                .range(deps.storage, None, None, Order::Ascending)
-                .map(|result| {
-                    result.map(|(key, value)| (general_purpose::STANDARD.encode(key), value))
-                })
-                .collect();
-
-            match signers {
-                Ok(signers) => to_json_binary(&QuerySignersResponse { signers }),
-                Err(e) => Err(e),
-            }
+        QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::Signers { start_after, limit } => {
+            to_json_binary(&query_signers(deps, start_after, limit)?)
+        }
+        QueryMsg::SignerSet { epoch } => {
+            to_json_binary(&SIGNER_SET_HISTORY.load(deps.storage, epoch)?)
         }
         QueryMsg::Tokens { start_after, limit } => {
             to_json_binary(&query_all_tokens(deps, start_after, limit)?)
@@ -43,9 +75,362 @@ pub fn query(deps: Deps<NeutronQuery>, _env: Env, msg: QueryMsg) -> StdResult<Bi
         QueryMsg::IsTransactionProcessed { transaction_hash } => {
             to_json_binary(&query_is_transaction_processed(deps, transaction_hash)?)
         }
+        QueryMsg::PendingReceive { transaction_hash } => {
+            to_json_binary(&PENDING_RECEIVES.load(deps.storage, &transaction_hash)?)
+        }
+        QueryMsg::FailedTokenLinks { start_after, limit } => {
+            to_json_binary(&query_failed_token_links(deps, start_after, limit)?)
+        }
+        QueryMsg::PendingReceives { start_after, limit } => {
+            to_json_binary(&query_pending_receives(deps, start_after, limit)?)
+        }
+        QueryMsg::AmountTiers { ticker } => to_json_binary(&query_amount_tiers(deps, ticker)?),
+        QueryMsg::SignerStats { start_after, limit } => {
+            to_json_binary(&query_signer_stats(deps, start_after, limit)?)
+        }
+        QueryMsg::TransactionSigners { transaction_hash } => to_json_binary(
+            &TRANSACTION_SIGNERS
+                .may_load(deps.storage, &transaction_hash)?
+                .unwrap_or_default(),
+        ),
+        QueryMsg::LinkTokenSigners { ticker } => to_json_binary(
+            &LINK_TOKEN_SIGNERS
+                .may_load(deps.storage, &ticker)?
+                .unwrap_or_default(),
+        ),
+        QueryMsg::SignerBond { name } => to_json_binary(&SIGNER_BONDS.load(deps.storage, &name)?),
+        QueryMsg::PendingRewards { name } => to_json_binary(&query_pending_rewards(deps, name)?),
+        QueryMsg::SignerRotationNonce { name } => to_json_binary(
+            &SIGNER_ROTATION_NONCES
+                .may_load(deps.storage, &name)?
+                .unwrap_or_default(),
+        ),
+        QueryMsg::Token { ticker } => to_json_binary(&tokens().load(deps.storage, &ticker)?),
+        QueryMsg::TokenByDenom { denom } => to_json_binary(&query_token_by_denom(deps, denom)?),
+        QueryMsg::TokenMetadata { ticker } => {
+            to_json_binary(&tokens().load(deps.storage, &ticker)?.metadata)
+        }
+        QueryMsg::TokenMetadataNonce { ticker } => to_json_binary(
+            &TOKEN_METADATA_NONCES
+                .may_load(deps.storage, &ticker)?
+                .unwrap_or_default(),
+        ),
+        QueryMsg::AdminActions { start_after, limit } => {
+            to_json_binary(&query_admin_actions(deps, start_after, limit)?)
+        }
+        QueryMsg::SignerChangeProposal { proposal_id } => {
+            to_json_binary(&SIGNER_CHANGE_PROPOSALS.load(deps.storage, proposal_id)?)
+        }
+        QueryMsg::TokenSigners { ticker } => to_json_binary(&query_token_signers(deps, ticker)?),
+        QueryMsg::VerifyAttestation {
+            attestation,
+            signatures,
+            signer_signatures,
+            adr036_signatures,
+            signer_epoch,
+            attestation_version,
+            token_signatures,
+        } => to_json_binary(&query_verify_attestation(
+            deps,
+            env,
+            attestation,
+            signatures,
+            signer_signatures.unwrap_or_default(),
+            adr036_signatures.unwrap_or_default(),
+            signer_epoch,
+            attestation_version,
+            token_signatures.unwrap_or_default(),
+        )?),
     }
 }
 
+/// Queries the signers ordered by their public key, the threshold and the
+/// total weight always cover all the active signers
+pub fn query_signers(
+    deps: Deps<NeutronQuery>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<QuerySignersResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_key = start_after
+        .map(|public_key_base64| general_purpose::STANDARD.decode(public_key_base64))
+        .transpose()
+        .map_err(|_| StdError::generic_err("Key could not be decoded"))?;
+    let start_bound = start_key.as_deref().map(Bound::exclusive);
+
+    let signers = signers()
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .map(|result| result.map(|(key, value)| (general_purpose::STANDARD.encode(key), value)))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let config = CONFIG.load(deps.storage)?;
+    let total_weight = total_signer_weight(deps.storage)?;
+    let threshold = get_signer_threshold(&config.signer_threshold, total_weight);
+    let epoch = SIGNER_EPOCH.load(deps.storage)?;
+
+    Ok(QuerySignersResponse {
+        signers,
+        threshold,
+        total_weight,
+        epoch,
+    })
+}
+
+/// Queries the receives collecting signatures, expired ones are included
+/// until the transaction is attested again
+pub fn query_pending_receives(
+    deps: Deps<NeutronQuery>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<QueryPendingReceivesResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_bound = start_after.as_deref().map(Bound::exclusive);
+
+    let receives = PENDING_RECEIVES
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(QueryPendingReceivesResponse { receives })
+}
+
+/// Queries the token links whose denom couldn't be created, ordered by ticker
+pub fn query_failed_token_links(
+    deps: Deps<NeutronQuery>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<QueryFailedTokenLinksResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_bound = start_after.as_deref().map(Bound::exclusive);
+
+    let links = FAILED_TOKEN_LINKS
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, link)| link))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(QueryFailedTokenLinksResponse { links })
+}
+
+/// Queries the participation of the signers, ordered by their name
+pub fn query_signer_stats(
+    deps: Deps<NeutronQuery>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<QuerySignerStatsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_bound = start_after.as_deref().map(Bound::exclusive);
+
+    let stats = SIGNER_STATS
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(QuerySignerStatsResponse { stats })
+}
+
+/// Queries the rewards a signer accrued and didn't claim yet
+pub fn query_pending_rewards(
+    deps: Deps<NeutronQuery>,
+    name: String,
+) -> StdResult<QueryPendingRewardsResponse> {
+    let rewards = SIGNER_REWARDS
+        .prefix(&name)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(denom, amount)| coin(amount.u128(), denom)))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(QueryPendingRewardsResponse { rewards })
+}
+
+/// Checks the signatures of an attestation without submitting them
+/// Every signature is matched to its signer, while passing is decided by the
+/// same verification the contract runs when the attestation is submitted
+#[allow(clippy::too_many_arguments)]
+pub fn query_verify_attestation(
+    deps: Deps<NeutronQuery>,
+    env: Env,
+    attestation: AttestationRequest,
+    signatures: Vec<String>,
+    signer_signatures: Vec<SignerSignature>,
+    adr036_signatures: Vec<Adr036Signature>,
+    signer_epoch: Option<u64>,
+    attestation_version: Option<AttestationVersion>,
+    token_signatures: Vec<String>,
+) -> StdResult<QueryVerifyAttestationResponse> {
+    let to_std_error = |err: ContractError| StdError::generic_err(err.to_string());
+    let config = CONFIG.load(deps.storage)?;
+    let attestation_version =
+        resolve_attestation_version(&config, attestation_version).map_err(to_std_error)?;
+
+    // Build the message exactly like the matching execute message does, a
+    // receive is also checked the way Receive checks it before its signatures
+    let (message, amount_tier, token_ticker, precheck) = match attestation {
+        AttestationRequest::LinkToken {
+            source_chain_id,
+            token,
+        } => {
+            let message = Attestation::link_token(&env, &source_chain_id, &token)
+                .with_optional("signer_epoch", signer_epoch)
+                .encode(attestation_version);
+            (message, None, None, Ok(()))
+        }
+        AttestationRequest::Receive {
+            source_chain_id,
+            transaction_hash,
+            ticker,
+            amount,
+            destination_addr,
+            expires_at,
+        } => {
+            let message = Attestation::receive(
+                &env,
+                &source_chain_id,
+                &transaction_hash,
+                &ticker,
+                amount,
+                &destination_addr,
+            )
+            .with_optional("signer_epoch", signer_epoch)
+            .with_optional(
+                "expires_at",
+                expires_at.map(|expires_at| expires_at.seconds()),
+            )
+            .with_optional(
+                "reward_fee_rate",
+                attested_reward_fee_rate(&config, attestation_version),
+            )
+            .encode(attestation_version);
+            let amount_tiers = AMOUNT_TIERS
+                .may_load(deps.storage, &ticker)?
+                .unwrap_or_default();
+            let precheck =
+                validate_receive(deps, &transaction_hash, &ticker, amount, &destination_addr)
+                    .and_then(|()| {
+                        validate_attestation_expiry(&env, expires_at, attestation_version)
+                    });
+            (
+                message,
+                find_amount_tier(&amount_tiers, amount).cloned(),
+                Some(ticker),
+                precheck,
+            )
+        }
+    };
+
+    let (allowed_keys, threshold) =
+        load_verification_keys(deps, &env, signer_epoch, amount_tier.as_ref())
+            .map_err(to_std_error)?;
+    let matches = match_signatures(
+        deps,
+        &message,
+        &signatures,
+        &signer_signatures,
+        &adr036_signatures,
+        &allowed_keys,
+    );
+    let verification = precheck
+        .and_then(|()| {
+            verify_signatures(
+                deps,
+                &env,
+                &message,
+                &signatures,
+                &signer_signatures,
+                &adr036_signatures,
+                signer_epoch,
+                amount_tier.as_ref(),
+            )
+        })
+        .and_then(|signers| {
+            if let Some(ticker) = &token_ticker {
+                verify_token_signatures(deps, ticker, &message, &token_signatures)?;
+            }
+            Ok(signers)
+        });
+    let (signers, error) = match verification {
+        Ok(signers) => (signers, None),
+        Err(err) => (vec![], Some(err.to_string())),
+    };
+
+    Ok(QueryVerifyAttestationResponse {
+        message: Binary::from(message),
+        signatures: matches,
+        threshold,
+        passed: error.is_none(),
+        signers,
+        error,
+    })
+}
+
+/// Queries the admin actions waiting to be executed, ordered by their ID
+pub fn query_admin_actions(
+    deps: Deps<NeutronQuery>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<QueryAdminActionsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_bound = start_after.map(Bound::exclusive);
+
+    let actions = ADMIN_ACTIONS
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(QueryAdminActionsResponse { actions })
+}
+
+/// Queries the own signers of a token and the weight required from them
+pub fn query_token_signers(
+    deps: Deps<NeutronQuery>,
+    ticker: String,
+) -> StdResult<QueryTokenSignersResponse> {
+    let signers = load_token_signer_keys(deps.storage, &ticker)?
+        .into_iter()
+        .map(|(key, signer)| (general_purpose::STANDARD.encode(key), signer))
+        .collect::<Vec<_>>();
+    let signer_threshold = TOKEN_SIGNER_THRESHOLD
+        .may_load(deps.storage, &ticker)?
+        .unwrap_or_else(SignerThreshold::supermajority);
+    let total_weight = signers.iter().map(|(_, signer)| signer.weight).sum();
+    // Tokens without signers of their own don't require any signatures
+    let threshold = if signers.is_empty() {
+        0
+    } else {
+        get_signer_threshold(&signer_threshold, total_weight)
+    };
+
+    Ok(QueryTokenSignersResponse {
+        signers,
+        signer_threshold,
+        threshold,
+        total_weight,
+    })
+}
+
+/// Queries the config along with the signer threshold currently in effect
+pub fn query_config(deps: Deps<NeutronQuery>) -> StdResult<QueryConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let total_weight = total_signer_weight(deps.storage)?;
+    let effective_signer_threshold = get_signer_threshold(&config.signer_threshold, total_weight);
+
+    Ok(QueryConfigResponse {
+        config,
+        effective_signer_threshold,
+    })
+}
+
+/// Queries a linked token by its TokenFactory denom
+pub fn query_token_by_denom(deps: Deps<NeutronQuery>, denom: String) -> StdResult<TokenInfo> {
+    tokens()
+        .idx
+        .denom
+        .item(deps.storage, denom)?
+        .map(|(_, token)| token)
+        .ok_or_else(|| StdError::not_found("TokenInfo"))
+}
+
 /// Queries all tokens that have been added to the bridge
 pub fn query_all_tokens(
     deps: Deps<NeutronQuery>,
@@ -55,10 +440,9 @@ pub fn query_all_tokens(
     let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
     let start_bound = start_after.as_deref().map(Bound::exclusive);
 
-    let tokens = TOKEN_MAPPING
+    let tokens = tokens()
         .keys(deps.storage, start_bound, None, Order::Ascending)
         .take(limit)
-        .map(|key_result| key_result.map_err(StdError::from))
         .collect::<StdResult<Vec<String>>>()?;
 
     Ok(QueryTokensResponse { tokens })
@@ -73,10 +457,14 @@ pub fn query_disabled_tokens(
     let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
     let start_bound = start_after.as_deref().map(Bound::exclusive);
 
-    let tokens = DISABLED_TOKENS
-        .keys(deps.storage, start_bound, None, Order::Ascending)
+    let tokens = tokens()
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .filter(|item| {
+            item.as_ref()
+                .map_or(true, |(_, token)| token.status == TokenStatus::Disabled)
+        })
         .take(limit)
-        .map(|key_result| key_result.map_err(StdError::from))
+        .map(|item| item.map(|(ticker, _)| ticker))
         .collect::<StdResult<Vec<String>>>()?;
 
     Ok(QueryTokensResponse { tokens })
@@ -89,3 +477,10 @@ pub fn query_is_transaction_processed(
 ) -> StdResult<bool> {
     Ok(HANDLED_TRANSACTIONS.has(deps.storage, &transaction_hash))
 }
+
+/// Queries the amount tiers of a token, tokens without tiers return an empty list
+pub fn query_amount_tiers(deps: Deps<NeutronQuery>, ticker: String) -> StdResult<Vec<AmountTier>> {
+    Ok(AMOUNT_TIERS
+        .may_load(deps.storage, &ticker)?
+        .unwrap_or_default())
+}
@@ -1,30 +1,147 @@
-use cw_storage_plus::{Item, Map};
+use cosmwasm_std::Uint128;
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, UniqueIndex};
 
-use crate::types::{BridgingAsset, Config, TokenMetadata};
+use crate::types::{
+    AmountTier, BridgingAsset, Config, FailedTokenLink, PendingReceive, PendingTokenLink,
+    QueuedAdminAction, RotatedSignerKey, SignerBond, SignerChangeProposal, SignerInfo, SignerSet,
+    SignerStats, SignerThreshold, TokenInfo,
+};
 
 use astroport::common::OwnershipProposal;
 
 /// Store the contract config
 pub const CONFIG: Item<Config> = Item::new("config");
 
-/// The public keys of the allowed signers of bridge messages used to confirm
+/// The indexes of the signer registry
+pub struct SignerIndexes<'a> {
+    /// The unique name of every signer
+    pub name: UniqueIndex<'a, String, SignerInfo, Vec<u8>>,
+}
+
+impl<'a> IndexList<SignerInfo> for SignerIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<SignerInfo>> + '_> {
+        let v: Vec<&dyn Index<SignerInfo>> = vec![&self.name];
+        Box::new(v.into_iter())
+    }
+}
+
+/// The registry of the allowed signers of bridge messages used to confirm
 /// signature. The public key is stored in the format required during bridging
-/// It holds <public key, name> to help identify specific keys
-pub const SIGNERS: Map<&[u8], String> = Map::new("signers");
+/// It holds <public key, signer info> to help identify specific keys, to know
+/// which algorithm verifies their signatures and whether they are active
+/// Signers can be looked up by their unique name
+pub fn signers<'a>() -> IndexedMap<'a, &'a [u8], SignerInfo, SignerIndexes<'a>> {
+    let indexes = SignerIndexes {
+        name: UniqueIndex::new(|signer| signer.name.clone(), "signers__name"),
+    };
+    IndexedMap::new("signers", indexes)
+}
+
+/// The keys rotated out of the signer set that are still accepted during their
+/// overlap period, keyed by the rotated public key
+pub const ROTATED_SIGNER_KEYS: Map<&[u8], RotatedSignerKey> = Map::new("rotated_signer_keys");
+
+/// The nonce the next key rotation of a signer must use, keyed by the signer name
+pub const SIGNER_ROTATION_NONCES: Map<&str, u64> = Map::new("signer_rotation_nonces");
+
+/// The participation of every signer, keyed by the signer name so it carries
+/// over key rotations
+pub const SIGNER_STATS: Map<&str, SignerStats> = Map::new("signer_stats");
+
+/// The names of the signers counted for every processed Receive, keyed by
+/// the transaction hash
+pub const TRANSACTION_SIGNERS: Map<&str, Vec<String>> = Map::new("transaction_signers");
+
+/// The names of the signers counted for every linked token, keyed by the
+/// CFT-20 ticker
+pub const LINK_TOKEN_SIGNERS: Map<&str, Vec<String>> = Map::new("link_token_signers");
+
+/// The bonds posted for the signers, keyed by the signer name so a bond can
+/// be posted before the signer is added
+pub const SIGNER_BONDS: Map<&str, SignerBond> = Map::new("signer_bonds");
+
+/// The rewards accrued by the signers and not claimed yet, keyed by
+/// (signer name, TokenFactory denom)
+pub const SIGNER_REWARDS: Map<(&str, &str), Uint128> = Map::new("signer_rewards");
+
+/// The evidence that has been used to slash a signer, keyed by
+/// (signer name, transaction hash)
+pub const SLASHED_EVIDENCE: Map<(&str, &str), bool> = Map::new("slashed_evidence");
+
+/// The epoch of the current signer set, bumped every time the signers change
+pub const SIGNER_EPOCH: Item<u64> = Item::new("signer_epoch");
+
+/// The history of past signer sets, keyed by the epoch they were active in
+pub const SIGNER_SET_HISTORY: Map<u64, SignerSet> = Map::new("signer_set_history");
 
-// Token Mapping is kept in a map of
-// CFT-20 Ticker -> TokenFactory denom as well as the reverse
-// TokenFactory denom -> CFT-20 Ticker
-pub const TOKEN_MAPPING: Map<&str, String> = Map::new("token_mapping");
+/// The indexes of the token registry
+pub struct TokenIndexes<'a> {
+    /// The unique TokenFactory denom of every token
+    pub denom: UniqueIndex<'a, String, TokenInfo, String>,
+}
 
-/// Store the disabled tokens
-pub const DISABLED_TOKENS: Map<&str, bool> = Map::new("disabled_tokens");
+impl<'a> IndexList<TokenInfo> for TokenIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<TokenInfo>> + '_> {
+        let v: Vec<&dyn Index<TokenInfo>> = vec![&self.denom];
+        Box::new(v.into_iter())
+    }
+}
+
+/// The registry of the linked tokens, keyed by the CFT-20 ticker
+/// Tokens can be looked up by their unique TokenFactory denom
+pub fn tokens<'a>() -> IndexedMap<'a, &'a str, TokenInfo, TokenIndexes<'a>> {
+    let indexes = TokenIndexes {
+        denom: UniqueIndex::new(|token| token.denom.clone(), "tokens__denom"),
+    };
+    IndexedMap::new("tokens", indexes)
+}
+
+/// The amount tiers of a CFT-20 ticker, ordered by their minimum amount
+pub const AMOUNT_TIERS: Map<&str, Vec<AmountTier>> = Map::new("amount_tiers");
+
+/// The own signers of a CFT-20 ticker, required on top of the global signers
+/// It holds <(ticker, public key), signer info>
+pub const TOKEN_SIGNERS: Map<(&str, &[u8]), SignerInfo> = Map::new("token_signers");
+
+/// The policy used to calculate the weight required from the signers of a
+/// CFT-20 ticker, a supermajority is used when it isn't set
+pub const TOKEN_SIGNER_THRESHOLD: Map<&str, SignerThreshold> = Map::new("token_signer_threshold");
+
+/// The pending signer change proposals, keyed by their ID
+pub const SIGNER_CHANGE_PROPOSALS: Map<u64, SignerChangeProposal> =
+    Map::new("signer_change_proposals");
+
+/// The ID the next signer change proposal gets
+pub const NEXT_SIGNER_CHANGE_ID: Item<u64> = Item::new("next_signer_change_id");
+
+/// The admin actions waiting for their delay to pass, keyed by their ID
+pub const ADMIN_ACTIONS: Map<u64, QueuedAdminAction> = Map::new("admin_actions");
+
+/// The ID the next queued admin action gets
+pub const NEXT_ADMIN_ACTION_ID: Item<u64> = Item::new("next_admin_action_id");
+
+/// The receives collecting signatures through Attest, keyed by the hash of
+/// the transaction on the origin chain, with one entry for each attested transfer
+pub const PENDING_RECEIVES: Map<&str, Vec<PendingReceive>> = Map::new("pending_receives");
 
 /// Store the transactions we've processed
 pub const HANDLED_TRANSACTIONS: Map<&str, bool> = Map::new("handled_transactions");
 
-/// Store the token metadata when the denom is created via Reply
-pub const TOKEN_METADATA: Item<TokenMetadata> = Item::new("token_metadata");
+/// The nonce the next signed metadata update of a token must be made for,
+/// keyed by the CFT-20 ticker
+pub const TOKEN_METADATA_NONCES: Map<&str, u64> = Map::new("token_metadata_nonces");
+
+/// Store the tokens being linked until their denom is created via Reply, keyed
+/// by the subdenom so several tokens can be linked in one transaction
+pub const PENDING_TOKEN_LINKS: Map<&str, PendingTokenLink> = Map::new("pending_token_links");
+
+/// The subdenoms of the denoms being created, in the order their replies come
+/// back. A failed creation only tells the reply that it failed, not which
+/// denom it was for
+pub const DENOM_CREATION_QUEUE: Item<Vec<String>> = Item::new("denom_creation_queue");
+
+/// The token links whose denom couldn't be created, keyed by the CFT-20 ticker
+pub const FAILED_TOKEN_LINKS: Map<&str, FailedTokenLink> = Map::new("failed_token_links");
 
 /// Contains a proposal to change contract ownership
 pub const OWNERSHIP_PROPOSAL: Item<OwnershipProposal> = Item::new("ownership_proposal");
@@ -1,5 +1,7 @@
+use std::fmt;
+
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Coin};
+use cosmwasm_std::{Addr, Binary, Coin, Decimal, Timestamp, Uint128};
 use neutron_sdk::bindings::msg::IbcFee;
 
 // Minimum IBC timeout is 5 seconds
@@ -7,9 +9,40 @@ pub const MIN_IBC_TIMEOUT_SECONDS: u64 = 5;
 // Maximum IBC timeout is 1 hour
 pub const MAX_IBC_TIMEOUT_SECONDS: u64 = 60 * 60;
 
+// Default grace period during which a replaced signer set is still accepted
+pub const DEFAULT_SIGNER_EPOCH_GRACE_SECONDS: u64 = 60 * 60;
+// Maximum grace period for a replaced signer set is 7 days
+pub const MAX_SIGNER_EPOCH_GRACE_SECONDS: u64 = 7 * 24 * 60 * 60;
+// Maximum period a rotated signer key is still accepted is 7 days
+pub const MAX_SIGNER_KEY_OVERLAP_SECONDS: u64 = 7 * 24 * 60 * 60;
+// Maximum delay before a queued admin action can be executed is 30 days
+pub const MAX_ADMIN_ACTION_DELAY_SECONDS: u64 = 30 * 24 * 60 * 60;
+// Default time bonds are locked after unbonding is 14 days
+pub const DEFAULT_UNBONDING_PERIOD_SECONDS: u64 = 14 * 24 * 60 * 60;
+// Maximum time bonds are locked after unbonding is 30 days
+pub const MAX_UNBONDING_PERIOD_SECONDS: u64 = 30 * 24 * 60 * 60;
+// Default age after which a pending receive expires is 1 day
+pub const DEFAULT_PENDING_RECEIVE_MAX_AGE_SECONDS: u64 = 24 * 60 * 60;
+// Maximum age of a pending receive is 7 days
+pub const MAX_PENDING_RECEIVE_MAX_AGE_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+// Maximum share of a received transfer paid to the signers as rewards is 10%
+pub const MAX_REWARD_FEE_RATE: Decimal = Decimal::percent(10);
+
 pub const FEE_DENOM: &str = "untrn";
-// Signer threshold can't be less than this value
-pub const MIN_SIGNER_THRESHOLD: u8 = 2;
+// Signer threshold can't be less than this value, it is also the minimum
+// amount of signers that need to sign regardless of their weight
+pub const MIN_SIGNER_THRESHOLD: u64 = 2;
+// Maximum length of the contact URL of a signer
+pub const MAX_SIGNER_CONTACT_URL_LENGTH: usize = 256;
+// The voting weight of a signer if none is given
+pub const DEFAULT_SIGNER_WEIGHT: u64 = 1;
+// Maximum amount of amount tiers for a single token
+pub const MAX_AMOUNT_TIERS: usize = 10;
+// Maximum voting weight of a single signer, keeps the total weight far from overflowing
+pub const MAX_SIGNER_WEIGHT: u64 = 1_000_000;
+// Length of a compressed SEC1 secp256k1 public key
+pub const SECP256K1_PUBLIC_KEY_LENGTH: usize = 33;
 // The reply ID for the instantiate_denom reply when linking a token
 pub const INSTANTIATE_DENOM_REPLY_ID: u64 = 1;
 // The reply ID for IBC transfer to capture the channel and sequence
@@ -25,6 +58,70 @@ pub struct Config {
     pub bridge_ibc_channel: String,
     /// The timeout in seconds for IBC packets
     pub ibc_timeout_seconds: u64,
+    /// The policy used to calculate the amount of valid signatures required
+    pub signer_threshold: SignerThreshold,
+    /// The time in seconds a replaced signer set is still accepted after
+    /// it was replaced
+    pub signer_epoch_grace_seconds: u64,
+    /// Whether attestations in the legacy v1 encoding are still accepted
+    pub v1_attestations_enabled: bool,
+    /// Whether the owner can still change the signer set directly, once
+    /// disabled only the signers can change it through signer change proposals
+    pub owner_signer_management: bool,
+    /// The time in seconds admin actions are queued before they can be
+    /// executed, admin actions apply immediately when this is 0
+    pub admin_action_delay_seconds: u64,
+    /// The time in seconds signatures are collected for a pending receive
+    /// before it expires
+    pub pending_receive_max_age_seconds: u64,
+    /// The amount of untrn a signer must have bonded to be added
+    pub min_signer_bond: Uint128,
+    /// The time in seconds unbonded funds are locked before they can be withdrawn
+    pub unbonding_period_seconds: u64,
+    /// The address receiving the slashed bonds
+    pub treasury: Option<Addr>,
+    /// The share of every received transfer paid to the bonded signers
+    /// counted for it, no rewards are paid when this is 0. The rate is part of
+    /// the v2 receive attestation, transfers attested in v1 don't pay it
+    pub reward_fee_rate: Decimal,
+}
+
+#[cw_serde]
+#[derive(Copy, Default)]
+pub enum AttestationVersion {
+    /// The plain concatenation of the attested fields
+    #[default]
+    V1,
+    /// The domain-separated and length-prefixed fields hashed with sha256
+    V2,
+}
+
+#[cw_serde]
+pub enum SignerThreshold {
+    /// A fixed total weight of valid signatures is required, with equal
+    /// weights this is M-of-N
+    Fixed { weight: u64 },
+    /// A percentage of the total weight of the current signers is required,
+    /// rounded up
+    Percentage { percentage: Decimal },
+}
+
+impl SignerThreshold {
+    /// The default policy, two-thirds of the total signer weight rounded up
+    pub fn supermajority() -> Self {
+        SignerThreshold::Percentage {
+            percentage: Decimal::from_ratio(2u128, 3u128),
+        }
+    }
+}
+
+#[cw_serde]
+pub struct AmountTier {
+    /// The tier applies to transfers of at least this amount
+    pub min_amount: Uint128,
+    /// The percentage of the total signer weight required for these transfers,
+    /// only used when it requires more than the global signer threshold
+    pub percentage: Decimal,
 }
 
 #[cw_serde]
@@ -39,16 +136,570 @@ pub struct TokenMetadata {
     pub decimals: u32,
 }
 
+#[cw_serde]
+#[derive(Copy, Default)]
+pub enum TokenStatus {
+    /// The token can be bridged in both directions
+    #[default]
+    Enabled,
+    /// The token is linked but can't be bridged
+    Disabled,
+}
+
+/// A CFT-20 token linked to a TokenFactory denom
+#[cw_serde]
+pub struct TokenInfo {
+    /// The metadata of the CFT-20 token, kept in sync with its denom metadata
+    pub metadata: TokenMetadata,
+    /// The TokenFactory denom minted for the token
+    pub denom: String,
+    /// The chain ID of the chain the token was linked from
+    pub source_chain_id: String,
+    /// The block height the token was linked at, tokens linked before the
+    /// registry existed hold the height they were migrated at
+    pub link_height: u64,
+    /// Whether the token can currently be bridged
+    pub status: TokenStatus,
+}
+
+/// A CFT-20 token to link in a LinkTokens batch
+#[cw_serde]
+pub struct TokenLink {
+    /// The chain ID of the source chain
+    pub source_chain_id: String,
+    /// The metadata of the CFT-20 token
+    pub token: TokenMetadata,
+    /// The signatures of from the verifying parties
+    #[serde(default)]
+    pub signatures: Vec<String>,
+    /// The signatures paired with the signer that made them
+    pub signer_signatures: Option<Vec<SignerSignature>>,
+    /// Signatures from secp256k1 signers made with off-chain wallets over
+    /// an ADR-036 sign-doc wrapping the attestation
+    pub adr036_signatures: Option<Vec<Adr036Signature>>,
+    /// The signer set epoch the signatures were made for
+    pub signer_epoch: Option<u64>,
+    /// The encoding of the signed attestation, defaults to v1
+    pub attestation_version: Option<AttestationVersion>,
+}
+
+/// A token link whose TokenFactory denom couldn't be created, linking the
+/// token again clears it
+#[cw_serde]
+pub struct FailedTokenLink {
+    /// The chain ID of the chain the token was linked from
+    pub source_chain_id: String,
+    /// The metadata of the CFT-20 token
+    pub token: TokenMetadata,
+    /// The reason the denom couldn't be created
+    pub reason: TokenLinkFailure,
+    /// The block height the link failed at
+    pub failed_at_height: u64,
+}
+
+/// The reason the TokenFactory denom of a token link couldn't be created
+#[cw_serde]
+pub enum TokenLinkFailure {
+    /// The denom already exists and isn't administered by the bridge
+    DenomAlreadyExists { denom: String },
+    /// Creating the denom failed with the given error
+    DenomCreationFailed { error: String },
+}
+
+impl TokenLinkFailure {
+    /// The name of the failure as used in events
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenLinkFailure::DenomAlreadyExists { .. } => "denom_already_exists",
+            TokenLinkFailure::DenomCreationFailed { .. } => "denom_creation_failed",
+        }
+    }
+}
+
+/// A token waiting for its TokenFactory denom to be created
+#[cw_serde]
+pub struct PendingTokenLink {
+    /// The chain ID of the chain the token is linked from
+    pub source_chain_id: String,
+    /// The metadata of the CFT-20 token
+    pub token: TokenMetadata,
+    /// The names of the signers that attested the link, only counted once
+    /// the denom exists
+    pub signers: Vec<String>,
+}
+
+#[cw_serde]
+pub struct QueryConfigResponse {
+    /// The config of the Bridge
+    pub config: Config,
+    /// The total weight of valid signatures currently required
+    pub effective_signer_threshold: u64,
+}
+
+#[cw_serde]
+#[derive(Copy, Default)]
+pub enum KeyType {
+    /// An ed25519 key verifying signatures over the attestation
+    #[default]
+    Ed25519,
+    /// A secp256k1 key verifying signatures over the sha256 of the attestation
+    Secp256k1,
+}
+
+impl KeyType {
+    /// The name of the key algorithm as used in events
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KeyType::Ed25519 => "ed25519",
+            KeyType::Secp256k1 => "secp256k1",
+        }
+    }
+}
+
+#[cw_serde]
+#[derive(Copy, Default)]
+pub enum SignerStatus {
+    /// The signer's signatures count toward the threshold
+    #[default]
+    Active,
+    /// The signer stays registered but its signatures are not counted
+    Suspended,
+}
+
+impl SignerStatus {
+    /// The name of the status as used in events
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SignerStatus::Active => "active",
+            SignerStatus::Suspended => "suspended",
+        }
+    }
+}
+
+#[cw_serde]
+pub struct SignerInfo {
+    /// A simple human name for the owner of the public key, unique among the signers
+    pub name: String,
+    /// The algorithm of the public key
+    pub key_type: KeyType,
+    /// The voting weight of the signer toward the threshold
+    pub weight: u64,
+    /// A URL to reach the operator of the signer
+    pub contact_url: Option<String>,
+    /// The time the signer was added
+    pub added_at: Timestamp,
+    /// Whether the signer's signatures are counted
+    pub status: SignerStatus,
+}
+
+impl SignerInfo {
+    /// Whether the signer's signatures count toward the threshold
+    pub fn is_active(&self) -> bool {
+        self.status == SignerStatus::Active
+    }
+}
+
+#[cw_serde]
+pub struct RotatedSignerKey {
+    /// The public key that replaced the rotated key
+    pub new_public_key: Binary,
+    /// The time the rotated key is no longer accepted
+    pub expires_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct SignerBond {
+    /// The address registered by the owner of the contract to manage the
+    /// bond, only it can add to or unbond it
+    pub owner: Addr,
+    /// The amount of untrn bonded
+    pub amount: Uint128,
+    /// The amount of untrn waiting for the unbonding period to end
+    pub unbonding_amount: Uint128,
+    /// The time the unbonding amount can be withdrawn
+    pub unbonding_release_at: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct SignedReceive {
+    /// The ticker of the CFT-20 token
+    pub ticker: String,
+    /// The amount of CFT-20 tokens
+    pub amount: Uint128,
+    /// The destination address to transfer the CFT-20-equivalent to
+    pub destination_addr: String,
+    /// The signer set epoch the signature was made for
+    pub signer_epoch: Option<u64>,
+    /// The expiry the signature was made with
+    pub expires_at: Option<Timestamp>,
+    /// The reward fee rate the signature was made with
+    pub reward_fee_rate: Option<Decimal>,
+    /// The encoding of the signed attestation
+    pub attestation_version: AttestationVersion,
+    /// The signature of the signer in base64
+    pub signature: String,
+}
+
+/// Proof that a signer signed two different transfers for the same transaction
+#[cw_serde]
+pub struct SlashEvidence {
+    /// The chain ID of the source chain
+    pub source_chain_id: String,
+    /// The hash of the transaction on the origin chain
+    pub transaction_hash: String,
+    /// The first transfer the signer signed
+    pub first: SignedReceive,
+    /// The conflicting transfer the signer signed
+    pub second: SignedReceive,
+}
+
+#[cw_serde]
+pub enum SignerReference {
+    /// The public key of the signer in base64
+    PublicKey { public_key_base64: String },
+    /// The position of the signer in the signer set, ordered the same as the
+    /// signers query
+    Index { index: u32 },
+}
+
+impl fmt::Display for SignerReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignerReference::PublicKey { public_key_base64 } => write!(f, "{}", public_key_base64),
+            SignerReference::Index { index } => write!(f, "#{}", index),
+        }
+    }
+}
+
+#[cw_serde]
+pub struct SignerSignature {
+    /// The signer that made the signature
+    pub signer: SignerReference,
+    /// The signature over the attestation in base64
+    pub signature: String,
+}
+
+#[cw_serde]
+pub struct Adr036Signature {
+    /// The secp256k1 public key of the signer in base64, the signer address
+    /// of the sign-doc is derived from it
+    pub public_key_base64: String,
+    /// The signature over the ADR-036 sign-doc in base64
+    pub signature: String,
+}
+
+#[cw_serde]
+pub enum SignerChange {
+    /// Add a signer to the signer set
+    AddSigner {
+        public_key_base64: String,
+        name: String,
+        key_type: Option<KeyType>,
+        weight: Option<u64>,
+        contact_url: Option<String>,
+    },
+    /// Remove a signer from the signer set
+    RemoveSigner { public_key_base64: String },
+    /// Update the voting weight of a signer
+    UpdateSignerWeight {
+        public_key_base64: String,
+        weight: u64,
+    },
+    /// Suspend or reactivate a signer
+    UpdateSignerStatus {
+        public_key_base64: String,
+        status: SignerStatus,
+    },
+    /// Update the policy used to calculate the weight of valid signatures required
+    UpdateSignerThreshold { signer_threshold: SignerThreshold },
+}
+
+#[cw_serde]
+pub struct SignerChangeProposal {
+    /// The change applied to the signer set once approved
+    pub change: SignerChange,
+    /// The signer epoch the proposal was made in, approvals are only
+    /// accepted while this epoch is current
+    pub signer_epoch: u64,
+    /// The public keys of the signers that approved the change
+    pub approvals: Vec<Binary>,
+    /// The address that proposed the change
+    pub proposer: Addr,
+}
+
+#[cw_serde]
+pub enum AdminAction {
+    /// Update the contract config
+    UpdateConfig {
+        bridge_ibc_channel: Option<String>,
+        ibc_timeout_seconds: Option<u64>,
+        signer_threshold: Option<SignerThreshold>,
+        signer_epoch_grace_seconds: Option<u64>,
+        v1_attestations_enabled: Option<bool>,
+        admin_action_delay_seconds: Option<u64>,
+        pending_receive_max_age_seconds: Option<u64>,
+        min_signer_bond: Option<Uint128>,
+        unbonding_period_seconds: Option<u64>,
+        treasury: Option<String>,
+        reward_fee_rate: Option<Decimal>,
+    },
+    /// Add a signer to the signer set
+    AddSigner {
+        public_key_base64: String,
+        name: String,
+        key_type: Option<KeyType>,
+        weight: Option<u64>,
+        contact_url: Option<String>,
+    },
+    /// Remove a signer from the signer set
+    RemoveSigner { public_key_base64: String },
+    /// Update the voting weight of a signer
+    UpdateSignerWeight {
+        public_key_base64: String,
+        weight: u64,
+    },
+    /// Count the signatures of a suspended signer again
+    ReactivateSigner { public_key_base64: String },
+    /// Register the address that owns the bond of a signer
+    SetSignerBondOwner { name: String, owner: String },
+    /// Enable a previously disabled token to being bridged again
+    EnableToken { ticker: String },
+    /// Override the metadata of a linked token
+    UpdateTokenMetadata { token: TokenMetadata },
+    /// Set the amount tiers of a token
+    SetAmountTiers {
+        ticker: String,
+        tiers: Vec<AmountTier>,
+    },
+    /// Add a signer to the own signer set of a token
+    AddTokenSigner {
+        ticker: String,
+        public_key_base64: String,
+        name: String,
+        key_type: Option<KeyType>,
+        weight: Option<u64>,
+    },
+    /// Remove a signer from the own signer set of a token
+    RemoveTokenSigner {
+        ticker: String,
+        public_key_base64: String,
+    },
+    /// Set the threshold policy of the own signer set of a token
+    SetTokenSignerThreshold {
+        ticker: String,
+        signer_threshold: SignerThreshold,
+    },
+}
+
+impl AdminAction {
+    /// The name of the action as used in events
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AdminAction::UpdateConfig { .. } => "update_config",
+            AdminAction::AddSigner { .. } => "add_signer",
+            AdminAction::RemoveSigner { .. } => "remove_signer",
+            AdminAction::UpdateSignerWeight { .. } => "update_signer_weight",
+            AdminAction::ReactivateSigner { .. } => "reactivate_signer",
+            AdminAction::SetSignerBondOwner { .. } => "set_signer_bond_owner",
+            AdminAction::EnableToken { .. } => "enable_token",
+            AdminAction::UpdateTokenMetadata { .. } => "update_token_metadata",
+            AdminAction::SetAmountTiers { .. } => "set_amount_tiers",
+            AdminAction::AddTokenSigner { .. } => "add_token_signer",
+            AdminAction::RemoveTokenSigner { .. } => "remove_token_signer",
+            AdminAction::SetTokenSignerThreshold { .. } => "set_token_signer_threshold",
+        }
+    }
+}
+
+#[cw_serde]
+pub struct QueuedAdminAction {
+    /// The admin action applied once executed
+    pub action: AdminAction,
+    /// The time the action was queued
+    pub queued_at: Timestamp,
+    /// The earliest time the action can be executed
+    pub executable_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct QueryAdminActionsResponse {
+    /// The queued admin actions, the format is
+    /// (action ID, queued action)
+    pub actions: Vec<(u64, QueuedAdminAction)>,
+}
+
+#[cw_serde]
+pub struct PendingReceive {
+    /// The chain ID of the source chain
+    pub source_chain_id: String,
+    /// The ticker of the CFT-20 token
+    pub ticker: String,
+    /// The amount of CFT-20 tokens
+    pub amount: Uint128,
+    /// The destination address to transfer the CFT-20-equivalent to
+    pub destination_addr: String,
+    /// The signer set epoch the signatures were made for
+    pub signer_epoch: Option<u64>,
+    /// The block time after which the signatures are no longer accepted
+    pub attestation_expires_at: Option<Timestamp>,
+    /// The encoding of the signed attestation
+    pub attestation_version: AttestationVersion,
+    /// The reward fee rate attested along with the transfer
+    pub reward_fee_rate: Option<Decimal>,
+    /// The signatures collected so far, paired with the public key of their signer
+    pub signer_signatures: Vec<SignerSignature>,
+    /// The names of the signers that attested so far
+    pub signers: Vec<String>,
+    /// The signatures of the token's own signers collected so far
+    pub token_signatures: Vec<String>,
+    /// The public keys of the token's own signers that attested so far
+    pub token_signers: Vec<Binary>,
+    /// The time the first signature was submitted
+    pub created_at: Timestamp,
+    /// The time after which no more signatures are collected, never later
+    /// than the expiry of the attestation
+    pub expires_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct QueryFailedTokenLinksResponse {
+    /// The failed token links, ordered by ticker
+    pub links: Vec<FailedTokenLink>,
+}
+
+#[cw_serde]
+pub struct QueryPendingReceivesResponse {
+    /// The pending receives, the format is
+    /// (transaction hash, pending receive for each attested transfer)
+    pub receives: Vec<(String, Vec<PendingReceive>)>,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct SignerStats {
+    /// The amount of attestations the signer's signature was counted for
+    pub attestations: u64,
+    /// The height of the last block the signer's signature was counted in
+    pub last_seen_height: Option<u64>,
+    /// The time of the last block the signer's signature was counted in
+    pub last_seen_time: Option<Timestamp>,
+}
+
+/// The message kinds signers attest, with the fields the message is built from
+#[cw_serde]
+pub enum AttestationRequest {
+    /// The attestation of LinkToken
+    LinkToken {
+        /// The chain ID of the source chain
+        source_chain_id: String,
+        /// The metadata of the CFT-20 token
+        token: TokenMetadata,
+    },
+    /// The attestation of Receive and Attest
+    Receive {
+        /// The chain ID of the source chain
+        source_chain_id: String,
+        /// The hash of the transaction on the origin chain
+        transaction_hash: String,
+        /// The ticker of the CFT-20 token
+        ticker: String,
+        /// The amount of CFT-20 tokens
+        amount: Uint128,
+        /// The destination address to transfer the CFT-20-equivalent to
+        destination_addr: String,
+        /// The block time after which the signatures are no longer accepted
+        expires_at: Option<Timestamp>,
+    },
+}
+
+#[cw_serde]
+pub enum SignatureKind {
+    /// A signature matched against the keys of every signer
+    Signature,
+    /// A signature paired with the signer that made it
+    SignerSignature,
+    /// A signature over an ADR-036 sign-doc wrapping the attestation
+    Adr036Signature,
+}
+
+#[cw_serde]
+pub struct SignatureMatch {
+    /// The list the signature was submitted in
+    pub kind: SignatureKind,
+    /// The position of the signature in its list
+    pub index: u32,
+    /// The name of the signer that made the signature, none if it doesn't
+    /// verify against any accepted key
+    pub signer: Option<String>,
+}
+
+#[cw_serde]
+pub struct QueryVerifyAttestationResponse {
+    /// The exact bytes the signers must sign
+    pub message: Binary,
+    /// The signer every submitted signature was matched to
+    pub signatures: Vec<SignatureMatch>,
+    /// The total weight of valid signatures required
+    pub threshold: u64,
+    /// Whether the signatures would pass the verification
+    pub passed: bool,
+    /// The names of the signers that would be counted, if it passes
+    pub signers: Vec<String>,
+    /// The error the verification would fail with
+    pub error: Option<String>,
+}
+
+#[cw_serde]
+pub struct QueryPendingRewardsResponse {
+    /// The rewards the signer can claim, one coin per bridged token
+    pub rewards: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct QuerySignerStatsResponse {
+    /// The participation of the signers, the format is (signer name, stats)
+    pub stats: Vec<(String, SignerStats)>,
+}
+
 #[cw_serde]
 pub struct QuerySignersResponse {
     /// The signers currently loaded, the format is
-    /// (base64 public key, name)
-    pub signers: Vec<(String, String)>,
+    /// (base64 public key, signer info)
+    pub signers: Vec<(String, SignerInfo)>,
+    /// The total weight of valid signatures currently required
+    pub threshold: u64,
+    /// The total weight of the current active signers
+    pub total_weight: u64,
+    /// The epoch of the current signer set
+    pub epoch: u64,
+}
+
+#[cw_serde]
+pub struct QueryTokenSignersResponse {
+    /// The own signers of the token, the format is
+    /// (base64 public key, signer info)
+    pub signers: Vec<(String, SignerInfo)>,
+    /// The policy used to calculate the weight required from the token's signers
+    pub signer_threshold: SignerThreshold,
+    /// The total weight of valid token signatures currently required, 0 when
+    /// the token has no signers of its own
+    pub threshold: u64,
+    /// The total weight of the token's signers
+    pub total_weight: u64,
+}
+
+#[cw_serde]
+pub struct SignerSet {
+    /// The signers in this set, the format is
+    /// (public key, signer info)
+    pub signers: Vec<(Binary, SignerInfo)>,
+    /// The time this signer set was replaced by the next epoch
+    pub replaced_at: Timestamp,
 }
 
 #[cw_serde]
 pub struct QueryTokensResponse {
-    /// The list of token denoms allowed in bridging
+    /// The list of CFT-20 tickers
     pub tokens: Vec<String>,
 }
 
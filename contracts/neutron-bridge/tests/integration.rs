@@ -1,17 +1,31 @@
+use asteroid_neutron_bridge::attestation::{adr036_sign_doc, adr036_signer_address, Attestation};
 use asteroid_neutron_bridge::contract::instantiate;
 use asteroid_neutron_bridge::error::ContractError;
 use asteroid_neutron_bridge::execute::{execute, reply};
 use asteroid_neutron_bridge::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
 use asteroid_neutron_bridge::query::query;
 use asteroid_neutron_bridge::types::{
-    Config, QuerySignersResponse, QueryTokensResponse, TokenMetadata, MAX_IBC_TIMEOUT_SECONDS,
-    MIN_IBC_TIMEOUT_SECONDS,
+    AdminAction, Adr036Signature, AmountTier, AttestationRequest, AttestationVersion, KeyType,
+    PendingReceive, QueryAdminActionsResponse, QueryConfigResponse, QueryFailedTokenLinksResponse,
+    QueryPendingReceivesResponse, QueryPendingRewardsResponse, QuerySignerStatsResponse,
+    QuerySignersResponse, QueryTokenSignersResponse, QueryTokensResponse,
+    QueryVerifyAttestationResponse, SignatureKind, SignatureMatch, SignedReceive, SignerBond,
+    SignerChange, SignerChangeProposal, SignerReference, SignerSet, SignerSignature, SignerStats,
+    SignerStatus, SignerThreshold, SlashEvidence, TokenInfo, TokenLink, TokenLinkFailure,
+    TokenMetadata, TokenStatus, DEFAULT_PENDING_RECEIVE_MAX_AGE_SECONDS, FEE_DENOM,
+    MAX_ADMIN_ACTION_DELAY_SECONDS, MAX_IBC_TIMEOUT_SECONDS, MAX_PENDING_RECEIVE_MAX_AGE_SECONDS,
+    MAX_REWARD_FEE_RATE, MIN_IBC_TIMEOUT_SECONDS,
+};
+use base64::{engine::general_purpose, Engine as _};
+use cw_multi_test::{AppBuilder, Contract, ContractWrapper, Executor};
+use ed25519_dalek::{Signer, SigningKey};
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Coin, CosmosMsg, Decimal, Env, Timestamp, Uint128, WasmMsg,
 };
-use astroport_test::cw_multi_test::{AppBuilder, Contract, ContractWrapper, Executor};
-// use astroport_test::modules::stargate::{MockStargate, StargateApp};
-use cosmwasm_std::{Addr, Coin, Uint128};
 use neutron_sdk::bindings::msg::NeutronMsg;
 use neutron_sdk::bindings::query::NeutronQuery;
+use osmosis_std::types::osmosis::tokenfactory::v1beta1::{MsgChangeAdmin, MsgCreateDenom};
 use stargate::MockIbc;
 
 use crate::stargate::{MockStargate, StargateApp};
@@ -21,18 +35,6 @@ type NeutronApp = StargateApp<NeutronMsg, NeutronQuery>;
 const VALID_SIGNER_1: &str = "b577zulJVqWfXiip7ydZrvMgp2SzfR+IXhH7vkUjr+Y=";
 const VALID_SIGNER_2: &str = "vXRMhQtQNezXhdvYe1xlHYysGaEAJH2WwnV8Fvuuttw=";
 
-// Signatures for TESTTOKEN with 6 decimals
-const SIGNATURE_1: &str =
-    "OU5aYIcdVHNVFNcg+MLT9uYVfkNHjTN8Pzg7lHmni5AuCC0ln78lJQnCRi8XxaPaxQYrm3TY+2+LeOU6H9j0DQ==";
-const SIGNATURE_2: &str =
-    "r3pfcIod2/49HHTOC+QRcVuccg2nOqSZsCNulv+McYFsEOPX7TN3PFscdVfavaGmb3mqdM6vF5italUVrJH3DA==";
-
-// Signatures for bridging 1000 TESTTOKEN
-const BRIDGE_SIGNATURE_1: &str =
-    "ZwoqbZxvNaz06/0ZO+M7g0Ygf5YRKkWYNcm/yD+wYQ43N9/9i5xiSHxMhOo0wttNf5NP/T7Rrlv1Sp3K8qyiCw==";
-const BRIDGE_SIGNATURE_2: &str =
-    "+Y5UhcFimBzBnJX8BIFZPR2DjUp3DaYVRF81osV/qx8E4gDWk3z1EtUsLX3oITTld0lc12IQGdpuFcCWDAMVAQ==";
-
 mod stargate;
 
 fn mock_app(owner: &Addr, coins: Vec<Coin>) -> NeutronApp {
@@ -49,6 +51,75 @@ fn bridge_contract() -> Box<dyn Contract<NeutronMsg, NeutronQuery>> {
     Box::new(ContractWrapper::new(execute, instantiate, query).with_reply(reply))
 }
 
+/// Creates a deterministic signing key to produce signatures during tests
+fn signing_key(seed: u8) -> SigningKey {
+    SigningKey::from_bytes(&[seed; 32])
+}
+
+/// Returns the public key of the signing key in base64
+fn public_key_base64(key: &SigningKey) -> String {
+    general_purpose::STANDARD.encode(key.verifying_key().to_bytes())
+}
+
+/// Signs the attestation and returns the signature in base64
+fn sign_attestation(key: &SigningKey, attestation: impl AsRef<[u8]>) -> String {
+    general_purpose::STANDARD.encode(key.sign(attestation.as_ref()).to_bytes())
+}
+
+/// Signs the attestation linking TESTTOKEN with 6 decimals
+fn link_token_signature(env: &Env, key: &SigningKey) -> String {
+    let token = TokenMetadata {
+        ticker: "TESTTOKEN".to_string(),
+        name: "TestToken".to_string(),
+        image_url: "https://example.com".to_string(),
+        decimals: 6,
+    };
+    let attestation =
+        Attestation::link_token(env, "localgaia-1", &token).encode(AttestationVersion::V1);
+    sign_attestation(key, attestation)
+}
+
+/// Signs the attestation bridging 1000 TESTTOKEN to user1 in TXHASH1
+fn bridge_signature(env: &Env, key: &SigningKey) -> String {
+    let attestation = Attestation::receive(
+        env,
+        "localgaia-1",
+        "TXHASH1",
+        "TESTTOKEN",
+        Uint128::from(1000u64),
+        "user1",
+    )
+    .encode(AttestationVersion::V1);
+    sign_attestation(key, attestation)
+}
+
+/// Creates a deterministic secp256k1 signing key to produce signatures during tests
+fn secp256k1_signing_key(seed: u8) -> k256::ecdsa::SigningKey {
+    k256::ecdsa::SigningKey::from_bytes(&[seed; 32].into()).unwrap()
+}
+
+/// Returns the compressed SEC1 public key of the secp256k1 signing key in base64
+fn secp256k1_public_key_base64(key: &k256::ecdsa::SigningKey) -> String {
+    general_purpose::STANDARD.encode(key.verifying_key().to_encoded_point(true).as_bytes())
+}
+
+/// Signs the sha256 digest of the attestation and returns the signature in base64
+fn secp256k1_sign_attestation(
+    key: &k256::ecdsa::SigningKey,
+    attestation: impl AsRef<[u8]>,
+) -> String {
+    let signature: k256::ecdsa::Signature = key.sign(attestation.as_ref());
+    general_purpose::STANDARD.encode(signature.to_bytes())
+}
+
+/// Returns an environment matching the bridge contract in the test app
+fn contract_env(app: &NeutronApp, contract_address: &Addr) -> Env {
+    let mut env = mock_env();
+    env.block = app.block_info();
+    env.contract.address = contract_address.clone();
+    env
+}
+
 #[test]
 fn test_instantiate() {
     let owner = Addr::unchecked("owner");
@@ -79,14 +150,19 @@ fn test_instantiate() {
         .unwrap();
 
     // Query to check all the values were set
-    let response: Config = app
+    let response: QueryConfigResponse = app
         .wrap()
         .query_wasm_smart(bridge_address, &QueryMsg::Config {})
         .unwrap();
 
-    assert_eq!(response.bridge_chain_id, "localgaia-1");
-    assert_eq!(response.bridge_ibc_channel, "channel-0");
-    assert_eq!(response.ibc_timeout_seconds, 10);
+    assert_eq!(response.config.bridge_chain_id, "localgaia-1");
+    assert_eq!(response.config.bridge_ibc_channel, "channel-0");
+    assert_eq!(response.config.ibc_timeout_seconds, 10);
+    assert_eq!(
+        response.config.signer_threshold,
+        SignerThreshold::supermajority()
+    );
+    assert_eq!(response.effective_signer_threshold, 2);
 
     let err = app
         .instantiate_contract(
@@ -239,6 +315,9 @@ fn test_add_signer() {
             &ExecuteMsg::AddSigner {
                 name: "signer".to_string(),
                 public_key_base64: "invalid_key".to_string(),
+                key_type: None,
+                weight: None,
+                contact_url: None,
             },
             &[],
         )
@@ -258,6 +337,9 @@ fn test_add_signer() {
         &ExecuteMsg::AddSigner {
             name: "signer".to_string(),
             public_key_base64: VALID_SIGNER_1.to_string(),
+            key_type: None,
+            weight: None,
+            contact_url: None,
         },
         &[],
     )
@@ -271,6 +353,9 @@ fn test_add_signer() {
             &ExecuteMsg::AddSigner {
                 name: "signer".to_string(),
                 public_key_base64: VALID_SIGNER_2.to_string(),
+                key_type: None,
+                weight: None,
+                contact_url: None,
             },
             &[],
         )
@@ -291,6 +376,9 @@ fn test_add_signer() {
             &ExecuteMsg::AddSigner {
                 name: "duplicate-signer".to_string(),
                 public_key_base64: VALID_SIGNER_1.to_string(),
+                key_type: None,
+                weight: None,
+                contact_url: None,
             },
             &[],
         )
@@ -303,6 +391,29 @@ fn test_add_signer() {
         }
     );
 
+    // An ed25519 key is not a valid secp256k1 key
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::AddSigner {
+                name: "secp256k1-signer".to_string(),
+                public_key_base64: VALID_SIGNER_2.to_string(),
+                key_type: Some(KeyType::Secp256k1),
+                weight: None,
+                contact_url: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidConfiguration {
+            reason: "Invalid secp256k1 public key".to_string()
+        }
+    );
+
     // Attempt to add a signer without being the owner
     let err = app
         .execute_contract(
@@ -311,6 +422,9 @@ fn test_add_signer() {
             &ExecuteMsg::AddSigner {
                 name: "duplicate-signer".to_string(),
                 public_key_base64: VALID_SIGNER_1.to_string(),
+                key_type: None,
+                weight: None,
+                contact_url: None,
             },
             &[],
         )
@@ -324,7 +438,13 @@ fn test_add_signer() {
     // Query to check new signer was added
     let response: QuerySignersResponse = app
         .wrap()
-        .query_wasm_smart(&bridge_address, &QueryMsg::Signers {})
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Signers {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
 
     assert_eq!(response.signers.len(), 1);
@@ -360,6 +480,9 @@ fn test_remove_signer() {
         &ExecuteMsg::AddSigner {
             name: "signer".to_string(),
             public_key_base64: VALID_SIGNER_1.to_string(),
+            key_type: None,
+            weight: None,
+            contact_url: None,
         },
         &[],
     )
@@ -376,74 +499,6414 @@ fn test_remove_signer() {
             &[],
         )
         .unwrap_err();
-
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidConfiguration {
+            reason: "Key to remove doesn't exist".to_string()
+        }
+    );
+
+    // Remove an unknown signer
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::RemoveSigner {
+                public_key_base64: "invalid_key".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidConfiguration {
+            reason: "Key could not be decoded".to_string()
+        }
+    );
+
+    // Attempt to remove a signer without being the owner
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::RemoveSigner {
+                public_key_base64: "aW52YWxpZC1zaWduZXI=".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    );
+
+    // Remove a known signer
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::RemoveSigner {
+            public_key_base64: VALID_SIGNER_1.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure signer was removed
+    let response: QuerySignersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Signers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(response.signers.len(), 0);
+}
+
+#[test]
+fn test_update_config() {
+    let owner = Addr::unchecked("owner");
+    let not_owner = Addr::unchecked("not_owner");
+    let mut app = mock_app(&owner, vec![]);
+    let contract_code = app.store_code(bridge_contract());
+
+    let bridge_address = app
+        .instantiate_contract(
+            contract_code,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: owner.to_string(),
+                ibc_timeout_seconds: 10,
+                bridge_ibc_channel: "channel-0".to_string(),
+                bridge_chain_id: "localgaia-1".to_string(),
+            },
+            &[],
+            "Asteroid Bridge",
+            None,
+        )
+        .unwrap();
+
+    // Attempt to update config without being the owner
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::UpdateConfig {
+                bridge_ibc_channel: None,
+                ibc_timeout_seconds: None,
+                signer_threshold: None,
+                signer_epoch_grace_seconds: None,
+                v1_attestations_enabled: None,
+                admin_action_delay_seconds: None,
+                pending_receive_max_age_seconds: None,
+                min_signer_bond: None,
+                unbonding_period_seconds: None,
+                treasury: None,
+                reward_fee_rate: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    );
+
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::UpdateConfig {
+            bridge_ibc_channel: None,
+            ibc_timeout_seconds: None,
+            signer_threshold: None,
+            signer_epoch_grace_seconds: None,
+            v1_attestations_enabled: None,
+            admin_action_delay_seconds: None,
+            pending_receive_max_age_seconds: None,
+            min_signer_bond: None,
+            unbonding_period_seconds: None,
+            treasury: None,
+            reward_fee_rate: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::UpdateConfig {
+            bridge_ibc_channel: None,
+            ibc_timeout_seconds: None,
+            signer_threshold: None,
+            signer_epoch_grace_seconds: None,
+            v1_attestations_enabled: None,
+            admin_action_delay_seconds: None,
+            pending_receive_max_age_seconds: None,
+            min_signer_bond: None,
+            unbonding_period_seconds: None,
+            treasury: None,
+            reward_fee_rate: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Attempt blank ibc channel
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::UpdateConfig {
+                bridge_ibc_channel: Some("".to_string()),
+                ibc_timeout_seconds: None,
+                signer_threshold: None,
+                signer_epoch_grace_seconds: None,
+                v1_attestations_enabled: None,
+                admin_action_delay_seconds: None,
+                pending_receive_max_age_seconds: None,
+                min_signer_bond: None,
+                unbonding_period_seconds: None,
+                treasury: None,
+                reward_fee_rate: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidConfiguration {
+            reason: "The bridge IBC channel must be specified".to_string()
+        }
+    );
+
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::UpdateConfig {
+            bridge_ibc_channel: Some("channel-9".to_string()),
+            ibc_timeout_seconds: None,
+            signer_threshold: None,
+            signer_epoch_grace_seconds: None,
+            v1_attestations_enabled: None,
+            admin_action_delay_seconds: None,
+            pending_receive_max_age_seconds: None,
+            min_signer_bond: None,
+            unbonding_period_seconds: None,
+            treasury: None,
+            reward_fee_rate: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Attempt invalid IBC timeout
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::UpdateConfig {
+                bridge_ibc_channel: None,
+                ibc_timeout_seconds: Some(MIN_IBC_TIMEOUT_SECONDS - 1),
+                signer_threshold: None,
+                signer_epoch_grace_seconds: None,
+                v1_attestations_enabled: None,
+                admin_action_delay_seconds: None,
+                pending_receive_max_age_seconds: None,
+                min_signer_bond: None,
+                unbonding_period_seconds: None,
+                treasury: None,
+                reward_fee_rate: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidIBCTimeout {
+            timeout: MIN_IBC_TIMEOUT_SECONDS - 1,
+            min: MIN_IBC_TIMEOUT_SECONDS,
+            max: MAX_IBC_TIMEOUT_SECONDS,
+        }
+    );
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::UpdateConfig {
+                bridge_ibc_channel: None,
+                ibc_timeout_seconds: Some(MAX_IBC_TIMEOUT_SECONDS + 1),
+                signer_threshold: None,
+                signer_epoch_grace_seconds: None,
+                v1_attestations_enabled: None,
+                admin_action_delay_seconds: None,
+                pending_receive_max_age_seconds: None,
+                min_signer_bond: None,
+                unbonding_period_seconds: None,
+                treasury: None,
+                reward_fee_rate: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidIBCTimeout {
+            timeout: MAX_IBC_TIMEOUT_SECONDS + 1,
+            min: MIN_IBC_TIMEOUT_SECONDS,
+            max: MAX_IBC_TIMEOUT_SECONDS,
+        }
+    );
+
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::UpdateConfig {
+            bridge_ibc_channel: None,
+            ibc_timeout_seconds: Some(MIN_IBC_TIMEOUT_SECONDS + 1),
+            signer_threshold: None,
+            signer_epoch_grace_seconds: None,
+            v1_attestations_enabled: None,
+            admin_action_delay_seconds: None,
+            pending_receive_max_age_seconds: None,
+            min_signer_bond: None,
+            unbonding_period_seconds: None,
+            treasury: None,
+            reward_fee_rate: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Query to check all the new values were set
+    let response: QueryConfigResponse = app
+        .wrap()
+        .query_wasm_smart(&bridge_address, &QueryMsg::Config {})
+        .unwrap();
+
+    assert_eq!(response.config.bridge_chain_id, "localgaia-1");
+    assert_eq!(response.config.bridge_ibc_channel, "channel-9");
+    assert_eq!(
+        response.config.ibc_timeout_seconds,
+        MIN_IBC_TIMEOUT_SECONDS + 1
+    );
+}
+
+#[test]
+fn test_update_signer_threshold() {
+    let owner = Addr::unchecked("owner");
+    let mut app = mock_app(&owner, vec![]);
+    let contract_code = app.store_code(bridge_contract());
+
+    let bridge_address = app
+        .instantiate_contract(
+            contract_code,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: owner.to_string(),
+                ibc_timeout_seconds: 10,
+                bridge_ibc_channel: "channel-0".to_string(),
+                bridge_chain_id: "localgaia-1".to_string(),
+            },
+            &[],
+            "Asteroid Bridge",
+            None,
+        )
+        .unwrap();
+
+    // Add two valid signers
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::AddSigner {
+            name: "signer1".to_string(),
+            public_key_base64: VALID_SIGNER_1.to_string(),
+            key_type: None,
+            weight: None,
+            contact_url: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::AddSigner {
+            name: "signer2".to_string(),
+            public_key_base64: VALID_SIGNER_2.to_string(),
+            key_type: None,
+            weight: None,
+            contact_url: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // A fixed threshold below the minimum is not allowed
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::UpdateConfig {
+                bridge_ibc_channel: None,
+                ibc_timeout_seconds: None,
+                signer_threshold: Some(SignerThreshold::Fixed { weight: 1 }),
+                signer_epoch_grace_seconds: None,
+                v1_attestations_enabled: None,
+                admin_action_delay_seconds: None,
+                pending_receive_max_age_seconds: None,
+                min_signer_bond: None,
+                unbonding_period_seconds: None,
+                treasury: None,
+                reward_fee_rate: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidConfiguration {
+            reason: "The signer threshold can't be less than 2".to_string()
+        }
+    );
+
+    // A threshold that requires more signatures than signers loaded is not allowed
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::UpdateConfig {
+                bridge_ibc_channel: None,
+                ibc_timeout_seconds: None,
+                signer_threshold: Some(SignerThreshold::Fixed { weight: 3 }),
+                signer_epoch_grace_seconds: None,
+                v1_attestations_enabled: None,
+                admin_action_delay_seconds: None,
+                pending_receive_max_age_seconds: None,
+                min_signer_bond: None,
+                unbonding_period_seconds: None,
+                treasury: None,
+                reward_fee_rate: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidConfiguration {
+            reason: "The signer threshold can't be met by the current signers".to_string()
+        }
+    );
+
+    // Percentages above 100% are not allowed
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::UpdateConfig {
+                bridge_ibc_channel: None,
+                ibc_timeout_seconds: None,
+                signer_threshold: Some(SignerThreshold::Percentage {
+                    percentage: Decimal::percent(150),
+                }),
+                signer_epoch_grace_seconds: None,
+                v1_attestations_enabled: None,
+                admin_action_delay_seconds: None,
+                pending_receive_max_age_seconds: None,
+                min_signer_bond: None,
+                unbonding_period_seconds: None,
+                treasury: None,
+                reward_fee_rate: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidConfiguration {
+            reason: "The signer threshold percentage must be between 0 and 1".to_string()
+        }
+    );
+
+    // Require all signers
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::UpdateConfig {
+            bridge_ibc_channel: None,
+            ibc_timeout_seconds: None,
+            signer_threshold: Some(SignerThreshold::Percentage {
+                percentage: Decimal::percent(100),
+            }),
+            signer_epoch_grace_seconds: None,
+            v1_attestations_enabled: None,
+            admin_action_delay_seconds: None,
+            pending_receive_max_age_seconds: None,
+            min_signer_bond: None,
+            unbonding_period_seconds: None,
+            treasury: None,
+            reward_fee_rate: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Query to check the effective threshold is reported
+    let response: QueryConfigResponse = app
+        .wrap()
+        .query_wasm_smart(&bridge_address, &QueryMsg::Config {})
+        .unwrap();
+
+    assert_eq!(
+        response.config.signer_threshold,
+        SignerThreshold::Percentage {
+            percentage: Decimal::percent(100)
+        }
+    );
+    assert_eq!(response.effective_signer_threshold, 2);
+
+    let response: QuerySignersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Signers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(response.signers.len(), 2);
+    assert_eq!(response.threshold, 2);
+}
+
+#[test]
+fn test_signer_epochs() {
+    let owner = Addr::unchecked("owner");
+    let not_owner = Addr::unchecked("not_owner");
+    let mut app = mock_app(&owner, vec![]);
+    let contract_code = app.store_code(bridge_contract());
+
+    let bridge_address = app
+        .instantiate_contract(
+            contract_code,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: owner.to_string(),
+                ibc_timeout_seconds: 10,
+                bridge_ibc_channel: "channel-0".to_string(),
+                bridge_chain_id: "localgaia-1".to_string(),
+            },
+            &[],
+            "Asteroid Bridge",
+            None,
+        )
+        .unwrap();
+
+    let signers = [
+        signing_key(1),
+        signing_key(2),
+        signing_key(3),
+        signing_key(4),
+    ];
+    let chain_id = app.block_info().chain_id;
+
+    // Add the first two signers, every change bumps the signer epoch
+    for (index, signer) in signers.iter().take(2).enumerate() {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::AddSigner {
+                name: format!("signer{}", index + 1),
+                public_key_base64: public_key_base64(signer),
+                key_type: None,
+                weight: None,
+                contact_url: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let response: QuerySignersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Signers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(response.epoch, 2);
+
+    // Link the token with signatures bound to the current epoch
+    let attestation = format!("localgaia-1TESTTOKEN6{}{}2", chain_id, bridge_address);
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::LinkToken {
+            source_chain_id: "localgaia-1".to_string(),
+            token: TokenMetadata {
+                ticker: "TESTTOKEN".to_string(),
+                name: "TestToken".to_string(),
+                image_url: "https://example.com".to_string(),
+                decimals: 6,
+            },
+            signatures: vec![
+                sign_attestation(&signers[0], &attestation),
+                sign_attestation(&signers[1], &attestation),
+            ],
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: Some(2),
+            attestation_version: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The signer set for the second epoch signs two transfers
+    let receive_attestation = |transaction_hash: &str, epoch: u64| {
+        format!(
+            "localgaia-1{}TESTTOKEN1000{}{}user1{}",
+            transaction_hash, chain_id, bridge_address, epoch
+        )
+    };
+    let receive_msg = |transaction_hash: &str, epoch: u64, signed_by: &[&SigningKey]| {
+        let attestation = receive_attestation(transaction_hash, epoch);
+        ExecuteMsg::Receive {
+            source_chain_id: "localgaia-1".to_string(),
+            transaction_hash: transaction_hash.to_string(),
+            ticker: "TESTTOKEN".to_string(),
+            amount: Uint128::from(1000u64),
+            destination_addr: "user1".to_string(),
+            signatures: signed_by
+                .iter()
+                .map(|signer| sign_attestation(signer, &attestation))
+                .collect(),
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: Some(epoch),
+            attestation_version: None,
+            token_signatures: None,
+            expires_at: None,
+        }
+    };
+
+    // Signatures for an epoch that doesn't exist yet are rejected
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &receive_msg("TXHASH1", 3, &[&signers[0], &signers[1]]),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidSignerEpoch {
+            epoch: 3,
+            current: 2
+        }
+    );
+
+    // Rotate the signer set twice by adding a third and a fourth signer
+    for (index, signer) in signers.iter().enumerate().skip(2) {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::AddSigner {
+                name: format!("signer{}", index + 1),
+                public_key_base64: public_key_base64(signer),
+                key_type: None,
+                weight: None,
+                contact_url: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // The replaced signer sets are kept in the history
+    let response: SignerSet = app
+        .wrap()
+        .query_wasm_smart(&bridge_address, &QueryMsg::SignerSet { epoch: 2 })
+        .unwrap();
+    assert_eq!(response.signers.len(), 2);
+
+    // Transfers signed by an earlier set are accepted within its grace period,
+    // even once it's no longer the set right before the current one
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &receive_msg("TXHASH1", 2, &[&signers[0], &signers[1]]),
+        &[],
+    )
+    .unwrap();
+
+    // Once the grace period is over, the earlier set is no longer accepted
+    app.update_block(|block| {
+        block.time = block.time.plus_seconds(60 * 60 + 1);
+    });
+
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &receive_msg("TXHASH2", 2, &[&signers[0], &signers[1]]),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidSignerEpoch {
+            epoch: 2,
+            current: 4
+        }
+    );
+
+    // Neither are the sets replaced before it
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &receive_msg("TXHASH2", 1, &[&signers[0], &signers[1]]),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidSignerEpoch {
+            epoch: 1,
+            current: 4
+        }
+    );
+
+    // The current signer set is still accepted
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &receive_msg("TXHASH2", 4, &[&signers[1], &signers[2], &signers[3]]),
+        &[],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_all_balances("user1").unwrap();
+    assert_eq!(res, vec![Coin::new(2000, "factory/contract0/TESTTOKEN")]);
+
+    // Suspending or removing a signer also drops it from the earlier sets
+    // still within their grace period
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::UpdateSignerStatus {
+            public_key_base64: public_key_base64(&signers[0]),
+            status: SignerStatus::Suspended,
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::RemoveSigner {
+            public_key_base64: public_key_base64(&signers[1]),
+        },
+        &[],
+    )
+    .unwrap();
+
+    for signed_by in [[&signers[0], &signers[2]], [&signers[1], &signers[2]]] {
+        let err = app
+            .execute_contract(
+                not_owner.clone(),
+                bridge_address.clone(),
+                &receive_msg("TXHASH3", 4, &signed_by),
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast::<ContractError>().unwrap(),
+            ContractError::ThresholdNotMet {}
+        );
+    }
+
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &receive_msg("TXHASH3", 4, &[&signers[2], &signers[3]]),
+        &[],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_attestation_versions() {
+    let owner = Addr::unchecked("owner");
+    let not_owner = Addr::unchecked("not_owner");
+    let mut app = mock_app(&owner, vec![]);
+    let contract_code = app.store_code(bridge_contract());
+
+    let bridge_address = app
+        .instantiate_contract(
+            contract_code,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: owner.to_string(),
+                ibc_timeout_seconds: 10,
+                bridge_ibc_channel: "channel-0".to_string(),
+                bridge_chain_id: "localgaia-1".to_string(),
+            },
+            &[],
+            "Asteroid Bridge",
+            None,
+        )
+        .unwrap();
+
+    let signers = [signing_key(1), signing_key(2)];
+    for (index, signer) in signers.iter().enumerate() {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::AddSigner {
+                name: format!("signer{}", index + 1),
+                public_key_base64: public_key_base64(signer),
+                key_type: None,
+                weight: None,
+                contact_url: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let env = contract_env(&app, &bridge_address);
+    let token = TokenMetadata {
+        ticker: "TESTTOKEN".to_string(),
+        name: "TestToken".to_string(),
+        image_url: "https://example.com".to_string(),
+        decimals: 6,
+    };
+
+    // Link the token with v2 signatures
+    let attestation =
+        Attestation::link_token(&env, "localgaia-1", &token).encode(AttestationVersion::V2);
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::LinkToken {
+            source_chain_id: "localgaia-1".to_string(),
+            token,
+            signatures: signers
+                .iter()
+                .map(|signer| sign_attestation(signer, &attestation))
+                .collect(),
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: Some(AttestationVersion::V2),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let receive_msg = |transaction_hash: &str, version: AttestationVersion| {
+        let attestation = Attestation::receive(
+            &env,
+            "localgaia-1",
+            transaction_hash,
+            "TESTTOKEN",
+            Uint128::from(1000u64),
+            "user1",
+        )
+        .encode(version);
+        ExecuteMsg::Receive {
+            source_chain_id: "localgaia-1".to_string(),
+            transaction_hash: transaction_hash.to_string(),
+            ticker: "TESTTOKEN".to_string(),
+            amount: Uint128::from(1000u64),
+            destination_addr: "user1".to_string(),
+            signatures: signers
+                .iter()
+                .map(|signer| sign_attestation(signer, &attestation))
+                .collect(),
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: Some(version),
+            token_signatures: None,
+            expires_at: None,
+        }
+    };
+
+    // v2 signatures don't verify when submitted as v1
+    let mut msg = receive_msg("TXHASH1", AttestationVersion::V2);
+    if let ExecuteMsg::Receive {
+        attestation_version,
+        ..
+    } = &mut msg
+    {
+        *attestation_version = Some(AttestationVersion::V1);
+    }
+    let err = app
+        .execute_contract(not_owner.clone(), bridge_address.clone(), &msg, &[])
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::ThresholdNotMet {}
+    );
+
+    // Both versions are accepted during the migration window
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &receive_msg("TXHASH1", AttestationVersion::V1),
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &receive_msg("TXHASH2", AttestationVersion::V2),
+        &[],
+    )
+    .unwrap();
+
+    // Only the owner can stop accepting v1 attestations
+    let update_msg = ExecuteMsg::UpdateConfig {
+        bridge_ibc_channel: None,
+        ibc_timeout_seconds: None,
+        signer_threshold: None,
+        signer_epoch_grace_seconds: None,
+        v1_attestations_enabled: Some(false),
+        admin_action_delay_seconds: None,
+        pending_receive_max_age_seconds: None,
+        min_signer_bond: None,
+        unbonding_period_seconds: None,
+        treasury: None,
+        reward_fee_rate: None,
+    };
+    let err = app
+        .execute_contract(not_owner.clone(), bridge_address.clone(), &update_msg, &[])
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    );
+
+    app.execute_contract(owner.clone(), bridge_address.clone(), &update_msg, &[])
+        .unwrap();
+
+    let response: QueryConfigResponse = app
+        .wrap()
+        .query_wasm_smart(&bridge_address, &QueryMsg::Config {})
+        .unwrap();
+    assert!(!response.config.v1_attestations_enabled);
+
+    // v1 attestations are now rejected, v2 still works
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &receive_msg("TXHASH3", AttestationVersion::V1),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::AttestationVersionDisabled {}
+    );
+
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &receive_msg("TXHASH3", AttestationVersion::V2),
+        &[],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_all_balances("user1").unwrap();
+    assert_eq!(res, vec![Coin::new(3000, "factory/contract0/TESTTOKEN")]);
+}
+
+#[test]
+fn test_attestation_expiry() {
+    let owner = Addr::unchecked("owner");
+    let not_owner = Addr::unchecked("not_owner");
+    let mut app = mock_app(&owner, vec![]);
+    let contract_code = app.store_code(bridge_contract());
+
+    let bridge_address = app
+        .instantiate_contract(
+            contract_code,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: owner.to_string(),
+                ibc_timeout_seconds: 10,
+                bridge_ibc_channel: "channel-0".to_string(),
+                bridge_chain_id: "localgaia-1".to_string(),
+            },
+            &[],
+            "Asteroid Bridge",
+            None,
+        )
+        .unwrap();
+
+    let signers = [signing_key(1), signing_key(2), signing_key(3)];
+    for (index, signer) in signers.iter().enumerate() {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::AddSigner {
+                name: format!("signer{}", index + 1),
+                public_key_base64: public_key_base64(signer),
+                key_type: None,
+                weight: None,
+                contact_url: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let env = contract_env(&app, &bridge_address);
+    let token = TokenMetadata {
+        ticker: "TESTTOKEN".to_string(),
+        name: "TestToken".to_string(),
+        image_url: "https://example.com".to_string(),
+        decimals: 6,
+    };
+    let attestation =
+        Attestation::link_token(&env, "localgaia-1", &token).encode(AttestationVersion::V1);
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::LinkToken {
+            source_chain_id: "localgaia-1".to_string(),
+            token,
+            signatures: vec![
+                sign_attestation(&signers[0], &attestation),
+                sign_attestation(&signers[1], &attestation),
+            ],
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let receive_attestation = |transaction_hash: &str, expires_at: Timestamp| {
+        Attestation::receive(
+            &env,
+            "localgaia-1",
+            transaction_hash,
+            "TESTTOKEN",
+            Uint128::from(1000u64),
+            "user1",
+        )
+        .with_optional("expires_at", Some(expires_at.seconds()))
+        .encode(AttestationVersion::V2)
+    };
+    let receive_msg = |transaction_hash: &str, signed_expires_at, expires_at| {
+        let attestation = receive_attestation(transaction_hash, signed_expires_at);
+        ExecuteMsg::Receive {
+            source_chain_id: "localgaia-1".to_string(),
+            transaction_hash: transaction_hash.to_string(),
+            ticker: "TESTTOKEN".to_string(),
+            amount: Uint128::from(1000u64),
+            destination_addr: "user1".to_string(),
+            signatures: vec![
+                sign_attestation(&signers[0], &attestation),
+                sign_attestation(&signers[1], &attestation),
+            ],
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: Some(AttestationVersion::V2),
+            token_signatures: None,
+            expires_at,
+        }
+    };
+    let expires_at = app.block_info().time.plus_seconds(60);
+
+    // In v1 the signer epoch and the expiry are concatenated, so the digits of
+    // an epoch-bound attestation can be re-split into a later expiry
+    let v1_attestation = |signer_epoch: Option<u64>, expires_at: Timestamp| {
+        Attestation::receive(
+            &env,
+            "localgaia-1",
+            "TXHASH1",
+            "TESTTOKEN",
+            Uint128::from(1000u64),
+            "user1",
+        )
+        .with_optional("signer_epoch", signer_epoch)
+        .with_optional("expires_at", Some(expires_at.seconds()))
+        .encode(AttestationVersion::V1)
+    };
+    let resplit_expires_at =
+        Timestamp::from_seconds(format!("3{}", expires_at.seconds()).parse().unwrap());
+    let attestation = v1_attestation(Some(3), expires_at);
+    assert_eq!(attestation, v1_attestation(None, resplit_expires_at));
+
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::Receive {
+                source_chain_id: "localgaia-1".to_string(),
+                transaction_hash: "TXHASH1".to_string(),
+                ticker: "TESTTOKEN".to_string(),
+                amount: Uint128::from(1000u64),
+                destination_addr: "user1".to_string(),
+                signatures: vec![
+                    sign_attestation(&signers[0], &attestation),
+                    sign_attestation(&signers[1], &attestation),
+                ],
+                signer_signatures: None,
+                adr036_signatures: None,
+                signer_epoch: None,
+                attestation_version: Some(AttestationVersion::V1),
+                token_signatures: None,
+                expires_at: Some(resplit_expires_at),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::AttestationExpiryRequiresV2 {}
+    );
+
+    // An expiry is never accepted in v1, even when the fields are split as signed
+    let attestation = v1_attestation(None, expires_at);
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::Attest {
+                source_chain_id: "localgaia-1".to_string(),
+                transaction_hash: "TXHASH1".to_string(),
+                ticker: "TESTTOKEN".to_string(),
+                amount: Uint128::from(1000u64),
+                destination_addr: "user1".to_string(),
+                signer_signatures: vec![SignerSignature {
+                    signer: SignerReference::PublicKey {
+                        public_key_base64: public_key_base64(&signers[0]),
+                    },
+                    signature: sign_attestation(&signers[0], &attestation),
+                }],
+                token_signer_signatures: vec![],
+                signer_epoch: None,
+                attestation_version: Some(AttestationVersion::V1),
+                expires_at: Some(expires_at),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::AttestationExpiryRequiresV2 {}
+    );
+
+    // The expiry is part of the signed attestation, it can't be dropped or extended
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &receive_msg("TXHASH1", expires_at, None),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::ThresholdNotMet {}
+    );
+
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &receive_msg("TXHASH1", expires_at, Some(expires_at.plus_seconds(60))),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::ThresholdNotMet {}
+    );
+
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &receive_msg("TXHASH1", expires_at, Some(expires_at)),
+        &[],
+    )
+    .unwrap();
+
+    // Signatures collected through Attest aren't kept past the expiry
+    let attestation = receive_attestation("TXHASH2", expires_at);
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::Attest {
+            source_chain_id: "localgaia-1".to_string(),
+            transaction_hash: "TXHASH2".to_string(),
+            ticker: "TESTTOKEN".to_string(),
+            amount: Uint128::from(1000u64),
+            destination_addr: "user1".to_string(),
+            signer_signatures: vec![SignerSignature {
+                signer: SignerReference::PublicKey {
+                    public_key_base64: public_key_base64(&signers[0]),
+                },
+                signature: sign_attestation(&signers[0], &attestation),
+            }],
+            token_signer_signatures: vec![],
+            signer_epoch: None,
+            attestation_version: Some(AttestationVersion::V2),
+            expires_at: Some(expires_at),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let pending: Vec<PendingReceive> = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::PendingReceive {
+                transaction_hash: "TXHASH2".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(pending[0].attestation_expires_at, Some(expires_at));
+    assert_eq!(pending[0].expires_at, expires_at);
+
+    // Once the block time reaches the expiry, the signatures are rejected
+    app.update_block(|block| {
+        block.time = block.time.plus_seconds(60);
+    });
+
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &receive_msg("TXHASH2", expires_at, Some(expires_at)),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::AttestationExpired { expires_at }
+    );
+
+    let res = app.wrap().query_all_balances("user1").unwrap();
+    assert_eq!(res, vec![Coin::new(1000, "factory/contract0/TESTTOKEN")]);
+}
+
+#[test]
+fn test_mixed_key_types() {
+    let owner = Addr::unchecked("owner");
+    let not_owner = Addr::unchecked("not_owner");
+    let mut app = mock_app(&owner, vec![]);
+    let contract_code = app.store_code(bridge_contract());
+
+    let bridge_address = app
+        .instantiate_contract(
+            contract_code,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: owner.to_string(),
+                ibc_timeout_seconds: 10,
+                bridge_ibc_channel: "channel-0".to_string(),
+                bridge_chain_id: "localgaia-1".to_string(),
+            },
+            &[],
+            "Asteroid Bridge",
+            None,
+        )
+        .unwrap();
+
+    let ed25519_signer = signing_key(1);
+    let secp256k1_signer = secp256k1_signing_key(2);
+
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::AddSigner {
+            name: "ed25519-signer".to_string(),
+            public_key_base64: public_key_base64(&ed25519_signer),
+            key_type: None,
+            weight: None,
+            contact_url: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::AddSigner {
+            name: "secp256k1-signer".to_string(),
+            public_key_base64: secp256k1_public_key_base64(&secp256k1_signer),
+            key_type: Some(KeyType::Secp256k1),
+            weight: None,
+            contact_url: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The signers are listed with their key types
+    let response: QuerySignersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Signers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let mut key_types = response
+        .signers
+        .iter()
+        .map(|(_, signer)| (signer.name.as_str(), signer.key_type))
+        .collect::<Vec<_>>();
+    key_types.sort_by_key(|(name, _)| *name);
+    assert_eq!(
+        key_types,
+        vec![
+            ("ed25519-signer", KeyType::Ed25519),
+            ("secp256k1-signer", KeyType::Secp256k1)
+        ]
+    );
+
+    // Both signatures count toward the same threshold
+    let env = contract_env(&app, &bridge_address);
+    let token = TokenMetadata {
+        ticker: "TESTTOKEN".to_string(),
+        name: "TestToken".to_string(),
+        image_url: "https://example.com".to_string(),
+        decimals: 6,
+    };
+    let attestation =
+        Attestation::link_token(&env, "localgaia-1", &token).encode(AttestationVersion::V2);
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::LinkToken {
+            source_chain_id: "localgaia-1".to_string(),
+            token,
+            signatures: vec![
+                sign_attestation(&ed25519_signer, &attestation),
+                secp256k1_sign_attestation(&secp256k1_signer, &attestation),
+            ],
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: Some(AttestationVersion::V2),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // secp256k1 signers can also sign v1 attestations
+    let attestation = Attestation::receive(
+        &env,
+        "localgaia-1",
+        "TXHASH1",
+        "TESTTOKEN",
+        Uint128::from(1000u64),
+        "user1",
+    )
+    .encode(AttestationVersion::V1);
+    let receive_msg = |signatures: Vec<String>| ExecuteMsg::Receive {
+        source_chain_id: "localgaia-1".to_string(),
+        transaction_hash: "TXHASH1".to_string(),
+        ticker: "TESTTOKEN".to_string(),
+        amount: Uint128::from(1000u64),
+        destination_addr: "user1".to_string(),
+        signatures,
+        signer_signatures: None,
+        adr036_signatures: None,
+        signer_epoch: None,
+        attestation_version: None,
+        token_signatures: None,
+        expires_at: None,
+    };
+
+    // A secp256k1 signature over another encoding of the attestation is not valid
+    let v2_attestation = Attestation::receive(
+        &env,
+        "localgaia-1",
+        "TXHASH1",
+        "TESTTOKEN",
+        Uint128::from(1000u64),
+        "user1",
+    )
+    .encode(AttestationVersion::V2);
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &receive_msg(vec![
+                sign_attestation(&ed25519_signer, &attestation),
+                secp256k1_sign_attestation(&secp256k1_signer, &v2_attestation),
+            ]),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::ThresholdNotMet {}
+    );
+
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &receive_msg(vec![
+            sign_attestation(&ed25519_signer, &attestation),
+            secp256k1_sign_attestation(&secp256k1_signer, &attestation),
+        ]),
+        &[],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_all_balances("user1").unwrap();
+    assert_eq!(res, vec![Coin::new(1000, "factory/contract0/TESTTOKEN")]);
+}
+
+#[test]
+fn test_adr036_signatures() {
+    let owner = Addr::unchecked("owner");
+    let not_owner = Addr::unchecked("not_owner");
+    let mut app = mock_app(&owner, vec![]);
+    let contract_code = app.store_code(bridge_contract());
+
+    let bridge_address = app
+        .instantiate_contract(
+            contract_code,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: owner.to_string(),
+                ibc_timeout_seconds: 10,
+                bridge_ibc_channel: "channel-0".to_string(),
+                bridge_chain_id: "localgaia-1".to_string(),
+            },
+            &[],
+            "Asteroid Bridge",
+            None,
+        )
+        .unwrap();
+
+    let ed25519_signer = signing_key(1);
+    let secp256k1_signer = secp256k1_signing_key(2);
+    let unknown_signer = secp256k1_signing_key(3);
+
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::AddSigner {
+            name: "ed25519-signer".to_string(),
+            public_key_base64: public_key_base64(&ed25519_signer),
+            key_type: None,
+            weight: None,
+            contact_url: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::AddSigner {
+            name: "secp256k1-signer".to_string(),
+            public_key_base64: secp256k1_public_key_base64(&secp256k1_signer),
+            key_type: Some(KeyType::Secp256k1),
+            weight: None,
+            contact_url: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Signs the attestation the way an off-chain wallet does, with the
+    // account of the key or with any other given account
+    let adr036_sign_as =
+        |key: &k256::ecdsa::SigningKey, signer_address: &str, attestation: &[u8]| Adr036Signature {
+            public_key_base64: secp256k1_public_key_base64(key),
+            signature: secp256k1_sign_attestation(
+                key,
+                adr036_sign_doc(signer_address, attestation).unwrap(),
+            ),
+        };
+    let adr036_sign = |key: &k256::ecdsa::SigningKey, attestation: &[u8]| {
+        let signer_address =
+            adr036_signer_address(key.verifying_key().to_encoded_point(true).as_bytes()).unwrap();
+        adr036_sign_as(key, &signer_address, attestation)
+    };
+
+    let env = contract_env(&app, &bridge_address);
+    let token = TokenMetadata {
+        ticker: "TESTTOKEN".to_string(),
+        name: "TestToken".to_string(),
+        image_url: "https://example.com".to_string(),
+        decimals: 6,
+    };
+    let attestation =
+        Attestation::link_token(&env, "localgaia-1", &token).encode(AttestationVersion::V2);
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::LinkToken {
+            source_chain_id: "localgaia-1".to_string(),
+            token,
+            signatures: vec![sign_attestation(&ed25519_signer, &attestation)],
+            signer_signatures: None,
+            adr036_signatures: Some(vec![adr036_sign(&secp256k1_signer, &attestation)]),
+            signer_epoch: None,
+            attestation_version: Some(AttestationVersion::V2),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let attestation = Attestation::receive(
+        &env,
+        "localgaia-1",
+        "TXHASH1",
+        "TESTTOKEN",
+        Uint128::from(1000u64),
+        "user1",
+    )
+    .encode(AttestationVersion::V1);
+    let receive_msg =
+        |signatures: Vec<String>, adr036_signatures: Vec<Adr036Signature>| ExecuteMsg::Receive {
+            source_chain_id: "localgaia-1".to_string(),
+            transaction_hash: "TXHASH1".to_string(),
+            ticker: "TESTTOKEN".to_string(),
+            amount: Uint128::from(1000u64),
+            destination_addr: "user1".to_string(),
+            signatures,
+            signer_signatures: None,
+            adr036_signatures: Some(adr036_signatures),
+            signer_epoch: None,
+            attestation_version: None,
+            token_signatures: None,
+            expires_at: None,
+        };
+
+    // ADR-036 signatures from keys outside of the signer set are rejected
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &receive_msg(
+                vec![sign_attestation(&ed25519_signer, &attestation)],
+                vec![adr036_sign(&unknown_signer, &attestation)],
+            ),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::UnknownSigner {
+            signer: secp256k1_public_key_base64(&unknown_signer),
+        }
+    );
+
+    // A key signing both the raw attestation and the sign-doc is only counted once
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &receive_msg(
+                vec![secp256k1_sign_attestation(&secp256k1_signer, &attestation)],
+                vec![adr036_sign(&secp256k1_signer, &attestation)],
+            ),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::ThresholdNotMet {}
+    );
+
+    // The sign-doc is rebuilt with the address of the signer's key, a sign-doc
+    // signed for another account isn't counted
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &receive_msg(
+                vec![sign_attestation(&ed25519_signer, &attestation)],
+                vec![adr036_sign_as(
+                    &secp256k1_signer,
+                    "cosmos1operator",
+                    &attestation,
+                )],
+            ),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::ThresholdNotMet {}
+    );
+
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &receive_msg(
+            vec![sign_attestation(&ed25519_signer, &attestation)],
+            vec![adr036_sign(&secp256k1_signer, &attestation)],
+        ),
+        &[],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_all_balances("user1").unwrap();
+    assert_eq!(res, vec![Coin::new(1000, "factory/contract0/TESTTOKEN")]);
+}
+
+#[test]
+fn test_signer_signatures() {
+    let owner = Addr::unchecked("owner");
+    let not_owner = Addr::unchecked("not_owner");
+    let mut app = mock_app(&owner, vec![]);
+    let contract_code = app.store_code(bridge_contract());
+
+    let bridge_address = app
+        .instantiate_contract(
+            contract_code,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: owner.to_string(),
+                ibc_timeout_seconds: 10,
+                bridge_ibc_channel: "channel-0".to_string(),
+                bridge_chain_id: "localgaia-1".to_string(),
+            },
+            &[],
+            "Asteroid Bridge",
+            None,
+        )
+        .unwrap();
+
+    let signers = [signing_key(1), signing_key(2), signing_key(3)];
+    for (index, signer) in signers.iter().enumerate() {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::AddSigner {
+                name: format!("signer{}", index + 1),
+                public_key_base64: public_key_base64(signer),
+                key_type: None,
+                weight: None,
+                contact_url: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // Signers can be referenced by their position in the signers query
+    let response: QuerySignersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Signers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let signer_index = |key: &SigningKey| {
+        response
+            .signers
+            .iter()
+            .position(|(public_key, _)| public_key == &public_key_base64(key))
+            .unwrap() as u32
+    };
+
+    let by_key = |key: &SigningKey, attestation: &[u8]| SignerSignature {
+        signer: SignerReference::PublicKey {
+            public_key_base64: public_key_base64(key),
+        },
+        signature: sign_attestation(key, attestation),
+    };
+    let by_index = |key: &SigningKey, attestation: &[u8]| SignerSignature {
+        signer: SignerReference::Index {
+            index: signer_index(key),
+        },
+        signature: sign_attestation(key, attestation),
+    };
+
+    // Link the token with signer signatures only
+    let env = contract_env(&app, &bridge_address);
+    let token = TokenMetadata {
+        ticker: "TESTTOKEN".to_string(),
+        name: "TestToken".to_string(),
+        image_url: "https://example.com".to_string(),
+        decimals: 6,
+    };
+    let attestation =
+        Attestation::link_token(&env, "localgaia-1", &token).encode(AttestationVersion::V1);
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::LinkToken {
+            source_chain_id: "localgaia-1".to_string(),
+            token,
+            signatures: vec![],
+            signer_signatures: Some(vec![
+                by_key(&signers[0], &attestation),
+                by_index(&signers[1], &attestation),
+            ]),
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let attestation = Attestation::receive(
+        &env,
+        "localgaia-1",
+        "TXHASH1",
+        "TESTTOKEN",
+        Uint128::from(1000u64),
+        "user1",
+    )
+    .encode(AttestationVersion::V1);
+    let receive_msg =
+        |signatures: Vec<String>, signer_signatures: Vec<SignerSignature>| ExecuteMsg::Receive {
+            source_chain_id: "localgaia-1".to_string(),
+            transaction_hash: "TXHASH1".to_string(),
+            ticker: "TESTTOKEN".to_string(),
+            amount: Uint128::from(1000u64),
+            destination_addr: "user1".to_string(),
+            signatures,
+            signer_signatures: Some(signer_signatures),
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
+            token_signatures: None,
+            expires_at: None,
+        };
+
+    // Keys outside of the signer set are rejected
+    let unknown_signer = signing_key(4);
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &receive_msg(
+                vec![],
+                vec![
+                    by_key(&unknown_signer, &attestation),
+                    by_key(&signers[0], &attestation),
+                ],
+            ),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::UnknownSigner {
+            signer: public_key_base64(&unknown_signer)
+        }
+    );
+
+    // Indexes outside of the signer set are rejected
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &receive_msg(
+                vec![],
+                vec![
+                    SignerSignature {
+                        signer: SignerReference::Index { index: 3 },
+                        signature: sign_attestation(&signers[0], &attestation),
+                    },
+                    by_key(&signers[1], &attestation),
+                ],
+            ),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::UnknownSigner {
+            signer: "#3".to_string()
+        }
+    );
+
+    // A signature paired with the wrong signer is rejected
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &receive_msg(
+                vec![],
+                vec![
+                    SignerSignature {
+                        signer: SignerReference::PublicKey {
+                            public_key_base64: public_key_base64(&signers[0]),
+                        },
+                        signature: sign_attestation(&signers[1], &attestation),
+                    },
+                    by_key(&signers[2], &attestation),
+                ],
+            ),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidSignerSignature {
+            signer: public_key_base64(&signers[0])
+        }
+    );
+
+    // The same signer can't be named twice, even in different forms
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &receive_msg(
+                vec![],
+                vec![
+                    by_key(&signers[0], &attestation),
+                    by_index(&signers[0], &attestation),
+                ],
+            ),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::DuplicateSignatures {}
+    );
+
+    // Signer signatures and plain signatures count toward the same threshold
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &receive_msg(
+            vec![sign_attestation(&signers[2], &attestation)],
+            vec![by_index(&signers[1], &attestation)],
+        ),
+        &[],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_all_balances("user1").unwrap();
+    assert_eq!(res, vec![Coin::new(1000, "factory/contract0/TESTTOKEN")]);
+}
+
+#[test]
+fn test_weighted_signers() {
+    let owner = Addr::unchecked("owner");
+    let not_owner = Addr::unchecked("not_owner");
+    let mut app = mock_app(&owner, vec![]);
+    let contract_code = app.store_code(bridge_contract());
+
+    let bridge_address = app
+        .instantiate_contract(
+            contract_code,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: owner.to_string(),
+                ibc_timeout_seconds: 10,
+                bridge_ibc_channel: "channel-0".to_string(),
+                bridge_chain_id: "localgaia-1".to_string(),
+            },
+            &[],
+            "Asteroid Bridge",
+            None,
+        )
+        .unwrap();
+
+    let signers = [signing_key(1), signing_key(2), signing_key(3)];
+
+    // A signer can't have a weight of 0
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::AddSigner {
+                name: "signer1".to_string(),
+                public_key_base64: public_key_base64(&signers[0]),
+                key_type: None,
+                weight: Some(0),
+                contact_url: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidConfiguration {
+            reason: "The signer weight must be between 1 and 1000000".to_string()
+        }
+    );
+
+    // The first signer carries most of the weight
+    for (index, (signer, weight)) in signers.iter().zip([Some(4), None, None]).enumerate() {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::AddSigner {
+                name: format!("signer{}", index + 1),
+                public_key_base64: public_key_base64(signer),
+                key_type: None,
+                weight,
+                contact_url: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // Two-thirds of the total weight of 6 is required
+    let response: QuerySignersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Signers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(response.total_weight, 6);
+    assert_eq!(response.threshold, 4);
+    let mut weights = response
+        .signers
+        .iter()
+        .map(|(_, signer)| (signer.name.as_str(), signer.weight))
+        .collect::<Vec<_>>();
+    weights.sort();
+    assert_eq!(
+        weights,
+        vec![("signer1", 4), ("signer2", 1), ("signer3", 1)]
+    );
+
+    let env = contract_env(&app, &bridge_address);
+    let token = TokenMetadata {
+        ticker: "TESTTOKEN".to_string(),
+        name: "TestToken".to_string(),
+        image_url: "https://example.com".to_string(),
+        decimals: 6,
+    };
+    let attestation =
+        Attestation::link_token(&env, "localgaia-1", &token).encode(AttestationVersion::V1);
+    let link_msg = |signed_by: &[&SigningKey]| ExecuteMsg::LinkToken {
+        source_chain_id: "localgaia-1".to_string(),
+        token: token.clone(),
+        signatures: signed_by
+            .iter()
+            .map(|signer| sign_attestation(signer, &attestation))
+            .collect(),
+        signer_signatures: None,
+        adr036_signatures: None,
+        signer_epoch: None,
+        attestation_version: None,
+    };
+
+    // Even with enough weight, a single signer is never enough
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &link_msg(&[&signers[0]]),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::ThresholdNotMet {}
+    );
+
+    // Two light signers don't carry enough weight
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &link_msg(&[&signers[1], &signers[2]]),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::ThresholdNotMet {}
+    );
+
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &link_msg(&[&signers[0], &signers[1]]),
+        &[],
+    )
+    .unwrap();
+
+    // Only the owner can update the weight of a signer
+    let update_msg = ExecuteMsg::UpdateSignerWeight {
+        public_key_base64: public_key_base64(&signers[0]),
+        weight: 1,
+    };
+    let err = app
+        .execute_contract(not_owner.clone(), bridge_address.clone(), &update_msg, &[])
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    );
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::UpdateSignerWeight {
+                public_key_base64: public_key_base64(&signing_key(4)),
+                weight: 1,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidConfiguration {
+            reason: "Key to update doesn't exist".to_string()
+        }
+    );
+
+    app.execute_contract(owner.clone(), bridge_address.clone(), &update_msg, &[])
+        .unwrap();
+
+    // The weight change starts a new signer epoch
+    let response: QuerySignersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Signers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(response.total_weight, 3);
+    assert_eq!(response.threshold, 2);
+    assert_eq!(response.epoch, 4);
+
+    // The two light signers are now enough
+    let attestation = Attestation::receive(
+        &env,
+        "localgaia-1",
+        "TXHASH1",
+        "TESTTOKEN",
+        Uint128::from(1000u64),
+        "user1",
+    )
+    .encode(AttestationVersion::V1);
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::Receive {
+            source_chain_id: "localgaia-1".to_string(),
+            transaction_hash: "TXHASH1".to_string(),
+            ticker: "TESTTOKEN".to_string(),
+            amount: Uint128::from(1000u64),
+            destination_addr: "user1".to_string(),
+            signatures: vec![
+                sign_attestation(&signers[1], &attestation),
+                sign_attestation(&signers[2], &attestation),
+            ],
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
+            token_signatures: None,
+            expires_at: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_all_balances("user1").unwrap();
+    assert_eq!(res, vec![Coin::new(1000, "factory/contract0/TESTTOKEN")]);
+
+    // A heavy signer can meet a fixed threshold alone, but never without a second signer
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::UpdateSignerWeight {
+            public_key_base64: public_key_base64(&signers[0]),
+            weight: 5,
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::UpdateConfig {
+            bridge_ibc_channel: None,
+            ibc_timeout_seconds: None,
+            signer_threshold: Some(SignerThreshold::Fixed { weight: 5 }),
+            signer_epoch_grace_seconds: None,
+            v1_attestations_enabled: None,
+            admin_action_delay_seconds: None,
+            pending_receive_max_age_seconds: None,
+            min_signer_bond: None,
+            unbonding_period_seconds: None,
+            treasury: None,
+            reward_fee_rate: None,
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::RemoveSigner {
+            public_key_base64: public_key_base64(&signers[2]),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::RemoveSigner {
+                public_key_base64: public_key_base64(&signers[1]),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidConfiguration {
+            reason: "The signer threshold can't be met by the current signers".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_amount_tiers() {
+    let owner = Addr::unchecked("owner");
+    let not_owner = Addr::unchecked("not_owner");
+    let mut app = mock_app(&owner, vec![]);
+    let contract_code = app.store_code(bridge_contract());
+
+    let bridge_address = app
+        .instantiate_contract(
+            contract_code,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: owner.to_string(),
+                ibc_timeout_seconds: 10,
+                bridge_ibc_channel: "channel-0".to_string(),
+                bridge_chain_id: "localgaia-1".to_string(),
+            },
+            &[],
+            "Asteroid Bridge",
+            None,
+        )
+        .unwrap();
+
+    let signers = [signing_key(1), signing_key(2), signing_key(3)];
+    for (index, signer) in signers.iter().enumerate() {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::AddSigner {
+                name: format!("signer{}", index + 1),
+                public_key_base64: public_key_base64(signer),
+                key_type: None,
+                weight: None,
+                contact_url: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let tiers = vec![
+        AmountTier {
+            min_amount: Uint128::from(10_000u64),
+            percentage: Decimal::percent(80),
+        },
+        AmountTier {
+            min_amount: Uint128::from(100_000u64),
+            percentage: Decimal::one(),
+        },
+    ];
+
+    // Tiers can only be set on linked tokens
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::SetAmountTiers {
+                ticker: "TESTTOKEN".to_string(),
+                tiers: tiers.clone(),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::TokenDoesNotExist {
+            ticker: "TESTTOKEN".to_string()
+        }
+    );
+
+    let env = contract_env(&app, &bridge_address);
+    let token = TokenMetadata {
+        ticker: "TESTTOKEN".to_string(),
+        name: "TestToken".to_string(),
+        image_url: "https://example.com".to_string(),
+        decimals: 6,
+    };
+    let attestation =
+        Attestation::link_token(&env, "localgaia-1", &token).encode(AttestationVersion::V1);
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::LinkToken {
+            source_chain_id: "localgaia-1".to_string(),
+            token: token.clone(),
+            signatures: vec![
+                sign_attestation(&signers[0], &attestation),
+                sign_attestation(&signers[1], &attestation),
+            ],
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Only the owner can set the tiers
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::SetAmountTiers {
+                ticker: "TESTTOKEN".to_string(),
+                tiers: tiers.clone(),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    );
+
+    // Larger amounts can't require less of the signer weight
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::SetAmountTiers {
+                ticker: "TESTTOKEN".to_string(),
+                tiers: vec![tiers[1].clone(), tiers[0].clone()],
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidConfiguration {
+            reason: "The amount tiers must increase in amount and percentage".to_string()
+        }
+    );
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::SetAmountTiers {
+                ticker: "TESTTOKEN".to_string(),
+                tiers: vec![AmountTier {
+                    min_amount: Uint128::from(10_000u64),
+                    percentage: Decimal::percent(120),
+                }],
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidConfiguration {
+            reason: "The amount tier percentage must be between 0 and 1".to_string()
+        }
+    );
+
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::SetAmountTiers {
+            ticker: "TESTTOKEN".to_string(),
+            tiers: tiers.clone(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let response: Vec<AmountTier> = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::AmountTiers {
+                ticker: "TESTTOKEN".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(response, tiers);
+
+    let receive_msg = |transaction_hash: &str, amount: u64, signed_by: &[&SigningKey]| {
+        let attestation = Attestation::receive(
+            &env,
+            "localgaia-1",
+            transaction_hash,
+            "TESTTOKEN",
+            Uint128::from(amount),
+            "user1",
+        )
+        .encode(AttestationVersion::V1);
+        ExecuteMsg::Receive {
+            source_chain_id: "localgaia-1".to_string(),
+            transaction_hash: transaction_hash.to_string(),
+            ticker: "TESTTOKEN".to_string(),
+            amount: Uint128::from(amount),
+            destination_addr: "user1".to_string(),
+            signatures: signed_by
+                .iter()
+                .map(|signer| sign_attestation(signer, &attestation))
+                .collect(),
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
+            token_signatures: None,
+            expires_at: None,
+        }
+    };
+
+    // Below the first tier the global threshold of 2 of 3 applies
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &receive_msg("TXHASH1", 9_999, &[&signers[0], &signers[1]]),
+        &[],
+    )
+    .unwrap();
+
+    // The tiers require all 3 signers for larger amounts
+    for (transaction_hash, amount) in [("TXHASH2", 10_000), ("TXHASH3", 100_000)] {
+        let err = app
+            .execute_contract(
+                not_owner.clone(),
+                bridge_address.clone(),
+                &receive_msg(transaction_hash, amount, &[&signers[0], &signers[1]]),
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast::<ContractError>().unwrap(),
+            ContractError::ThresholdNotMet {}
+        );
+    }
+
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &receive_msg("TXHASH3", 100_000, &[&signers[0], &signers[1], &signers[2]]),
+        &[],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_all_balances("user1").unwrap();
+    assert_eq!(res, vec![Coin::new(109_999, "factory/contract0/TESTTOKEN")]);
+
+    // Removing the tiers restores the global threshold
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::SetAmountTiers {
+            ticker: "TESTTOKEN".to_string(),
+            tiers: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &receive_msg("TXHASH4", 100_000, &[&signers[0], &signers[1]]),
+        &[],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_token_signers() {
+    let owner = Addr::unchecked("owner");
+    let not_owner = Addr::unchecked("not_owner");
+    let mut app = mock_app(&owner, vec![]);
+    let contract_code = app.store_code(bridge_contract());
+
+    let bridge_address = app
+        .instantiate_contract(
+            contract_code,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: owner.to_string(),
+                ibc_timeout_seconds: 10,
+                bridge_ibc_channel: "channel-0".to_string(),
+                bridge_chain_id: "localgaia-1".to_string(),
+            },
+            &[],
+            "Asteroid Bridge",
+            None,
+        )
+        .unwrap();
+
+    let signers = [signing_key(1), signing_key(2)];
+    for (index, signer) in signers.iter().enumerate() {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::AddSigner {
+                name: format!("signer{}", index + 1),
+                public_key_base64: public_key_base64(signer),
+                key_type: None,
+                weight: None,
+                contact_url: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+    let token_signers = [signing_key(3), signing_key(4), signing_key(5)];
+
+    let add_token_signer_msg = |index: usize| ExecuteMsg::AddTokenSigner {
+        ticker: "TESTTOKEN".to_string(),
+        public_key_base64: public_key_base64(&token_signers[index]),
+        name: format!("issuer{}", index + 1),
+        key_type: None,
+        weight: None,
+    };
+
+    // Token signers can only be added to linked tokens
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &add_token_signer_msg(0),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::TokenDoesNotExist {
+            ticker: "TESTTOKEN".to_string()
+        }
+    );
+
+    let env = contract_env(&app, &bridge_address);
+    let token = TokenMetadata {
+        ticker: "TESTTOKEN".to_string(),
+        name: "TestToken".to_string(),
+        image_url: "https://example.com".to_string(),
+        decimals: 6,
+    };
+    let attestation =
+        Attestation::link_token(&env, "localgaia-1", &token).encode(AttestationVersion::V1);
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::LinkToken {
+            source_chain_id: "localgaia-1".to_string(),
+            token: token.clone(),
+            signatures: vec![
+                sign_attestation(&signers[0], &attestation),
+                sign_attestation(&signers[1], &attestation),
+            ],
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Only the owner can manage the token signers
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &add_token_signer_msg(0),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    );
+
+    let receive_msg = |transaction_hash: &str, token_signed_by: &[&SigningKey]| {
+        let attestation = Attestation::receive(
+            &env,
+            "localgaia-1",
+            transaction_hash,
+            "TESTTOKEN",
+            Uint128::from(1000u64),
+            "user1",
+        )
+        .encode(AttestationVersion::V1);
+        ExecuteMsg::Receive {
+            source_chain_id: "localgaia-1".to_string(),
+            transaction_hash: transaction_hash.to_string(),
+            ticker: "TESTTOKEN".to_string(),
+            amount: Uint128::from(1000u64),
+            destination_addr: "user1".to_string(),
+            signatures: signers
+                .iter()
+                .map(|signer| sign_attestation(signer, &attestation))
+                .collect(),
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
+            token_signatures: Some(
+                token_signed_by
+                    .iter()
+                    .map(|signer| sign_attestation(signer, &attestation))
+                    .collect(),
+            ),
+            expires_at: None,
+        }
+    };
+
+    // Without signers of its own, only the global quorum is needed
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &receive_msg("TXHASH1", &[]),
+        &[],
+    )
+    .unwrap();
+
+    for index in 0..token_signers.len() {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &add_token_signer_msg(index),
+            &[],
+        )
+        .unwrap();
+    }
+
+    let response: QueryTokenSignersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::TokenSigners {
+                ticker: "TESTTOKEN".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(response.signers.len(), 3);
+    assert_eq!(response.signer_threshold, SignerThreshold::supermajority());
+    assert_eq!(response.threshold, 2);
+    assert_eq!(response.total_weight, 3);
+
+    // The global signers alone are no longer enough
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &receive_msg("TXHASH2", &[&token_signers[0]]),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::TokenThresholdNotMet {
+            ticker: "TESTTOKEN".to_string()
+        }
+    );
+
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &receive_msg("TXHASH2", &[&token_signers[0], &token_signers[2]]),
+        &[],
+    )
+    .unwrap();
+
+    // The token quorum can be raised to all of its signers
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::SetTokenSignerThreshold {
+                ticker: "TESTTOKEN".to_string(),
+                signer_threshold: SignerThreshold::Fixed { weight: 1 },
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidConfiguration {
+            reason: "The signer threshold can't be less than 2".to_string()
+        }
+    );
+
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::SetTokenSignerThreshold {
+            ticker: "TESTTOKEN".to_string(),
+            signer_threshold: SignerThreshold::Fixed { weight: 3 },
+        },
+        &[],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &receive_msg("TXHASH3", &[&token_signers[0], &token_signers[2]]),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::TokenThresholdNotMet {
+            ticker: "TESTTOKEN".to_string()
+        }
+    );
+
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &receive_msg(
+            "TXHASH3",
+            &[&token_signers[0], &token_signers[1], &token_signers[2]],
+        ),
+        &[],
+    )
+    .unwrap();
+
+    // Removing all token signers restores the global quorum alone
+    for signer in &token_signers {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::RemoveTokenSigner {
+                ticker: "TESTTOKEN".to_string(),
+                public_key_base64: public_key_base64(signer),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &receive_msg("TXHASH4", &[]),
+        &[],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_all_balances("user1").unwrap();
+    assert_eq!(res, vec![Coin::new(4000, "factory/contract0/TESTTOKEN")]);
+}
+
+#[test]
+fn test_signer_change_proposals() {
+    let owner = Addr::unchecked("owner");
+    let relayer = Addr::unchecked("relayer");
+    let mut app = mock_app(&owner, vec![]);
+    let contract_code = app.store_code(bridge_contract());
+
+    let bridge_address = app
+        .instantiate_contract(
+            contract_code,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: owner.to_string(),
+                ibc_timeout_seconds: 10,
+                bridge_ibc_channel: "channel-0".to_string(),
+                bridge_chain_id: "localgaia-1".to_string(),
+            },
+            &[],
+            "Asteroid Bridge",
+            None,
+        )
+        .unwrap();
+
+    let signers = [
+        signing_key(1),
+        signing_key(2),
+        signing_key(3),
+        signing_key(4),
+    ];
+    for (index, signer) in signers.iter().take(3).enumerate() {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::AddSigner {
+                name: format!("signer{}", index + 1),
+                public_key_base64: public_key_base64(signer),
+                key_type: None,
+                weight: None,
+                contact_url: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let env = contract_env(&app, &bridge_address);
+    let approve =
+        |proposal_id: u64, signer_epoch: u64, change: &SignerChange, signer: &SigningKey| {
+            let attestation = Attestation::signer_change(&env, proposal_id, signer_epoch, change)
+                .unwrap()
+                .encode(AttestationVersion::V2);
+            SignerSignature {
+                signer: SignerReference::PublicKey {
+                    public_key_base64: public_key_base64(signer),
+                },
+                signature: sign_attestation(signer, attestation),
+            }
+        };
+
+    // A proposal must be backed by a signer
+    let add_signer = SignerChange::AddSigner {
+        public_key_base64: public_key_base64(&signers[3]),
+        name: "signer4".to_string(),
+        key_type: None,
+        weight: None,
+        contact_url: None,
+    };
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::ProposeSignerChange {
+                change: add_signer.clone(),
+                signer_signatures: vec![],
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidConfiguration {
+            reason: "A signer change must be approved by at least one signer".to_string()
+        }
+    );
+
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::ProposeSignerChange {
+            change: add_signer.clone(),
+            signer_signatures: vec![approve(0, 3, &add_signer, &signers[0])],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let proposal: SignerChangeProposal = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::SignerChangeProposal { proposal_id: 0 },
+        )
+        .unwrap();
+    assert_eq!(proposal.change, add_signer);
+    assert_eq!(proposal.signer_epoch, 3);
+    assert_eq!(proposal.approvals.len(), 1);
+    assert_eq!(proposal.proposer, relayer);
+
+    // Only signers of the current set can approve
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::ApproveSignerChange {
+                proposal_id: 0,
+                signer_signatures: vec![approve(0, 3, &add_signer, &signers[3])],
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::UnknownSigner {
+            signer: public_key_base64(&signers[3])
+        }
+    );
+
+    // A signer can't approve twice
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::ApproveSignerChange {
+                proposal_id: 0,
+                signer_signatures: vec![approve(0, 3, &add_signer, &signers[0])],
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::DuplicateSignatures {}
+    );
+
+    // Approvals for another proposal don't verify
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::ApproveSignerChange {
+                proposal_id: 0,
+                signer_signatures: vec![approve(1, 3, &add_signer, &signers[1])],
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidSignerSignature {
+            signer: public_key_base64(&signers[1])
+        }
+    );
+
+    // The second approval reaches the threshold and applies the change
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::ApproveSignerChange {
+            proposal_id: 0,
+            signer_signatures: vec![approve(0, 3, &add_signer, &signers[1])],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let response: QuerySignersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Signers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(response.signers.len(), 4);
+    assert_eq!(response.epoch, 4);
+
+    app.wrap()
+        .query_wasm_smart::<SignerChangeProposal>(
+            &bridge_address,
+            &QueryMsg::SignerChangeProposal { proposal_id: 0 },
+        )
+        .unwrap_err();
+
+    // Only the owner can hand over the signer management
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::DisableOwnerSignerManagement {},
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    );
+
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::DisableOwnerSignerManagement {},
+        &[],
+    )
+    .unwrap();
+
+    // The owner can no longer change the signers or the threshold
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::RemoveSigner {
+                public_key_base64: public_key_base64(&signers[0]),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::OwnerSignerManagementDisabled {}
+    );
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::UpdateConfig {
+                bridge_ibc_channel: None,
+                ibc_timeout_seconds: None,
+                signer_threshold: Some(SignerThreshold::Fixed { weight: 2 }),
+                signer_epoch_grace_seconds: None,
+                v1_attestations_enabled: None,
+                admin_action_delay_seconds: None,
+                pending_receive_max_age_seconds: None,
+                min_signer_bond: None,
+                unbonding_period_seconds: None,
+                treasury: None,
+                reward_fee_rate: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::OwnerSignerManagementDisabled {}
+    );
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::DisableOwnerSignerManagement {},
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::OwnerSignerManagementDisabled {}
+    );
+
+    // A proposal carrying the approvals of the threshold applies at once
+    let update_weight = SignerChange::UpdateSignerWeight {
+        public_key_base64: public_key_base64(&signers[3]),
+        weight: 3,
+    };
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::ProposeSignerChange {
+            change: update_weight.clone(),
+            signer_signatures: vec![approve(1, 4, &update_weight, &signers[0])],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let remove_signer = SignerChange::RemoveSigner {
+        public_key_base64: public_key_base64(&signers[0]),
+    };
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::ProposeSignerChange {
+            change: remove_signer.clone(),
+            signer_signatures: [&signers[1], &signers[2], &signers[3]]
+                .into_iter()
+                .map(|signer| approve(2, 4, &remove_signer, signer))
+                .collect(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let response: QuerySignersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Signers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(response.signers.len(), 3);
+    assert_eq!(response.epoch, 5);
+
+    // Proposals made for a previous signer set are stale
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::ApproveSignerChange {
+                proposal_id: 1,
+                signer_signatures: vec![approve(1, 4, &update_weight, &signers[1])],
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidSignerEpoch {
+            epoch: 4,
+            current: 5
+        }
+    );
+}
+
+#[test]
+fn test_admin_action_queue() {
+    let owner = Addr::unchecked("owner");
+    let not_owner = Addr::unchecked("not_owner");
+    let mut app = mock_app(&owner, vec![]);
+    let contract_code = app.store_code(bridge_contract());
+
+    let bridge_address = app
+        .instantiate_contract(
+            contract_code,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: owner.to_string(),
+                ibc_timeout_seconds: 10,
+                bridge_ibc_channel: "channel-0".to_string(),
+                bridge_chain_id: "localgaia-1".to_string(),
+            },
+            &[],
+            "Asteroid Bridge",
+            None,
+        )
+        .unwrap();
+
+    // Without a delay admin actions apply immediately
+    let signers = [signing_key(1), signing_key(2), signing_key(3)];
+    let add_signer_msg = |index: usize| ExecuteMsg::AddSigner {
+        name: format!("signer{}", index + 1),
+        public_key_base64: public_key_base64(&signers[index]),
+        key_type: None,
+        weight: None,
+        contact_url: None,
+    };
+    for index in 0..2 {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &add_signer_msg(index),
+            &[],
+        )
+        .unwrap();
+    }
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::UpdateConfig {
+                bridge_ibc_channel: None,
+                ibc_timeout_seconds: None,
+                signer_threshold: None,
+                signer_epoch_grace_seconds: None,
+                v1_attestations_enabled: None,
+                admin_action_delay_seconds: Some(MAX_ADMIN_ACTION_DELAY_SECONDS + 1),
+                pending_receive_max_age_seconds: None,
+                min_signer_bond: None,
+                unbonding_period_seconds: None,
+                treasury: None,
+                reward_fee_rate: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidConfiguration {
+            reason: format!(
+                "The admin action delay can't be more than {} seconds",
+                MAX_ADMIN_ACTION_DELAY_SECONDS
+            )
+        }
+    );
+
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::UpdateConfig {
+            bridge_ibc_channel: None,
+            ibc_timeout_seconds: None,
+            signer_threshold: None,
+            signer_epoch_grace_seconds: None,
+            v1_attestations_enabled: None,
+            admin_action_delay_seconds: Some(60 * 60),
+            pending_receive_max_age_seconds: None,
+            min_signer_bond: None,
+            unbonding_period_seconds: None,
+            treasury: None,
+            reward_fee_rate: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let env = contract_env(&app, &bridge_address);
+    let token = TokenMetadata {
+        ticker: "TESTTOKEN".to_string(),
+        name: "TestToken".to_string(),
+        image_url: "https://example.com".to_string(),
+        decimals: 6,
+    };
+    let attestation =
+        Attestation::link_token(&env, "localgaia-1", &token).encode(AttestationVersion::V1);
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::LinkToken {
+            source_chain_id: "localgaia-1".to_string(),
+            token: token.clone(),
+            signatures: vec![
+                sign_attestation(&signers[0], &attestation),
+                sign_attestation(&signers[1], &attestation),
+            ],
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Only the owner can queue admin actions
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &add_signer_msg(2),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    );
+
+    // With a delay admin actions are queued
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &add_signer_msg(2),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::UpdateConfig {
+            bridge_ibc_channel: None,
+            ibc_timeout_seconds: Some(20),
+            signer_threshold: None,
+            signer_epoch_grace_seconds: None,
+            v1_attestations_enabled: None,
+            admin_action_delay_seconds: None,
+            pending_receive_max_age_seconds: None,
+            min_signer_bond: None,
+            unbonding_period_seconds: None,
+            treasury: None,
+            reward_fee_rate: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let response: QuerySignersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Signers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(response.signers.len(), 2);
+
+    let response: QueryAdminActionsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::AdminActions {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(response.actions.len(), 2);
+    assert_eq!(response.actions[0].0, 0);
+    assert_eq!(
+        response.actions[0].1.action,
+        AdminAction::AddSigner {
+            public_key_base64: public_key_base64(&signers[2]),
+            name: "signer3".to_string(),
+            key_type: None,
+            weight: None,
+            contact_url: None,
+        }
+    );
+    assert_eq!(
+        response.actions[0].1.executable_at,
+        response.actions[0].1.queued_at.plus_seconds(60 * 60)
+    );
+
+    let response: QueryAdminActionsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::AdminActions {
+                start_after: Some(0),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+    assert_eq!(response.actions.len(), 1);
+    assert_eq!(response.actions[0].0, 1);
+
+    // Disabling a token stays immediate for incident response
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::DisableToken {
+            ticker: "TESTTOKEN".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::EnableToken {
+            ticker: "TESTTOKEN".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let response: QueryTokensResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::DisabledTokens {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(response.tokens, vec!["TESTTOKEN".to_string()]);
+
+    // The own signers of a token are queued like the global signers
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::AddTokenSigner {
+            ticker: "TESTTOKEN".to_string(),
+            public_key_base64: public_key_base64(&signing_key(4)),
+            name: "token_signer1".to_string(),
+            key_type: None,
+            weight: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let response: QueryTokenSignersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::TokenSigners {
+                ticker: "TESTTOKEN".to_string(),
+            },
+        )
+        .unwrap();
+    assert!(response.signers.is_empty());
+
+    // Queued actions can't be executed before their delay passed
+    let queued_at = app.block_info().time;
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::ExecuteAdminAction { id: 0 },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::AdminActionNotReady {
+            id: 0,
+            executable_at: queued_at.plus_seconds(60 * 60)
+        }
+    );
+
+    // Only the owner can cancel queued actions
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::CancelAdminAction { id: 1 },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    );
+
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::CancelAdminAction { id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    app.update_block(|block| {
+        block.time = block.time.plus_seconds(60 * 60);
+    });
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::ExecuteAdminAction { id: 1 },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::AdminActionNotFound { id: 1 }
+    );
+
+    for id in [0, 2, 3] {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::ExecuteAdminAction { id },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let response: QueryTokenSignersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::TokenSigners {
+                ticker: "TESTTOKEN".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(response.signers.len(), 1);
+
+    let response: QuerySignersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Signers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(response.signers.len(), 3);
+
+    let response: QueryConfigResponse = app
+        .wrap()
+        .query_wasm_smart(&bridge_address, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(response.config.ibc_timeout_seconds, 10);
+
+    let response: QueryTokensResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::DisabledTokens {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(response.tokens.len(), 0);
+
+    let response: QueryAdminActionsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::AdminActions {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(response.actions.is_empty());
+}
+
+#[test]
+fn test_rotate_signer_key() {
+    let owner = Addr::unchecked("owner");
+    let relayer = Addr::unchecked("relayer");
+    let mut app = mock_app(&owner, vec![]);
+    let contract_code = app.store_code(bridge_contract());
+
+    let bridge_address = app
+        .instantiate_contract(
+            contract_code,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: owner.to_string(),
+                ibc_timeout_seconds: 10,
+                bridge_ibc_channel: "channel-0".to_string(),
+                bridge_chain_id: "localgaia-1".to_string(),
+            },
+            &[],
+            "Asteroid Bridge",
+            None,
+        )
+        .unwrap();
+
+    let signers = [signing_key(1), signing_key(2), signing_key(3)];
+    for (index, signer) in signers.iter().enumerate() {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::AddSigner {
+                name: format!("signer{}", index + 1),
+                public_key_base64: public_key_base64(signer),
+                key_type: None,
+                weight: Some(index as u64 + 1),
+                contact_url: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+    let new_key = signing_key(4);
+
+    let env = contract_env(&app, &bridge_address);
+    let token = TokenMetadata {
+        ticker: "TESTTOKEN".to_string(),
+        name: "TestToken".to_string(),
+        image_url: "https://example.com".to_string(),
+        decimals: 6,
+    };
+    let attestation =
+        Attestation::link_token(&env, "localgaia-1", &token).encode(AttestationVersion::V1);
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::LinkToken {
+            source_chain_id: "localgaia-1".to_string(),
+            token: token.clone(),
+            signatures: vec![
+                sign_attestation(&signers[1], &attestation),
+                sign_attestation(&signers[2], &attestation),
+            ],
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let rotate_msg = |nonce: u64, signed_by: &SigningKey| {
+        let attestation = Attestation::rotate_signer_key(
+            &env,
+            &public_key_base64(&signers[2]),
+            &public_key_base64(&new_key),
+            KeyType::Ed25519,
+            nonce,
+        )
+        .encode(AttestationVersion::V2);
+        ExecuteMsg::RotateSignerKey {
+            old_public_key_base64: public_key_base64(&signers[2]),
+            new_public_key_base64: public_key_base64(&new_key),
+            new_key_type: None,
+            nonce,
+            signature: sign_attestation(signed_by, attestation),
+            overlap_seconds: Some(60 * 60),
+        }
+    };
+
+    // The rotation must use the next nonce of the signer
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &rotate_msg(1, &signers[2]),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidSignerRotationNonce {
+            nonce: 1,
+            expected: 0
+        }
+    );
+
+    // Only the old key can authorise the rotation
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &rotate_msg(0, &signers[1]),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidSignerSignature {
+            signer: public_key_base64(&signers[2])
+        }
+    );
+
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &rotate_msg(0, &signers[2]),
+        &[],
+    )
+    .unwrap();
+
+    // The signer keeps its name and weight under the new key
+    let response: QuerySignersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Signers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(response.signers.len(), 3);
+    assert_eq!(response.total_weight, 6);
+    let (_, signer) = response
+        .signers
+        .iter()
+        .find(|(key, _)| key == &public_key_base64(&new_key))
+        .unwrap();
+    assert_eq!(signer.name, "signer3");
+    assert_eq!(signer.weight, 3);
+    assert!(!response
+        .signers
+        .iter()
+        .any(|(key, _)| key == &public_key_base64(&signers[2])));
+
+    let nonce: u64 = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::SignerRotationNonce {
+                name: "signer3".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(nonce, 1);
+
+    // The rotation can't be replayed
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &rotate_msg(0, &signers[2]),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::UnknownSigner {
+            signer: public_key_base64(&signers[2])
+        }
+    );
+
+    let receive_msg = |transaction_hash: &str, signed_by: &[&SigningKey]| {
+        let attestation = Attestation::receive(
+            &env,
+            "localgaia-1",
+            transaction_hash,
+            "TESTTOKEN",
+            Uint128::from(1000u64),
+            "user1",
+        )
+        .encode(AttestationVersion::V1);
+        ExecuteMsg::Receive {
+            source_chain_id: "localgaia-1".to_string(),
+            transaction_hash: transaction_hash.to_string(),
+            ticker: "TESTTOKEN".to_string(),
+            amount: Uint128::from(1000u64),
+            destination_addr: "user1".to_string(),
+            signatures: signed_by
+                .iter()
+                .map(|signer| sign_attestation(signer, &attestation))
+                .collect(),
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
+            token_signatures: None,
+            expires_at: None,
+        }
+    };
+
+    // During the overlap the old key is still accepted
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &receive_msg("TXHASH1", &[&signers[1], &signers[2]]),
+        &[],
+    )
+    .unwrap();
+
+    // Signing with both keys only counts the signer once
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &receive_msg("TXHASH2", &[&signers[2], &new_key]),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::ThresholdNotMet {}
+    );
+
+    // Once the overlap ends only the new key is accepted
+    app.update_block(|block| {
+        block.time = block.time.plus_seconds(60 * 60);
+    });
+
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &receive_msg("TXHASH2", &[&signers[1], &signers[2]]),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::ThresholdNotMet {}
+    );
+
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &receive_msg("TXHASH2", &[&signers[1], &new_key]),
+        &[],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_all_balances("user1").unwrap();
+    assert_eq!(res, vec![Coin::new(2000, "factory/contract0/TESTTOKEN")]);
+}
+
+#[test]
+fn test_signer_registry() {
+    let owner = Addr::unchecked("owner");
+    let relayer = Addr::unchecked("relayer");
+    let mut app = mock_app(&owner, vec![]);
+    let contract_code = app.store_code(bridge_contract());
+
+    let bridge_address = app
+        .instantiate_contract(
+            contract_code,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: owner.to_string(),
+                ibc_timeout_seconds: 10,
+                bridge_ibc_channel: "channel-0".to_string(),
+                bridge_chain_id: "localgaia-1".to_string(),
+            },
+            &[],
+            "Asteroid Bridge",
+            None,
+        )
+        .unwrap();
+
+    let signers = [signing_key(1), signing_key(2), signing_key(3)];
+    for (index, signer) in signers.iter().enumerate() {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::AddSigner {
+                name: format!("signer{}", index + 1),
+                public_key_base64: public_key_base64(signer),
+                key_type: None,
+                weight: None,
+                contact_url: Some(format!("https://signer{}.example.com", index + 1)),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // Names are unique among the signers
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::AddSigner {
+                name: "signer1".to_string(),
+                public_key_base64: public_key_base64(&signing_key(4)),
+                key_type: None,
+                weight: None,
+                contact_url: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidConfiguration {
+            reason: "The name 'signer1' is already linked to a public key".to_string()
+        }
+    );
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::AddSigner {
+                name: "signer4".to_string(),
+                public_key_base64: public_key_base64(&signing_key(4)),
+                key_type: None,
+                weight: None,
+                contact_url: Some("".to_string()),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidConfiguration {
+            reason: "The contact URL must be between 1 and 256 characters".to_string()
+        }
+    );
+
+    // The signers are paginated by their public key
+    let first_page: QuerySignersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Signers {
+                start_after: None,
+                limit: Some(2),
+            },
+        )
+        .unwrap();
+    assert_eq!(first_page.signers.len(), 2);
+    assert_eq!(first_page.total_weight, 3);
+
+    let second_page: QuerySignersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Signers {
+                start_after: Some(first_page.signers[1].0.clone()),
+                limit: Some(2),
+            },
+        )
+        .unwrap();
+    assert_eq!(second_page.signers.len(), 1);
+    assert_eq!(second_page.total_weight, 3);
+
+    let (_, signer) = first_page
+        .signers
+        .iter()
+        .chain(second_page.signers.iter())
+        .find(|(key, _)| key == &public_key_base64(&signers[2]))
+        .unwrap();
+    assert_eq!(signer.name, "signer3");
+    assert_eq!(
+        signer.contact_url,
+        Some("https://signer3.example.com".to_string())
+    );
+    assert_eq!(signer.added_at, app.block_info().time);
+    assert_eq!(signer.status, SignerStatus::Active);
+
+    let env = contract_env(&app, &bridge_address);
+    let token = TokenMetadata {
+        ticker: "TESTTOKEN".to_string(),
+        name: "TestToken".to_string(),
+        image_url: "https://example.com".to_string(),
+        decimals: 6,
+    };
+    let attestation =
+        Attestation::link_token(&env, "localgaia-1", &token).encode(AttestationVersion::V1);
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::LinkToken {
+            source_chain_id: "localgaia-1".to_string(),
+            token: token.clone(),
+            signatures: signers
+                .iter()
+                .map(|signer| sign_attestation(signer, &attestation))
+                .collect(),
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Only the owner can suspend a signer
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::UpdateSignerStatus {
+                public_key_base64: public_key_base64(&signers[2]),
+                status: SignerStatus::Suspended,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    );
+
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::UpdateSignerStatus {
+            public_key_base64: public_key_base64(&signers[2]),
+            status: SignerStatus::Suspended,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The suspended signer stays registered without counting toward the total weight
+    let response: QuerySignersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Signers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(response.signers.len(), 3);
+    assert_eq!(response.total_weight, 2);
+    assert_eq!(response.epoch, 4);
+
+    let receive_attestation = |transaction_hash: &str| {
+        Attestation::receive(
+            &env,
+            "localgaia-1",
+            transaction_hash,
+            "TESTTOKEN",
+            Uint128::from(1000u64),
+            "user1",
+        )
+        .encode(AttestationVersion::V1)
+    };
+    let receive_msg = |transaction_hash: &str, signed_by: &[&SigningKey]| ExecuteMsg::Receive {
+        source_chain_id: "localgaia-1".to_string(),
+        transaction_hash: transaction_hash.to_string(),
+        ticker: "TESTTOKEN".to_string(),
+        amount: Uint128::from(1000u64),
+        destination_addr: "user1".to_string(),
+        signatures: signed_by
+            .iter()
+            .map(|signer| sign_attestation(signer, receive_attestation(transaction_hash)))
+            .collect(),
+        signer_signatures: None,
+        adr036_signatures: None,
+        signer_epoch: None,
+        attestation_version: None,
+        token_signatures: None,
+        expires_at: None,
+    };
+
+    // The signatures of the suspended signer are not counted
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &receive_msg("TXHASH1", &[&signers[0], &signers[2]]),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::ThresholdNotMet {}
+    );
+
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::Receive {
+                source_chain_id: "localgaia-1".to_string(),
+                transaction_hash: "TXHASH1".to_string(),
+                ticker: "TESTTOKEN".to_string(),
+                amount: Uint128::from(1000u64),
+                destination_addr: "user1".to_string(),
+                signatures: vec![],
+                signer_signatures: Some(
+                    [&signers[0], &signers[2]]
+                        .iter()
+                        .map(|signer| SignerSignature {
+                            signer: SignerReference::PublicKey {
+                                public_key_base64: public_key_base64(signer),
+                            },
+                            signature: sign_attestation(signer, receive_attestation("TXHASH1")),
+                        })
+                        .collect(),
+                ),
+                adr036_signatures: None,
+                signer_epoch: None,
+                attestation_version: None,
+                token_signatures: None,
+                expires_at: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::SignerSuspended {
+            signer: public_key_base64(&signers[2])
+        }
+    );
+
+    // Nor are they counted for the set replaced by the suspension, even
+    // within its grace period
+    let attestation = Attestation::receive(
+        &env,
+        "localgaia-1",
+        "TXHASH1",
+        "TESTTOKEN",
+        Uint128::from(1000u64),
+        "user1",
+    )
+    .with_optional("signer_epoch", Some(3))
+    .encode(AttestationVersion::V1);
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::Receive {
+                source_chain_id: "localgaia-1".to_string(),
+                transaction_hash: "TXHASH1".to_string(),
+                ticker: "TESTTOKEN".to_string(),
+                amount: Uint128::from(1000u64),
+                destination_addr: "user1".to_string(),
+                signatures: [&signers[0], &signers[2]]
+                    .iter()
+                    .map(|signer| sign_attestation(signer, &attestation))
+                    .collect(),
+                signer_signatures: None,
+                adr036_signatures: None,
+                signer_epoch: Some(3),
+                attestation_version: None,
+                token_signatures: None,
+                expires_at: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::ThresholdNotMet {}
+    );
+
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &receive_msg("TXHASH1", &[&signers[0], &signers[1]]),
+        &[],
+    )
+    .unwrap();
+
+    // A signer can be suspended in an emergency even when the others can't
+    // meet the threshold alone
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::UpdateSignerStatus {
+            public_key_base64: public_key_base64(&signers[1]),
+            status: SignerStatus::Suspended,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &receive_msg("TXHASH2", &[&signers[0], &signers[1]]),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::ThresholdNotMet {}
+    );
+
+    // Without a delay the reactivation is applied right away
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::UpdateSignerStatus {
+            public_key_base64: public_key_base64(&signers[1]),
+            status: SignerStatus::Active,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Reactivating a signer is an admin action, queued behind the delay
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::UpdateConfig {
+            bridge_ibc_channel: None,
+            ibc_timeout_seconds: None,
+            signer_threshold: None,
+            signer_epoch_grace_seconds: None,
+            v1_attestations_enabled: None,
+            admin_action_delay_seconds: Some(60 * 60),
+            pending_receive_max_age_seconds: None,
+            min_signer_bond: None,
+            unbonding_period_seconds: None,
+            treasury: None,
+            reward_fee_rate: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::UpdateSignerStatus {
+            public_key_base64: public_key_base64(&signers[2]),
+            status: SignerStatus::Active,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let response: QueryAdminActionsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::AdminActions {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        response.actions[0].1.action,
+        AdminAction::ReactivateSigner {
+            public_key_base64: public_key_base64(&signers[2]),
+        }
+    );
+
+    let response: QuerySignersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Signers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(response.total_weight, 2);
+
+    // Once reactivated the signer counts again
+    app.update_block(|block| {
+        block.time = block.time.plus_seconds(60 * 60);
+    });
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::ExecuteAdminAction { id: 0 },
+        &[],
+    )
+    .unwrap();
+
+    let response: QuerySignersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Signers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(response.total_weight, 3);
+
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &receive_msg("TXHASH2", &[&signers[0], &signers[1], &signers[2]]),
+        &[],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_all_balances("user1").unwrap();
+    assert_eq!(res, vec![Coin::new(2000, "factory/contract0/TESTTOKEN")]);
+}
+
+#[test]
+fn test_signer_stats() {
+    let owner = Addr::unchecked("owner");
+    let relayer = Addr::unchecked("relayer");
+    let mut app = mock_app(&owner, vec![]);
+    let contract_code = app.store_code(bridge_contract());
+
+    let bridge_address = app
+        .instantiate_contract(
+            contract_code,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: owner.to_string(),
+                ibc_timeout_seconds: 10,
+                bridge_ibc_channel: "channel-0".to_string(),
+                bridge_chain_id: "localgaia-1".to_string(),
+            },
+            &[],
+            "Asteroid Bridge",
+            None,
+        )
+        .unwrap();
+
+    let signers = [signing_key(1), signing_key(2), signing_key(3)];
+    for (index, signer) in signers.iter().enumerate() {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::AddSigner {
+                name: format!("signer{}", index + 1),
+                public_key_base64: public_key_base64(signer),
+                key_type: None,
+                weight: None,
+                contact_url: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // Signers start without any participation
+    let response: QuerySignerStatsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::SignerStats {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(response.stats.len(), 3);
+    assert!(response
+        .stats
+        .iter()
+        .all(|(_, stats)| stats == &SignerStats::default()));
+
+    // Signer signatures are all counted
+    let env = contract_env(&app, &bridge_address);
+    let token = TokenMetadata {
+        ticker: "TESTTOKEN".to_string(),
+        name: "TestToken".to_string(),
+        image_url: "https://example.com".to_string(),
+        decimals: 6,
+    };
+    let attestation =
+        Attestation::link_token(&env, "localgaia-1", &token).encode(AttestationVersion::V1);
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::LinkToken {
+            source_chain_id: "localgaia-1".to_string(),
+            token: token.clone(),
+            signatures: vec![],
+            signer_signatures: Some(
+                signers
+                    .iter()
+                    .map(|signer| SignerSignature {
+                        signer: SignerReference::PublicKey {
+                            public_key_base64: public_key_base64(signer),
+                        },
+                        signature: sign_attestation(signer, &attestation),
+                    })
+                    .collect(),
+            ),
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let link_signers: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::LinkTokenSigners {
+                ticker: "TESTTOKEN".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(link_signers, vec!["signer1", "signer2", "signer3"]);
+
+    let receive_msg = |env: &Env, transaction_hash: &str, signed_by: &[&SigningKey]| {
+        let attestation = Attestation::receive(
+            env,
+            "localgaia-1",
+            transaction_hash,
+            "TESTTOKEN",
+            Uint128::from(1000u64),
+            "user1",
+        )
+        .encode(AttestationVersion::V1);
+        ExecuteMsg::Receive {
+            source_chain_id: "localgaia-1".to_string(),
+            transaction_hash: transaction_hash.to_string(),
+            ticker: "TESTTOKEN".to_string(),
+            amount: Uint128::from(1000u64),
+            destination_addr: "user1".to_string(),
+            signatures: signed_by
+                .iter()
+                .map(|signer| sign_attestation(signer, &attestation))
+                .collect(),
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
+            token_signatures: None,
+            expires_at: None,
+        }
+    };
+
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &receive_msg(&env, "TXHASH1", &[&signers[0], &signers[1]]),
+        &[],
+    )
+    .unwrap();
+    let first_height = app.block_info().height;
+
+    app.update_block(|block| {
+        block.height += 10;
+        block.time = block.time.plus_seconds(60);
+    });
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &receive_msg(&env, "TXHASH2", &[&signers[0], &signers[2]]),
+        &[],
+    )
+    .unwrap();
+    let second_block = app.block_info();
+
+    let mut transaction_signers: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::TransactionSigners {
+                transaction_hash: "TXHASH2".to_string(),
+            },
+        )
+        .unwrap();
+    transaction_signers.sort();
+    assert_eq!(transaction_signers, vec!["signer1", "signer3"]);
+
+    // Unknown transactions have no signers
+    let transaction_signers: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::TransactionSigners {
+                transaction_hash: "TXHASH3".to_string(),
+            },
+        )
+        .unwrap();
+    assert!(transaction_signers.is_empty());
+
+    // A signer added later hasn't been seen yet
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::AddSigner {
+            name: "signer4".to_string(),
+            public_key_base64: public_key_base64(&signing_key(4)),
+            key_type: None,
+            weight: None,
+            contact_url: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let response: QuerySignerStatsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::SignerStats {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        response.stats,
+        vec![
+            (
+                "signer1".to_string(),
+                SignerStats {
+                    attestations: 3,
+                    last_seen_height: Some(second_block.height),
+                    last_seen_time: Some(second_block.time),
+                }
+            ),
+            (
+                "signer2".to_string(),
+                SignerStats {
+                    attestations: 2,
+                    last_seen_height: Some(first_height),
+                    last_seen_time: Some(env.block.time),
+                }
+            ),
+            (
+                "signer3".to_string(),
+                SignerStats {
+                    attestations: 2,
+                    last_seen_height: Some(second_block.height),
+                    last_seen_time: Some(second_block.time),
+                }
+            ),
+            ("signer4".to_string(), SignerStats::default()),
+        ]
+    );
+
+    // The stats are paginated by the signer name
+    let response: QuerySignerStatsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::SignerStats {
+                start_after: Some("signer2".to_string()),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+    assert_eq!(response.stats.len(), 1);
+    assert_eq!(response.stats[0].0, "signer3");
+}
+
+#[test]
+fn test_attest() {
+    let owner = Addr::unchecked("owner");
+    let relayer = Addr::unchecked("relayer");
+    let mut app = mock_app(&owner, vec![]);
+    let contract_code = app.store_code(bridge_contract());
+
+    let bridge_address = app
+        .instantiate_contract(
+            contract_code,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: owner.to_string(),
+                ibc_timeout_seconds: 10,
+                bridge_ibc_channel: "channel-0".to_string(),
+                bridge_chain_id: "localgaia-1".to_string(),
+            },
+            &[],
+            "Asteroid Bridge",
+            None,
+        )
+        .unwrap();
+
+    let signers = [signing_key(1), signing_key(2), signing_key(3)];
+    for (index, signer) in signers.iter().enumerate() {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::AddSigner {
+                name: format!("signer{}", index + 1),
+                public_key_base64: public_key_base64(signer),
+                key_type: None,
+                weight: None,
+                contact_url: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let env = contract_env(&app, &bridge_address);
+    let token = TokenMetadata {
+        ticker: "TESTTOKEN".to_string(),
+        name: "TestToken".to_string(),
+        image_url: "https://example.com".to_string(),
+        decimals: 6,
+    };
+    let attestation =
+        Attestation::link_token(&env, "localgaia-1", &token).encode(AttestationVersion::V1);
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::LinkToken {
+            source_chain_id: "localgaia-1".to_string(),
+            token: token.clone(),
+            signatures: vec![
+                sign_attestation(&signers[0], &attestation),
+                sign_attestation(&signers[1], &attestation),
+            ],
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let attest_msg = |transaction_hash: &str, amount: u64, signed_by: &SigningKey| {
+        let attestation = Attestation::receive(
+            &env,
+            "localgaia-1",
+            transaction_hash,
+            "TESTTOKEN",
+            Uint128::from(amount),
+            "user1",
+        )
+        .encode(AttestationVersion::V1);
+        ExecuteMsg::Attest {
+            source_chain_id: "localgaia-1".to_string(),
+            transaction_hash: transaction_hash.to_string(),
+            ticker: "TESTTOKEN".to_string(),
+            amount: Uint128::from(amount),
+            destination_addr: "user1".to_string(),
+            signer_signatures: vec![SignerSignature {
+                signer: SignerReference::PublicKey {
+                    public_key_base64: public_key_base64(signed_by),
+                },
+                signature: sign_attestation(signed_by, attestation),
+            }],
+            token_signer_signatures: vec![],
+            signer_epoch: None,
+            attestation_version: None,
+            expires_at: None,
+        }
+    };
+
+    // A single signature is kept until more signers attest
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &attest_msg("TXHASH1", 1000, &signers[0]),
+        &[],
+    )
+    .unwrap();
+
+    let pending: Vec<PendingReceive> = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::PendingReceive {
+                transaction_hash: "TXHASH1".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].signers, vec!["signer1"]);
+    assert_eq!(pending[0].amount, Uint128::from(1000u64));
+    assert_eq!(
+        pending[0].expires_at,
+        app.block_info()
+            .time
+            .plus_seconds(DEFAULT_PENDING_RECEIVE_MAX_AGE_SECONDS)
+    );
+    assert!(app.wrap().query_all_balances("user1").unwrap().is_empty());
+
+    // A signer can't attest twice
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &attest_msg("TXHASH1", 1000, &signers[0]),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::DuplicateSignatures {}
+    );
+
+    // A signer attesting a different transfer doesn't block the others, the
+    // signatures of each transfer are collected separately
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &attest_msg("TXHASH1", 2000, &signers[2]),
+        &[],
+    )
+    .unwrap();
+
+    let pending: Vec<PendingReceive> = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::PendingReceive {
+                transaction_hash: "TXHASH1".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(pending.len(), 2);
+    assert_eq!(pending[1].signers, vec!["signer3"]);
+    assert_eq!(pending[1].amount, Uint128::from(2000u64));
+
+    // A signer can't attest two different transfers for the same transaction
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &attest_msg("TXHASH1", 2000, &signers[0]),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::DuplicateSignatures {}
+    );
+
+    // Invalid signatures are rejected right away
+    let mut invalid_msg = attest_msg("TXHASH1", 1000, &signers[1]);
+    if let ExecuteMsg::Attest {
+        signer_signatures, ..
+    } = &mut invalid_msg
+    {
+        signer_signatures[0].signature = sign_attestation(&signers[1], "other");
+    }
+    let err = app
+        .execute_contract(relayer.clone(), bridge_address.clone(), &invalid_msg, &[])
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidSignerSignature {
+            signer: public_key_base64(&signers[1])
+        }
+    );
+
+    // The signature crossing the threshold mints the tokens
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &attest_msg("TXHASH1", 1000, &signers[1]),
+        &[],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_all_balances("user1").unwrap();
+    assert_eq!(res, vec![Coin::new(1000, "factory/contract0/TESTTOKEN")]);
+
+    let response: QueryPendingReceivesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::PendingReceives {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(response.receives.is_empty());
+
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &attest_msg("TXHASH1", 1000, &signers[2]),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::TransactionAlreadyHandled {
+            transaction_hash: "TXHASH1".to_string()
+        }
+    );
+
+    // Only the owner can change the age of pending receives, within limits
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::UpdateConfig {
+                bridge_ibc_channel: None,
+                ibc_timeout_seconds: None,
+                signer_threshold: None,
+                signer_epoch_grace_seconds: None,
+                v1_attestations_enabled: None,
+                admin_action_delay_seconds: None,
+                pending_receive_max_age_seconds: Some(MAX_PENDING_RECEIVE_MAX_AGE_SECONDS + 1),
+                min_signer_bond: None,
+                unbonding_period_seconds: None,
+                treasury: None,
+                reward_fee_rate: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidConfiguration {
+            reason: format!(
+                "The pending receive age must be between 1 and {} seconds",
+                MAX_PENDING_RECEIVE_MAX_AGE_SECONDS
+            )
+        }
+    );
+
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::UpdateConfig {
+            bridge_ibc_channel: None,
+            ibc_timeout_seconds: None,
+            signer_threshold: None,
+            signer_epoch_grace_seconds: None,
+            v1_attestations_enabled: None,
+            admin_action_delay_seconds: None,
+            pending_receive_max_age_seconds: Some(60),
+            min_signer_bond: None,
+            unbonding_period_seconds: None,
+            treasury: None,
+            reward_fee_rate: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &attest_msg("TXHASH2", 1000, &signers[0]),
+        &[],
+    )
+    .unwrap();
+
+    // Once expired, the transaction collects signatures from scratch
+    app.update_block(|block| {
+        block.time = block.time.plus_seconds(60);
+    });
+
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &attest_msg("TXHASH2", 2000, &signers[1]),
+        &[],
+    )
+    .unwrap();
+
+    let response: QueryPendingReceivesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::PendingReceives {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(response.receives.len(), 1);
+    assert_eq!(response.receives[0].0, "TXHASH2");
+    assert_eq!(response.receives[0].1.len(), 1);
+    assert_eq!(response.receives[0].1[0].signers, vec!["signer2"]);
+    assert_eq!(response.receives[0].1[0].amount, Uint128::from(2000u64));
+
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &attest_msg("TXHASH2", 2000, &signers[2]),
+        &[],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_all_balances("user1").unwrap();
+    assert_eq!(res, vec![Coin::new(3000, "factory/contract0/TESTTOKEN")]);
+
+    let mut transaction_signers: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::TransactionSigners {
+                transaction_hash: "TXHASH2".to_string(),
+            },
+        )
+        .unwrap();
+    transaction_signers.sort();
+    assert_eq!(transaction_signers, vec!["signer2", "signer3"]);
+
+    // Collected signatures are counted by key, a signer suspended since no
+    // longer counts toward the threshold
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &attest_msg("TXHASH3", 1000, &signers[0]),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::UpdateSignerStatus {
+            public_key_base64: public_key_base64(&signers[0]),
+            status: SignerStatus::Suspended,
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &attest_msg("TXHASH3", 1000, &signers[1]),
+        &[],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_all_balances("user1").unwrap();
+    assert_eq!(res, vec![Coin::new(3000, "factory/contract0/TESTTOKEN")]);
+
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &attest_msg("TXHASH3", 1000, &signers[2]),
+        &[],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_all_balances("user1").unwrap();
+    assert_eq!(res, vec![Coin::new(4000, "factory/contract0/TESTTOKEN")]);
+
+    let mut transaction_signers: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::TransactionSigners {
+                transaction_hash: "TXHASH3".to_string(),
+            },
+        )
+        .unwrap();
+    transaction_signers.sort();
+    assert_eq!(transaction_signers, vec!["signer2", "signer3"]);
+}
+
+#[test]
+fn test_signer_bonds() {
+    let owner = Addr::unchecked("owner");
+    let relayer = Addr::unchecked("relayer");
+    let treasury = Addr::unchecked("treasury");
+    let mut app = mock_app(&owner, vec![Coin::new(10_000, FEE_DENOM)]);
+    let contract_code = app.store_code(bridge_contract());
+
+    let bridge_address = app
+        .instantiate_contract(
+            contract_code,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: owner.to_string(),
+                ibc_timeout_seconds: 10,
+                bridge_ibc_channel: "channel-0".to_string(),
+                bridge_chain_id: "localgaia-1".to_string(),
+            },
+            &[],
+            "Asteroid Bridge",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::UpdateConfig {
+            bridge_ibc_channel: None,
+            ibc_timeout_seconds: None,
+            signer_threshold: None,
+            signer_epoch_grace_seconds: None,
+            v1_attestations_enabled: None,
+            admin_action_delay_seconds: None,
+            pending_receive_max_age_seconds: None,
+            min_signer_bond: Some(Uint128::from(1000u64)),
+            unbonding_period_seconds: Some(60),
+            treasury: Some(treasury.to_string()),
+            reward_fee_rate: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let signers = [signing_key(1), signing_key(2), signing_key(3)];
+    let add_signer_msg = |index: usize| ExecuteMsg::AddSigner {
+        name: format!("signer{}", index + 1),
+        public_key_base64: public_key_base64(&signers[index]),
+        key_type: None,
+        weight: None,
+        contact_url: None,
+    };
+
+    // Signers must be bonded before they are added
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &add_signer_msg(0),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InsufficientSignerBond {
+            name: "signer1".to_string(),
+            required: Uint128::from(1000u64)
+        }
+    );
+
+    // A bond can only be posted by the owner registered for the signer
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::BondSigner {
+                name: "signer1".to_string(),
+            },
+            &[Coin::new(1000, FEE_DENOM)],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::SignerBondNotFound {
+            name: "signer1".to_string()
+        }
+    );
+
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::SetSignerBondOwner {
+                name: "signer1".to_string(),
+                owner: relayer.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    );
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::BondSigner {
+                name: "signer1".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidBondFunds {}
+    );
+
+    for index in 0..signers.len() {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::SetSignerBondOwner {
+                name: format!("signer{}", index + 1),
+                owner: owner.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::BondSigner {
+                name: format!("signer{}", index + 1),
+            },
+            &[Coin::new(1000, FEE_DENOM)],
+        )
+        .unwrap();
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &add_signer_msg(index),
+            &[],
+        )
+        .unwrap();
+    }
+
+    // Only the owner of the bond can add to it
+    app.send_tokens(owner.clone(), relayer.clone(), &[Coin::new(100, FEE_DENOM)])
+        .unwrap();
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::BondSigner {
+                name: "signer1".to_string(),
+            },
+            &[Coin::new(100, FEE_DENOM)],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    );
+
+    // The owner of a bond holding funds can't be changed
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::SetSignerBondOwner {
+                name: "signer1".to_string(),
+                owner: relayer.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidConfiguration {
+            reason: "The bond of the signer still holds funds".to_string()
+        }
+    );
+
+    // Signers in the signer set must keep the minimum bond
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::UnbondSigner {
+                name: "signer1".to_string(),
+                amount: Uint128::from(1u64),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InsufficientSignerBond {
+            name: "signer1".to_string(),
+            required: Uint128::from(1000u64)
+        }
+    );
+
+    // Funds above the minimum are released after the unbonding period
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::BondSigner {
+            name: "signer1".to_string(),
+        },
+        &[Coin::new(500, FEE_DENOM)],
+    )
+    .unwrap();
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::UnbondSigner {
+            name: "signer1".to_string(),
+            amount: Uint128::from(500u64),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let bond: SignerBond = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::SignerBond {
+                name: "signer1".to_string(),
+            },
+        )
+        .unwrap();
+    let release_at = app.block_info().time.plus_seconds(60);
+    assert_eq!(
+        bond,
+        SignerBond {
+            owner: owner.clone(),
+            amount: Uint128::from(1000u64),
+            unbonding_amount: Uint128::from(500u64),
+            unbonding_release_at: Some(release_at),
+        }
+    );
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::WithdrawSignerBond {
+                name: "signer1".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::UnbondingNotFinished { release_at }
+    );
+
+    app.update_block(|block| {
+        block.time = block.time.plus_seconds(60);
+    });
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::WithdrawSignerBond {
+            name: "signer1".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let balance = app.wrap().query_balance(&owner, FEE_DENOM).unwrap();
+    assert_eq!(balance.amount, Uint128::from(6900u64));
+
+    // The evidence is two different transfers signed for the same transaction
+    let env = contract_env(&app, &bridge_address);
+    let signed_receive = |signer: &SigningKey, amount: u64| {
+        let attestation = Attestation::receive(
+            &env,
+            "localgaia-1",
+            "TXHASH1",
+            "TESTTOKEN",
+            Uint128::from(amount),
+            "user1",
+        )
+        .encode(AttestationVersion::V1);
+        SignedReceive {
+            ticker: "TESTTOKEN".to_string(),
+            amount: Uint128::from(amount),
+            destination_addr: "user1".to_string(),
+            signer_epoch: None,
+            expires_at: None,
+            reward_fee_rate: None,
+            attestation_version: AttestationVersion::V1,
+            signature: sign_attestation(signer, attestation),
+        }
+    };
+    let evidence = SlashEvidence {
+        source_chain_id: "localgaia-1".to_string(),
+        transaction_hash: "TXHASH1".to_string(),
+        first: signed_receive(&signers[2], 1000),
+        second: signed_receive(&signers[2], 2000),
+    };
+    let slash_msg = |evidence: &SlashEvidence, signer_signatures: Option<Vec<SignerSignature>>| {
+        ExecuteMsg::SlashSigner {
+            name: "signer3".to_string(),
+            evidence: evidence.clone(),
+            signer_signatures,
+        }
+    };
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &slash_msg(
+                &SlashEvidence {
+                    second: signed_receive(&signers[2], 1000),
+                    ..evidence.clone()
+                },
+                None,
+            ),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidSlashEvidence {
+            reason: "The signed transfers don't conflict".to_string()
+        }
+    );
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &slash_msg(
+                &SlashEvidence {
+                    second: signed_receive(&signers[0], 2000),
+                    ..evidence.clone()
+                },
+                None,
+            ),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidSlashEvidence {
+            reason: "The signature isn't valid for the signer 'signer3'".to_string()
+        }
+    );
+
+    // Anyone else than the owner needs the approval of the signers
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &slash_msg(&evidence, None),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::ThresholdNotMet {}
+    );
+
+    let attestation =
+        Attestation::slash_signer(&env, "signer3", "TXHASH1").encode(AttestationVersion::V2);
+    let approvals = signers[..2]
+        .iter()
+        .map(|signer| SignerSignature {
+            signer: SignerReference::PublicKey {
+                public_key_base64: public_key_base64(signer),
+            },
+            signature: sign_attestation(signer, &attestation),
+        })
+        .collect::<Vec<_>>();
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &slash_msg(&evidence, Some(approvals)),
+        &[],
+    )
+    .unwrap();
+
+    // The bond went to the treasury and the signer is suspended
+    let balance = app.wrap().query_balance(&treasury, FEE_DENOM).unwrap();
+    assert_eq!(balance.amount, Uint128::from(1000u64));
+
+    let bond: SignerBond = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::SignerBond {
+                name: "signer3".to_string(),
+            },
+        )
+        .unwrap();
+    assert!(bond.amount.is_zero());
+
+    let response: QuerySignersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Signers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let (_, signer) = response
+        .signers
+        .iter()
+        .find(|(key, _)| key == &public_key_base64(&signers[2]))
+        .unwrap();
+    assert_eq!(signer.status, SignerStatus::Suspended);
+
+    // The same evidence can't be used twice
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &slash_msg(&evidence, None),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidSlashEvidence {
+            reason: "The evidence has already been used".to_string()
+        }
+    );
+
+    // Rotating the key doesn't clear a signer of what the old key signed
+    let new_key = signing_key(5);
+    let attestation = Attestation::rotate_signer_key(
+        &env,
+        &public_key_base64(&signers[1]),
+        &public_key_base64(&new_key),
+        KeyType::Ed25519,
+        0,
+    )
+    .encode(AttestationVersion::V2);
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::RotateSignerKey {
+            old_public_key_base64: public_key_base64(&signers[1]),
+            new_public_key_base64: public_key_base64(&new_key),
+            new_key_type: None,
+            nonce: 0,
+            signature: sign_attestation(&signers[1], attestation),
+            overlap_seconds: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The slash goes through even though the last signer can't meet the
+    // threshold alone
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::SlashSigner {
+            name: "signer2".to_string(),
+            evidence: SlashEvidence {
+                first: signed_receive(&signers[1], 1000),
+                second: signed_receive(&signers[1], 2000),
+                ..evidence.clone()
+            },
+            signer_signatures: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let balance = app.wrap().query_balance(&treasury, FEE_DENOM).unwrap();
+    assert_eq!(balance.amount, Uint128::from(2000u64));
+
+    let response: QuerySignersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Signers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let (_, signer) = response
+        .signers
+        .iter()
+        .find(|(key, _)| key == &public_key_base64(&new_key))
+        .unwrap();
+    assert_eq!(signer.status, SignerStatus::Suspended);
+    assert_eq!(response.total_weight, 1);
+}
+
+#[test]
+fn test_signer_rewards() {
+    let owner = Addr::unchecked("owner");
+    let relayer = Addr::unchecked("relayer");
+    let mut app = mock_app(&owner, vec![Coin::new(10_000, FEE_DENOM)]);
+    let contract_code = app.store_code(bridge_contract());
+
+    let bridge_address = app
+        .instantiate_contract(
+            contract_code,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: owner.to_string(),
+                ibc_timeout_seconds: 10,
+                bridge_ibc_channel: "channel-0".to_string(),
+                bridge_chain_id: "localgaia-1".to_string(),
+            },
+            &[],
+            "Asteroid Bridge",
+            None,
+        )
+        .unwrap();
+
+    let signers = [signing_key(1), signing_key(2), signing_key(3)];
+    for (index, signer) in signers.iter().enumerate() {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::AddSigner {
+                name: format!("signer{}", index + 1),
+                public_key_base64: public_key_base64(signer),
+                key_type: None,
+                weight: None,
+                contact_url: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let env = contract_env(&app, &bridge_address);
+    let token = TokenMetadata {
+        ticker: "TESTTOKEN".to_string(),
+        name: "TestToken".to_string(),
+        image_url: "https://example.com".to_string(),
+        decimals: 6,
+    };
+    let attestation =
+        Attestation::link_token(&env, "localgaia-1", &token).encode(AttestationVersion::V1);
+    app.execute_contract(
+        relayer.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::LinkToken {
+            source_chain_id: "localgaia-1".to_string(),
+            token: token.clone(),
+            signatures: vec![
+                sign_attestation(&signers[0], &attestation),
+                sign_attestation(&signers[1], &attestation),
+            ],
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let update_config_msg = |reward_fee_rate| ExecuteMsg::UpdateConfig {
+        bridge_ibc_channel: None,
+        ibc_timeout_seconds: None,
+        signer_threshold: None,
+        signer_epoch_grace_seconds: None,
+        v1_attestations_enabled: None,
+        admin_action_delay_seconds: None,
+        pending_receive_max_age_seconds: None,
+        min_signer_bond: None,
+        unbonding_period_seconds: None,
+        treasury: None,
+        reward_fee_rate: Some(reward_fee_rate),
+    };
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &update_config_msg(Decimal::percent(11)),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidConfiguration {
+            reason: format!(
+                "The reward fee rate can't be more than {}",
+                MAX_REWARD_FEE_RATE
+            )
+        }
+    );
+
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &update_config_msg(Decimal::percent(10)),
+        &[],
+    )
+    .unwrap();
+
+    // The reward fee rate is signed along with the transfer in the v2 encoding
+    let attest_msg = |transaction_hash: &str,
+                      signed_by: &SigningKey,
+                      attestation_version: AttestationVersion,
+                      reward_fee_rate: Option<Decimal>| {
+        let attestation = Attestation::receive(
+            &env,
+            "localgaia-1",
+            transaction_hash,
+            "TESTTOKEN",
+            Uint128::from(1010u64),
+            "user1",
+        )
+        .with_optional("reward_fee_rate", reward_fee_rate)
+        .encode(attestation_version);
+        ExecuteMsg::Attest {
+            source_chain_id: "localgaia-1".to_string(),
+            transaction_hash: transaction_hash.to_string(),
+            ticker: "TESTTOKEN".to_string(),
+            amount: Uint128::from(1010u64),
+            destination_addr: "user1".to_string(),
+            signer_signatures: vec![SignerSignature {
+                signer: SignerReference::PublicKey {
+                    public_key_base64: public_key_base64(signed_by),
+                },
+                signature: sign_attestation(signed_by, attestation),
+            }],
+            token_signer_signatures: vec![],
+            signer_epoch: None,
+            attestation_version: Some(attestation_version),
+            expires_at: None,
+        }
+    };
+    for signer in &signers[..2] {
+        app.execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &attest_msg(
+                "TXHASH1",
+                signer,
+                AttestationVersion::V2,
+                Some(Decimal::percent(10)),
+            ),
+            &[],
+        )
+        .unwrap();
+    }
+
+    let rewards_of = |app: &NeutronApp, name: &str| {
+        app.wrap()
+            .query_wasm_smart::<QueryPendingRewardsResponse>(
+                &bridge_address,
+                &QueryMsg::PendingRewards {
+                    name: name.to_string(),
+                },
+            )
+            .unwrap()
+            .rewards
+    };
+
+    // Without a bond there is nobody to pay, so no fee is taken
+    let res = app.wrap().query_all_balances("user1").unwrap();
+    assert_eq!(res, vec![Coin::new(1010, "factory/contract0/TESTTOKEN")]);
+    assert!(rewards_of(&app, "signer1").is_empty());
+    assert!(rewards_of(&app, "signer2").is_empty());
+
+    for name in ["signer1", "signer2"] {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::SetSignerBondOwner {
+                name: name.to_string(),
+                owner: owner.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::BondSigner {
+                name: name.to_string(),
+            },
+            &[Coin::new(100, FEE_DENOM)],
+        )
+        .unwrap();
+    }
+
+    // A v2 signature that doesn't carry the reward fee rate isn't accepted
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &attest_msg("TXHASH2", &signers[0], AttestationVersion::V2, None),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidSignerSignature {
+            signer: public_key_base64(&signers[0])
+        }
+    );
+
+    for signer in &signers[..2] {
+        app.execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &attest_msg(
+                "TXHASH2",
+                signer,
+                AttestationVersion::V2,
+                Some(Decimal::percent(10)),
+            ),
+            &[],
+        )
+        .unwrap();
+    }
+
+    // The user receives the transfer minus the rewards of the bonded signers
+    let res = app.wrap().query_all_balances("user1").unwrap();
+    assert_eq!(res, vec![Coin::new(1919, "factory/contract0/TESTTOKEN")]);
+
+    // The remainder of the split goes to the first signer
+    assert_eq!(
+        rewards_of(&app, "signer1"),
+        vec![Coin::new(51, "factory/contract0/TESTTOKEN")]
+    );
+    assert_eq!(
+        rewards_of(&app, "signer2"),
+        vec![Coin::new(50, "factory/contract0/TESTTOKEN")]
+    );
+    assert!(rewards_of(&app, "signer3").is_empty());
+
+    // The signers of a v1 transfer never agreed to a fee, so the user receives
+    // the attested amount in full and no rewards are paid
+    for signer in &signers[..2] {
+        app.execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &attest_msg("TXHASH3", signer, AttestationVersion::V1, None),
+            &[],
+        )
+        .unwrap();
+    }
+
+    let res = app.wrap().query_all_balances("user1").unwrap();
+    assert_eq!(res, vec![Coin::new(2929, "factory/contract0/TESTTOKEN")]);
+    assert_eq!(
+        rewards_of(&app, "signer1"),
+        vec![Coin::new(51, "factory/contract0/TESTTOKEN")]
+    );
+    assert_eq!(
+        rewards_of(&app, "signer2"),
+        vec![Coin::new(50, "factory/contract0/TESTTOKEN")]
+    );
+
+    // Rewards are paid to the owner of the bond of the signer
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::ClaimRewards {
+                name: "signer3".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::SignerBondNotFound {
+            name: "signer3".to_string()
+        }
+    );
+
+    let err = app
+        .execute_contract(
+            relayer.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::ClaimRewards {
+                name: "signer1".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    );
+
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::ClaimRewards {
+            name: "signer1".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res = app
+        .wrap()
+        .query_balance(&owner, "factory/contract0/TESTTOKEN")
+        .unwrap();
+    assert_eq!(res.amount, Uint128::from(51u64));
+    assert!(rewards_of(&app, "signer1").is_empty());
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::ClaimRewards {
+                name: "signer1".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::NoPendingRewards {
+            name: "signer1".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_verify_attestation() {
+    let owner = Addr::unchecked("owner");
+    let mut app = mock_app(&owner, vec![]);
+    let contract_code = app.store_code(bridge_contract());
+
+    let bridge_address = app
+        .instantiate_contract(
+            contract_code,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: owner.to_string(),
+                ibc_timeout_seconds: 10,
+                bridge_ibc_channel: "channel-0".to_string(),
+                bridge_chain_id: "localgaia-1".to_string(),
+            },
+            &[],
+            "Asteroid Bridge",
+            None,
+        )
+        .unwrap();
+
+    let signers = [signing_key(1), signing_key(2), signing_key(3)];
+    for (index, signer) in signers.iter().enumerate() {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::AddSigner {
+                name: format!("signer{}", index + 1),
+                public_key_base64: public_key_base64(signer),
+                key_type: None,
+                weight: None,
+                contact_url: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let env = contract_env(&app, &bridge_address);
+    let token = TokenMetadata {
+        ticker: "TESTTOKEN".to_string(),
+        name: "TestToken".to_string(),
+        image_url: "https://example.com".to_string(),
+        decimals: 6,
+    };
+    let attestation =
+        Attestation::link_token(&env, "localgaia-1", &token).encode(AttestationVersion::V1);
+    let other_attestation =
+        Attestation::link_token(&env, "localgaia-2", &token).encode(AttestationVersion::V1);
+    let verify = |signatures: Vec<String>, signer_signatures: Option<Vec<SignerSignature>>| {
+        app.wrap()
+            .query_wasm_smart::<QueryVerifyAttestationResponse>(
+                &bridge_address,
+                &QueryMsg::VerifyAttestation {
+                    attestation: AttestationRequest::LinkToken {
+                        source_chain_id: "localgaia-1".to_string(),
+                        token: token.clone(),
+                    },
+                    signatures,
+                    signer_signatures,
+                    adr036_signatures: None,
+                    signer_epoch: None,
+                    attestation_version: None,
+                    token_signatures: None,
+                },
+            )
+            .unwrap()
+    };
+
+    // A signature over another message doesn't match any signer
+    let response = verify(
+        vec![
+            sign_attestation(&signers[0], &attestation),
+            sign_attestation(&signers[1], &other_attestation),
+        ],
+        None,
+    );
+    assert_eq!(
+        response,
+        QueryVerifyAttestationResponse {
+            message: Binary::from(attestation.clone()),
+            signatures: vec![
+                SignatureMatch {
+                    kind: SignatureKind::Signature,
+                    index: 0,
+                    signer: Some("signer1".to_string()),
+                },
+                SignatureMatch {
+                    kind: SignatureKind::Signature,
+                    index: 1,
+                    signer: None,
+                },
+            ],
+            threshold: 2,
+            passed: false,
+            signers: vec![],
+            error: Some(ContractError::ThresholdNotMet {}.to_string()),
+        }
+    );
+
+    let response = verify(
+        vec![sign_attestation(&signers[0], &attestation)],
+        Some(vec![SignerSignature {
+            signer: SignerReference::PublicKey {
+                public_key_base64: public_key_base64(&signers[2]),
+            },
+            signature: sign_attestation(&signers[2], &attestation),
+        }]),
+    );
+    assert!(response.passed);
+    assert_eq!(response.signers, vec!["signer3", "signer1"]);
+    assert_eq!(
+        response.signatures[1],
+        SignatureMatch {
+            kind: SignatureKind::SignerSignature,
+            index: 0,
+            signer: Some("signer3".to_string()),
+        }
+    );
+
+    // The message of a receive includes its expiry
+    let expires_at = app.block_info().time.plus_seconds(60);
+    let response: QueryVerifyAttestationResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::VerifyAttestation {
+                attestation: AttestationRequest::Receive {
+                    source_chain_id: "localgaia-1".to_string(),
+                    transaction_hash: "TXHASH1".to_string(),
+                    ticker: "TESTTOKEN".to_string(),
+                    amount: Uint128::from(1000u64),
+                    destination_addr: "user1".to_string(),
+                    expires_at: Some(expires_at),
+                },
+                signatures: vec![],
+                signer_signatures: None,
+                adr036_signatures: None,
+                signer_epoch: None,
+                attestation_version: Some(AttestationVersion::V2),
+                token_signatures: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        response.message,
+        Binary::from(
+            Attestation::receive(
+                &env,
+                "localgaia-1",
+                "TXHASH1",
+                "TESTTOKEN",
+                Uint128::from(1000u64),
+                "user1",
+            )
+            .with_optional("expires_at", Some(expires_at.seconds()))
+            .encode(AttestationVersion::V2)
+        )
+    );
+    assert!(!response.passed);
+    // The token of a receive is checked like Receive checks it
+    assert_eq!(
+        response.error,
+        Some(
+            ContractError::TokenDoesNotExist {
+                ticker: "TESTTOKEN".to_string()
+            }
+            .to_string()
+        )
+    );
+
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::LinkToken {
+            source_chain_id: "localgaia-1".to_string(),
+            token: token.clone(),
+            signatures: vec![
+                sign_attestation(&signers[0], &attestation),
+                sign_attestation(&signers[1], &attestation),
+            ],
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
+        },
+        &[],
+    )
+    .unwrap();
+    let token_signers = [signing_key(4), signing_key(5)];
+    for (index, token_signer) in token_signers.iter().enumerate() {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::AddTokenSigner {
+                ticker: "TESTTOKEN".to_string(),
+                public_key_base64: public_key_base64(token_signer),
+                name: format!("issuer{}", index + 1),
+                key_type: None,
+                weight: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let verify_receive = |expires_at: Option<Timestamp>, token_signed_by: &[SigningKey]| {
+        let attestation = Attestation::receive(
+            &env,
+            "localgaia-1",
+            "TXHASH1",
+            "TESTTOKEN",
+            Uint128::from(1000u64),
+            "user1",
+        )
+        .with_optional(
+            "expires_at",
+            expires_at.map(|expires_at| expires_at.seconds()),
+        )
+        .encode(AttestationVersion::V2);
+        app.wrap()
+            .query_wasm_smart::<QueryVerifyAttestationResponse>(
+                &bridge_address,
+                &QueryMsg::VerifyAttestation {
+                    attestation: AttestationRequest::Receive {
+                        source_chain_id: "localgaia-1".to_string(),
+                        transaction_hash: "TXHASH1".to_string(),
+                        ticker: "TESTTOKEN".to_string(),
+                        amount: Uint128::from(1000u64),
+                        destination_addr: "user1".to_string(),
+                        expires_at,
+                    },
+                    signatures: signers[..2]
+                        .iter()
+                        .map(|signer| sign_attestation(signer, &attestation))
+                        .collect(),
+                    signer_signatures: None,
+                    adr036_signatures: None,
+                    signer_epoch: None,
+                    attestation_version: Some(AttestationVersion::V2),
+                    token_signatures: Some(
+                        token_signed_by
+                            .iter()
+                            .map(|signer| sign_attestation(signer, &attestation))
+                            .collect(),
+                    ),
+                },
+            )
+            .unwrap()
+    };
+
+    // A token with its own signers also needs their quorum
+    let response = verify_receive(None, &token_signers[..1]);
+    assert!(!response.passed);
+    assert_eq!(
+        response.error,
+        Some(
+            ContractError::TokenThresholdNotMet {
+                ticker: "TESTTOKEN".to_string()
+            }
+            .to_string()
+        )
+    );
+    let response = verify_receive(None, &token_signers[..]);
+    assert!(response.passed);
+    assert_eq!(response.signers.len(), 2);
+
+    // An expired attestation doesn't pass
+    let expires_at = app.block_info().time;
+    let response = verify_receive(Some(expires_at), &token_signers[..]);
+    assert!(!response.passed);
+    assert_eq!(
+        response.error,
+        Some(ContractError::AttestationExpired { expires_at }.to_string())
+    );
+}
+
+#[test]
+fn test_update_token_metadata() {
+    let owner = Addr::unchecked("owner");
+    let not_owner = Addr::unchecked("not_owner");
+    let mut app = mock_app(&owner, vec![]);
+    let contract_code = app.store_code(bridge_contract());
+
+    let bridge_address = app
+        .instantiate_contract(
+            contract_code,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: owner.to_string(),
+                ibc_timeout_seconds: 10,
+                bridge_ibc_channel: "channel-0".to_string(),
+                bridge_chain_id: "localgaia-1".to_string(),
+            },
+            &[],
+            "Asteroid Bridge",
+            None,
+        )
+        .unwrap();
+
+    let env = contract_env(&app, &bridge_address);
+
+    for seed in 1..=2u8 {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::AddSigner {
+                name: format!("signer{}", seed),
+                public_key_base64: public_key_base64(&signing_key(seed)),
+                key_type: None,
+                weight: None,
+                contact_url: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let token = TokenMetadata {
+        ticker: "TESTTOKEN".to_string(),
+        name: "TestToken".to_string(),
+        image_url: "https://example.com".to_string(),
+        decimals: 6,
+    };
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::LinkToken {
+            source_chain_id: "localgaia-1".to_string(),
+            token: token.clone(),
+            signatures: vec![
+                link_token_signature(&env, &signing_key(1)),
+                link_token_signature(&env, &signing_key(2)),
+            ],
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The metadata is stored when the denom is created
+    let response: TokenMetadata = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::TokenMetadata {
+                ticker: "TESTTOKEN".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(response, token);
+
+    let updated = TokenMetadata {
+        name: "Test Token".to_string(),
+        image_url: "https://example.com/token.png".to_string(),
+        ..token.clone()
+    };
+    let attestation = Attestation::update_token_metadata(&env, "localgaia-1", &updated, 0)
+        .encode(AttestationVersion::V1);
+    let signatures = vec![
+        sign_attestation(&signing_key(1), &attestation),
+        sign_attestation(&signing_key(2), &attestation),
+    ];
+    let update_msg =
+        |token: TokenMetadata, signatures: Vec<String>| ExecuteMsg::UpdateTokenMetadata {
+            source_chain_id: "localgaia-1".to_string(),
+            token,
+            signatures,
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
+        };
+
+    // Signatures over other metadata don't count
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &update_msg(
+                TokenMetadata {
+                    name: "Fake Token".to_string(),
+                    ..updated.clone()
+                },
+                signatures.clone(),
+            ),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::ThresholdNotMet {}
+    );
+
+    // Anyone can relay a signed update
+    app.execute_contract(
+        not_owner.clone(),
+        bridge_address.clone(),
+        &update_msg(updated.clone(), signatures.clone()),
+        &[],
+    )
+    .unwrap();
+
+    let response: TokenMetadata = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::TokenMetadata {
+                ticker: "TESTTOKEN".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(response, updated);
+
+    let nonce: u64 = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::TokenMetadataNonce {
+                ticker: "TESTTOKEN".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(nonce, 1);
+
+    // The signatures can't be replayed once the nonce moved on
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &update_msg(updated.clone(), signatures),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::ThresholdNotMet {}
+    );
+
+    // The decimals can't change
+    let attestation = Attestation::update_token_metadata(
+        &env,
+        "localgaia-1",
+        &TokenMetadata {
+            decimals: 8,
+            ..updated.clone()
+        },
+        1,
+    )
+    .encode(AttestationVersion::V1);
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &update_msg(
+                TokenMetadata {
+                    decimals: 8,
+                    ..updated.clone()
+                },
+                vec![
+                    sign_attestation(&signing_key(1), &attestation),
+                    sign_attestation(&signing_key(2), &attestation),
+                ],
+            ),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidConfiguration {
+            reason: "The decimals of a linked token can't change".to_string()
+        }
+    );
+
+    // Without signatures only the owner can override the metadata
+    let err = app
+        .execute_contract(
+            not_owner.clone(),
+            bridge_address.clone(),
+            &update_msg(token.clone(), vec![]),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    );
+
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &update_msg(token.clone(), vec![]),
+        &[],
+    )
+    .unwrap();
+
+    let response: TokenMetadata = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::TokenMetadata {
+                ticker: "TESTTOKEN".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(response, token);
+
+    // Unknown tokens can't be updated
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &update_msg(
+                TokenMetadata {
+                    ticker: "NOT_TESTTOKEN".to_string(),
+                    ..token
+                },
+                vec![],
+            ),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::TokenDoesNotExist {
+            ticker: "NOT_TESTTOKEN".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_link_tokens() {
+    let owner = Addr::unchecked("owner");
+    let mut app = mock_app(&owner, vec![]);
+    let contract_code = app.store_code(bridge_contract());
+
+    let bridge_address = app
+        .instantiate_contract(
+            contract_code,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: owner.to_string(),
+                ibc_timeout_seconds: 10,
+                bridge_ibc_channel: "channel-0".to_string(),
+                bridge_chain_id: "localgaia-1".to_string(),
+            },
+            &[],
+            "Asteroid Bridge",
+            None,
+        )
+        .unwrap();
+
+    let env = contract_env(&app, &bridge_address);
+
+    for seed in 1..=2u8 {
+        app.execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::AddSigner {
+                name: format!("signer{}", seed),
+                public_key_base64: public_key_base64(&signing_key(seed)),
+                key_type: None,
+                weight: None,
+                contact_url: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let token_link = |ticker: &str| {
+        let token = TokenMetadata {
+            ticker: ticker.to_string(),
+            name: format!("{} Token", ticker),
+            image_url: format!("https://example.com/{}.png", ticker),
+            decimals: 6,
+        };
+        let attestation =
+            Attestation::link_token(&env, "localgaia-1", &token).encode(AttestationVersion::V1);
+        TokenLink {
+            source_chain_id: "localgaia-1".to_string(),
+            token,
+            signatures: vec![
+                sign_attestation(&signing_key(1), &attestation),
+                sign_attestation(&signing_key(2), &attestation),
+            ],
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
+        }
+    };
+
+    // An empty batch is rejected
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::LinkTokens { links: vec![] },
+            &[],
+        )
+        .unwrap_err();
     assert_eq!(
         err.downcast::<ContractError>().unwrap(),
         ContractError::InvalidConfiguration {
-            reason: "Key to remove doesn't exist".to_string()
+            reason: "At least one token must be linked".to_string()
         }
     );
 
-    // Remove an unknown signer
+    // The same token can't be linked twice in a batch
     let err = app
         .execute_contract(
             owner.clone(),
             bridge_address.clone(),
-            &ExecuteMsg::RemoveSigner {
-                public_key_base64: "invalid_key".to_string(),
+            &ExecuteMsg::LinkTokens {
+                links: vec![token_link("ALPHA"), token_link("ALPHA")],
             },
             &[],
         )
         .unwrap_err();
-
     assert_eq!(
         err.downcast::<ContractError>().unwrap(),
-        ContractError::InvalidConfiguration {
-            reason: "Key could not be decoded".to_string()
+        ContractError::TokenAlreadyExists {
+            ticker: "ALPHA".to_string()
         }
     );
 
-    // Attempt to remove a signer without being the owner
+    // One invalid link fails the whole batch
+    let mut invalid_link = token_link("BETA");
+    invalid_link.token.decimals = 8;
     let err = app
         .execute_contract(
-            not_owner.clone(),
+            owner.clone(),
             bridge_address.clone(),
-            &ExecuteMsg::RemoveSigner {
-                public_key_base64: "aW52YWxpZC1zaWduZXI=".to_string(),
+            &ExecuteMsg::LinkTokens {
+                links: vec![token_link("ALPHA"), invalid_link],
             },
             &[],
         )
         .unwrap_err();
-
     assert_eq!(
         err.downcast::<ContractError>().unwrap(),
-        ContractError::Unauthorized {}
+        ContractError::ThresholdNotMet {}
     );
 
-    // Remove a known signer
     app.execute_contract(
         owner.clone(),
         bridge_address.clone(),
-        &ExecuteMsg::RemoveSigner {
-            public_key_base64: VALID_SIGNER_1.to_string(),
+        &ExecuteMsg::LinkTokens {
+            links: vec![token_link("ALPHA"), token_link("BETA")],
         },
         &[],
     )
     .unwrap();
 
-    // Ensure signer was removed
-    let response: QuerySignersResponse = app
+    // Several LinkToken messages in one transaction
+    let link_msg = |link: TokenLink| {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: bridge_address.to_string(),
+            msg: to_json_binary(&ExecuteMsg::LinkToken {
+                source_chain_id: link.source_chain_id,
+                token: link.token,
+                signatures: link.signatures,
+                signer_signatures: link.signer_signatures,
+                adr036_signatures: link.adr036_signatures,
+                signer_epoch: link.signer_epoch,
+                attestation_version: link.attestation_version,
+            })
+            .unwrap(),
+            funds: vec![],
+        })
+    };
+    app.execute_multi(
+        owner.clone(),
+        vec![link_msg(token_link("DELTA")), link_msg(token_link("GAMMA"))],
+    )
+    .unwrap();
+
+    let response: QueryTokensResponse = app
         .wrap()
-        .query_wasm_smart(&bridge_address, &QueryMsg::Signers {})
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Tokens {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
-
-    assert_eq!(response.signers.len(), 0);
+    assert_eq!(response.tokens, vec!["ALPHA", "BETA", "DELTA", "GAMMA"]);
+
+    // Every token got its own denom and metadata
+    for ticker in ["ALPHA", "BETA", "DELTA", "GAMMA"] {
+        let token: TokenInfo = app
+            .wrap()
+            .query_wasm_smart(
+                &bridge_address,
+                &QueryMsg::Token {
+                    ticker: ticker.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(token.denom, format!("factory/contract0/{}", ticker));
+        assert_eq!(token.metadata, token_link(ticker).token);
+
+        let signers: Vec<String> = app
+            .wrap()
+            .query_wasm_smart(
+                &bridge_address,
+                &QueryMsg::LinkTokenSigners {
+                    ticker: ticker.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(signers, vec!["signer1", "signer2"]);
+    }
 }
 
 #[test]
-fn test_update_config() {
+fn test_failed_token_links() {
     let owner = Addr::unchecked("owner");
-    let not_owner = Addr::unchecked("not_owner");
     let mut app = mock_app(&owner, vec![]);
     let contract_code = app.store_code(bridge_contract());
 
@@ -463,140 +6926,216 @@ fn test_update_config() {
         )
         .unwrap();
 
-    // Attempt to update config without being the owner
-    let err = app
-        .execute_contract(
-            not_owner.clone(),
+    let env = contract_env(&app, &bridge_address);
+
+    for seed in 1..=2u8 {
+        app.execute_contract(
+            owner.clone(),
             bridge_address.clone(),
-            &ExecuteMsg::UpdateConfig {
-                bridge_ibc_channel: None,
-                ibc_timeout_seconds: None,
+            &ExecuteMsg::AddSigner {
+                name: format!("signer{}", seed),
+                public_key_base64: public_key_base64(&signing_key(seed)),
+                key_type: None,
+                weight: None,
+                contact_url: None,
             },
             &[],
         )
-        .unwrap_err();
-
-    assert_eq!(
-        err.downcast::<ContractError>().unwrap(),
-        ContractError::Unauthorized {}
-    );
+        .unwrap();
+    }
+
+    let link_token_msg = |ticker: &str| {
+        let token = TokenMetadata {
+            ticker: ticker.to_string(),
+            name: format!("{} Token", ticker),
+            image_url: format!("https://example.com/{}.png", ticker),
+            decimals: 6,
+        };
+        let attestation =
+            Attestation::link_token(&env, "localgaia-1", &token).encode(AttestationVersion::V1);
+        ExecuteMsg::LinkToken {
+            source_chain_id: "localgaia-1".to_string(),
+            token,
+            signatures: vec![
+                sign_attestation(&signing_key(1), &attestation),
+                sign_attestation(&signing_key(2), &attestation),
+            ],
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
+        }
+    };
+    let failed_links = |app: &NeutronApp| {
+        app.wrap()
+            .query_wasm_smart::<QueryFailedTokenLinksResponse>(
+                &bridge_address,
+                &QueryMsg::FailedTokenLinks {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap()
+            .links
+    };
+
+    // Denoms created by the bridge before, one of them handed to another admin
+    for subdenom in ["ADOPTED", "TAKEN"] {
+        app.execute(
+            bridge_address.clone(),
+            CosmosMsg::Stargate {
+                type_url: MsgCreateDenom::TYPE_URL.to_string(),
+                value: MsgCreateDenom {
+                    sender: bridge_address.to_string(),
+                    subdenom: subdenom.to_string(),
+                }
+                .into(),
+            },
+        )
+        .unwrap();
+    }
+    let change_admin = |app: &mut NeutronApp, sender: &str, new_admin: &str| {
+        app.execute(
+            Addr::unchecked(sender),
+            CosmosMsg::Stargate {
+                type_url: MsgChangeAdmin::TYPE_URL.to_string(),
+                value: MsgChangeAdmin {
+                    sender: sender.to_string(),
+                    denom: "factory/contract0/TAKEN".to_string(),
+                    new_admin: new_admin.to_string(),
+                }
+                .into(),
+            },
+        )
+        .unwrap();
+    };
+    change_admin(&mut app, "contract0", "someone");
 
+    // The existing denom of the bridge is adopted
     app.execute_contract(
         owner.clone(),
         bridge_address.clone(),
-        &ExecuteMsg::UpdateConfig {
-            bridge_ibc_channel: None,
-            ibc_timeout_seconds: None,
-        },
+        &link_token_msg("ADOPTED"),
         &[],
     )
     .unwrap();
 
+    let token: TokenInfo = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Token {
+                ticker: "ADOPTED".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(token.denom, "factory/contract0/ADOPTED");
+    assert_eq!(token.status, TokenStatus::Enabled);
+    assert!(failed_links(&app).is_empty());
+
+    // A denom administered by someone else can't be adopted, the link fails
+    // without aborting the transaction
     app.execute_contract(
         owner.clone(),
         bridge_address.clone(),
-        &ExecuteMsg::UpdateConfig {
-            bridge_ibc_channel: None,
-            ibc_timeout_seconds: None,
-        },
+        &link_token_msg("TAKEN"),
         &[],
     )
     .unwrap();
 
-    // Attempt blank ibc channel
-    let err = app
-        .execute_contract(
-            owner.clone(),
-            bridge_address.clone(),
-            &ExecuteMsg::UpdateConfig {
-                bridge_ibc_channel: Some("".to_string()),
-                ibc_timeout_seconds: None,
+    app.wrap()
+        .query_wasm_smart::<TokenInfo>(
+            &bridge_address,
+            &QueryMsg::Token {
+                ticker: "TAKEN".to_string(),
             },
-            &[],
         )
         .unwrap_err();
 
+    let links = failed_links(&app);
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].token.ticker, "TAKEN");
+    assert_eq!(links[0].source_chain_id, "localgaia-1");
     assert_eq!(
-        err.downcast::<ContractError>().unwrap(),
-        ContractError::InvalidConfiguration {
-            reason: "The bridge IBC channel must be specified".to_string()
+        links[0].reason,
+        TokenLinkFailure::DenomAlreadyExists {
+            denom: "factory/contract0/TAKEN".to_string()
         }
     );
 
+    let signers: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::LinkTokenSigners {
+                ticker: "TAKEN".to_string(),
+            },
+        )
+        .unwrap();
+    assert!(signers.is_empty());
+
+    // Only the adopted link counted the signers
+    let signer_stats = |app: &NeutronApp| {
+        app.wrap()
+            .query_wasm_smart::<QuerySignerStatsResponse>(
+                &bridge_address,
+                &QueryMsg::SignerStats {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap()
+            .stats
+            .into_iter()
+            .map(|(_, stats)| stats.attestations)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(signer_stats(&app), vec![1, 1]);
+
+    // Any other failure is recorded with its reason
+    let long_ticker = "L".repeat(45);
     app.execute_contract(
         owner.clone(),
         bridge_address.clone(),
-        &ExecuteMsg::UpdateConfig {
-            bridge_ibc_channel: Some("channel-9".to_string()),
-            ibc_timeout_seconds: None,
-        },
+        &link_token_msg(&long_ticker),
         &[],
     )
     .unwrap();
 
-    // Attempt invalid IBC timeout
-    let err = app
-        .execute_contract(
-            owner.clone(),
-            bridge_address.clone(),
-            &ExecuteMsg::UpdateConfig {
-                bridge_ibc_channel: None,
-                ibc_timeout_seconds: Some(MIN_IBC_TIMEOUT_SECONDS - 1),
-            },
-            &[],
-        )
-        .unwrap_err();
-
-    assert_eq!(
-        err.downcast::<ContractError>().unwrap(),
-        ContractError::InvalidIBCTimeout {
-            timeout: MIN_IBC_TIMEOUT_SECONDS - 1,
-            min: MIN_IBC_TIMEOUT_SECONDS,
-            max: MAX_IBC_TIMEOUT_SECONDS,
-        }
-    );
-
-    let err = app
-        .execute_contract(
-            owner.clone(),
-            bridge_address.clone(),
-            &ExecuteMsg::UpdateConfig {
-                bridge_ibc_channel: None,
-                ibc_timeout_seconds: Some(MAX_IBC_TIMEOUT_SECONDS + 1),
-            },
-            &[],
-        )
-        .unwrap_err();
-
-    assert_eq!(
-        err.downcast::<ContractError>().unwrap(),
-        ContractError::InvalidIBCTimeout {
-            timeout: MAX_IBC_TIMEOUT_SECONDS + 1,
-            min: MIN_IBC_TIMEOUT_SECONDS,
-            max: MAX_IBC_TIMEOUT_SECONDS,
-        }
-    );
-
+    let links = failed_links(&app);
+    assert_eq!(links.len(), 2);
+    assert_eq!(links[0].token.ticker, long_ticker);
+    assert!(matches!(
+        links[0].reason,
+        TokenLinkFailure::DenomCreationFailed { .. }
+    ));
+
+    // Once the bridge administers the denom again, linking it again adopts it
+    // and clears the failed link
+    change_admin(&mut app, "someone", "contract0");
     app.execute_contract(
         owner.clone(),
         bridge_address.clone(),
-        &ExecuteMsg::UpdateConfig {
-            bridge_ibc_channel: None,
-            ibc_timeout_seconds: Some(MIN_IBC_TIMEOUT_SECONDS + 1),
-        },
+        &link_token_msg("TAKEN"),
         &[],
     )
     .unwrap();
 
-    // Query to check all the new values were set
-    let response: Config = app
+    let token: TokenInfo = app
         .wrap()
-        .query_wasm_smart(&bridge_address, &QueryMsg::Config {})
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Token {
+                ticker: "TAKEN".to_string(),
+            },
+        )
         .unwrap();
+    assert_eq!(token.denom, "factory/contract0/TAKEN");
+    assert_eq!(signer_stats(&app), vec![2, 2]);
 
-    assert_eq!(response.bridge_chain_id, "localgaia-1");
-    assert_eq!(response.bridge_ibc_channel, "channel-9");
-    assert_eq!(response.ibc_timeout_seconds, MIN_IBC_TIMEOUT_SECONDS + 1);
+    let links = failed_links(&app);
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].token.ticker, long_ticker);
 }
 
 #[test]
@@ -621,13 +7160,18 @@ fn test_link_token() {
         )
         .unwrap();
 
+    let env = contract_env(&app, &bridge_address);
+
     // Add a valid signer
     app.execute_contract(
         owner.clone(),
         bridge_address.clone(),
         &ExecuteMsg::AddSigner {
             name: "signer1".to_string(),
-            public_key_base64: VALID_SIGNER_1.to_string(),
+            public_key_base64: public_key_base64(&signing_key(1)),
+            key_type: None,
+            weight: None,
+            contact_url: None,
         },
         &[],
     )
@@ -639,19 +7183,18 @@ fn test_link_token() {
         bridge_address.clone(),
         &ExecuteMsg::AddSigner {
             name: "signer2".to_string(),
-            public_key_base64: VALID_SIGNER_2.to_string(),
+            public_key_base64: public_key_base64(&signing_key(2)),
+            key_type: None,
+            weight: None,
+            contact_url: None,
         },
         &[],
     )
     .unwrap();
 
     // Signatures for TESTTOKEN with 6 decimals
-    let signature_1 =
-        "OU5aYIcdVHNVFNcg+MLT9uYVfkNHjTN8Pzg7lHmni5AuCC0ln78lJQnCRi8XxaPaxQYrm3TY+2+LeOU6H9j0DQ=="
-            .to_string();
-    let signature_2 =
-        "r3pfcIod2/49HHTOC+QRcVuccg2nOqSZsCNulv+McYFsEOPX7TN3PFscdVfavaGmb3mqdM6vF5italUVrJH3DA=="
-            .to_string();
+    let signature_1 = link_token_signature(&env, &signing_key(1));
+    let signature_2 = link_token_signature(&env, &signing_key(2));
 
     // Duplicate signatures
     let err = app
@@ -667,6 +7210,10 @@ fn test_link_token() {
                     decimals: 6,
                 },
                 signatures: vec![signature_1.clone(), signature_1.clone()],
+                signer_signatures: None,
+                adr036_signatures: None,
+                signer_epoch: None,
+                attestation_version: None,
             },
             &[],
         )
@@ -691,6 +7238,10 @@ fn test_link_token() {
                     decimals: 6,
                 },
                 signatures: vec![signature_1.clone(), signature_2.clone()],
+                signer_signatures: None,
+                adr036_signatures: None,
+                signer_epoch: None,
+                attestation_version: None,
             },
             &[],
         )
@@ -715,6 +7266,10 @@ fn test_link_token() {
                     decimals: 6,
                 },
                 signatures: vec![signature_1.clone()],
+                signer_signatures: None,
+                adr036_signatures: None,
+                signer_epoch: None,
+                attestation_version: None,
             },
             &[],
         )
@@ -725,6 +7280,88 @@ fn test_link_token() {
         ContractError::ThresholdNotMet {}
     );
 
+    // The v2 signatures don't cover a different name or image, nor the same
+    // bytes split differently between the ticker and the name
+    let v2_attestation = Attestation::link_token(
+        &env,
+        "localgaia-1",
+        &TokenMetadata {
+            ticker: "TESTTOKEN".to_string(),
+            name: "TestToken".to_string(),
+            image_url: "https://example.com".to_string(),
+            decimals: 6,
+        },
+    )
+    .encode(AttestationVersion::V2);
+    let v2_signatures = vec![
+        sign_attestation(&signing_key(1), &v2_attestation),
+        sign_attestation(&signing_key(2), &v2_attestation),
+    ];
+    for (token, attestation_version, signatures) in [
+        (
+            TokenMetadata {
+                ticker: "TESTTOKEN".to_string(),
+                name: "FakeToken".to_string(),
+                image_url: "https://example.com".to_string(),
+                decimals: 6,
+            },
+            AttestationVersion::V2,
+            &v2_signatures,
+        ),
+        (
+            TokenMetadata {
+                ticker: "TESTTOKEN".to_string(),
+                name: "TestToken".to_string(),
+                image_url: "https://fake.example.com".to_string(),
+                decimals: 6,
+            },
+            AttestationVersion::V2,
+            &v2_signatures,
+        ),
+        (
+            TokenMetadata {
+                ticker: "TESTTOKENT".to_string(),
+                name: "estToken".to_string(),
+                image_url: "https://example.com".to_string(),
+                decimals: 6,
+            },
+            AttestationVersion::V2,
+            &v2_signatures,
+        ),
+        (
+            TokenMetadata {
+                ticker: "TESTTOKENT".to_string(),
+                name: "estToken".to_string(),
+                image_url: "https://example.com".to_string(),
+                decimals: 6,
+            },
+            AttestationVersion::V1,
+            &vec![signature_1.clone(), signature_2.clone()],
+        ),
+    ] {
+        let err = app
+            .execute_contract(
+                owner.clone(),
+                bridge_address.clone(),
+                &ExecuteMsg::LinkToken {
+                    source_chain_id: "localgaia-1".to_string(),
+                    token,
+                    signatures: signatures.clone(),
+                    signer_signatures: None,
+                    adr036_signatures: None,
+                    signer_epoch: None,
+                    attestation_version: Some(attestation_version),
+                },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast::<ContractError>().unwrap(),
+            ContractError::ThresholdNotMet {}
+        );
+    }
+
     // No signatures
     let err = app
         .execute_contract(
@@ -739,6 +7376,10 @@ fn test_link_token() {
                     decimals: 6,
                 },
                 signatures: vec![],
+                signer_signatures: None,
+                adr036_signatures: None,
+                signer_epoch: None,
+                attestation_version: None,
             },
             &[],
         )
@@ -762,6 +7403,10 @@ fn test_link_token() {
                 decimals: 6,
             },
             signatures: vec![signature_1.clone(), signature_2.clone()],
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
         },
         &[],
     )
@@ -780,10 +7425,55 @@ fn test_link_token() {
         )
         .unwrap();
 
-    // We should have two tokens listed in this response as we add a mapping for
-    // CFT-20 <> TokenFactory and TokenFactory <> CFT-20
-    assert_eq!(response.tokens.len(), 2);
-    assert_eq!(response.tokens[0], "TESTTOKEN");
+    // Only the CFT-20 ticker is listed, the denom is part of its record
+    assert_eq!(response.tokens, vec!["TESTTOKEN".to_string()]);
+
+    let token: TokenInfo = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::Token {
+                ticker: "TESTTOKEN".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        token,
+        TokenInfo {
+            metadata: TokenMetadata {
+                ticker: "TESTTOKEN".to_string(),
+                name: "TestToken".to_string(),
+                image_url: "https://example.com".to_string(),
+                decimals: 6,
+            },
+            denom: "factory/contract0/TESTTOKEN".to_string(),
+            source_chain_id: "localgaia-1".to_string(),
+            link_height: app.block_info().height,
+            status: TokenStatus::Enabled,
+        }
+    );
+
+    // The same record is found from the TokenFactory denom
+    let response: TokenInfo = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::TokenByDenom {
+                denom: "factory/contract0/TESTTOKEN".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(response, token);
+
+    // Tickers aren't denoms
+    app.wrap()
+        .query_wasm_smart::<TokenInfo>(
+            &bridge_address,
+            &QueryMsg::TokenByDenom {
+                denom: "TESTTOKEN".to_string(),
+            },
+        )
+        .unwrap_err();
 
     // Attempt to add a duplicate
     let err = app
@@ -799,6 +7489,10 @@ fn test_link_token() {
                     decimals: 6,
                 },
                 signatures: vec![signature_1, signature_2],
+                signer_signatures: None,
+                adr036_signatures: None,
+                signer_epoch: None,
+                attestation_version: None,
             },
             &[],
         )
@@ -835,13 +7529,18 @@ fn test_enable_disable_token() {
         )
         .unwrap();
 
+    let env = contract_env(&app, &bridge_address);
+
     // Add a valid signer
     app.execute_contract(
         owner.clone(),
         bridge_address.clone(),
         &ExecuteMsg::AddSigner {
             name: "signer1".to_string(),
-            public_key_base64: VALID_SIGNER_1.to_string(),
+            public_key_base64: public_key_base64(&signing_key(1)),
+            key_type: None,
+            weight: None,
+            contact_url: None,
         },
         &[],
     )
@@ -853,19 +7552,18 @@ fn test_enable_disable_token() {
         bridge_address.clone(),
         &ExecuteMsg::AddSigner {
             name: "signer2".to_string(),
-            public_key_base64: VALID_SIGNER_2.to_string(),
+            public_key_base64: public_key_base64(&signing_key(2)),
+            key_type: None,
+            weight: None,
+            contact_url: None,
         },
         &[],
     )
     .unwrap();
 
     // Signatures for TESTTOKEN with 6 decimals
-    let signature_1 =
-        "OU5aYIcdVHNVFNcg+MLT9uYVfkNHjTN8Pzg7lHmni5AuCC0ln78lJQnCRi8XxaPaxQYrm3TY+2+LeOU6H9j0DQ=="
-            .to_string();
-    let signature_2 =
-        "r3pfcIod2/49HHTOC+QRcVuccg2nOqSZsCNulv+McYFsEOPX7TN3PFscdVfavaGmb3mqdM6vF5italUVrJH3DA=="
-            .to_string();
+    let signature_1 = link_token_signature(&env, &signing_key(1));
+    let signature_2 = link_token_signature(&env, &signing_key(2));
 
     // Valid signatures
     app.execute_contract(
@@ -880,6 +7578,10 @@ fn test_enable_disable_token() {
                 decimals: 6,
             },
             signatures: vec![signature_1.clone(), signature_2.clone()],
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
         },
         &[],
     )
@@ -898,10 +7600,8 @@ fn test_enable_disable_token() {
         )
         .unwrap();
 
-    // We should have two tokens listed in this response as we add a mapping for
-    // CFT-20 <> TokenFactory and TokenFactory <> CFT-20
-    assert_eq!(response.tokens.len(), 2);
-    assert_eq!(response.tokens[0], "TESTTOKEN");
+    // Only the CFT-20 ticker is listed
+    assert_eq!(response.tokens, vec!["TESTTOKEN".to_string()]);
 
     // Enable a token that wasn't disabled
     let err = app
@@ -981,8 +7681,19 @@ fn test_enable_disable_token() {
         )
         .unwrap();
 
-    // Ensure both sides are disabled, making the count 2
-    assert_eq!(response.tokens.len(), 2);
+    assert_eq!(response.tokens, vec!["TESTTOKEN".to_string()]);
+
+    // The status covers the TokenFactory denom as well
+    let token: TokenInfo = app
+        .wrap()
+        .query_wasm_smart(
+            &bridge_address,
+            &QueryMsg::TokenByDenom {
+                denom: "factory/contract0/TESTTOKEN".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(token.status, TokenStatus::Disabled);
 
     // Disable a disabled token
     let err = app
@@ -1070,13 +7781,18 @@ fn test_bridge_receive() {
         )
         .unwrap();
 
+    let env = contract_env(&app, &bridge_address);
+
     // Add a valid signer
     app.execute_contract(
         owner.clone(),
         bridge_address.clone(),
         &ExecuteMsg::AddSigner {
             name: "signer1".to_string(),
-            public_key_base64: VALID_SIGNER_1.to_string(),
+            public_key_base64: public_key_base64(&signing_key(1)),
+            key_type: None,
+            weight: None,
+            contact_url: None,
         },
         &[],
     )
@@ -1088,7 +7804,10 @@ fn test_bridge_receive() {
         bridge_address.clone(),
         &ExecuteMsg::AddSigner {
             name: "signer2".to_string(),
-            public_key_base64: VALID_SIGNER_2.to_string(),
+            public_key_base64: public_key_base64(&signing_key(2)),
+            key_type: None,
+            weight: None,
+            contact_url: None,
         },
         &[],
     )
@@ -1106,9 +7825,15 @@ fn test_bridge_receive() {
                 amount: Uint128::from(1000u64),
                 destination_addr: "user1".to_string(),
                 signatures: vec![
-                    BRIDGE_SIGNATURE_1.to_string().to_string().clone(),
-                    BRIDGE_SIGNATURE_2.to_string().to_string().clone(),
+                    bridge_signature(&env, &signing_key(1)),
+                    bridge_signature(&env, &signing_key(2)),
                 ],
+                signer_signatures: None,
+                adr036_signatures: None,
+                signer_epoch: None,
+                attestation_version: None,
+                token_signatures: None,
+                expires_at: None,
             },
             &[],
         )
@@ -1134,9 +7859,13 @@ fn test_bridge_receive() {
                 decimals: 6,
             },
             signatures: vec![
-                SIGNATURE_1.to_string().clone(),
-                SIGNATURE_2.to_string().clone(),
+                link_token_signature(&env, &signing_key(1)),
+                link_token_signature(&env, &signing_key(2)),
             ],
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
         },
         &[],
     )
@@ -1155,10 +7884,8 @@ fn test_bridge_receive() {
         )
         .unwrap();
 
-    // We should have two tokens listed in this response as we add a mapping for
-    // CFT-20 <> TokenFactory and TokenFactory <> CFT-20
-    assert_eq!(response.tokens.len(), 2);
-    assert_eq!(response.tokens[0], "TESTTOKEN");
+    // Only the CFT-20 ticker is listed
+    assert_eq!(response.tokens, vec!["TESTTOKEN".to_string()]);
 
     // Receive token with no signatures
     let err = app
@@ -1172,6 +7899,12 @@ fn test_bridge_receive() {
                 amount: Uint128::from(1000u64),
                 destination_addr: "user1".to_string(),
                 signatures: vec![],
+                signer_signatures: None,
+                adr036_signatures: None,
+                signer_epoch: None,
+                attestation_version: None,
+                token_signatures: None,
+                expires_at: None,
             },
             &[],
         )
@@ -1194,9 +7927,15 @@ fn test_bridge_receive() {
                 amount: Uint128::from(10000u64),
                 destination_addr: "user1".to_string(),
                 signatures: vec![
-                    BRIDGE_SIGNATURE_1.to_string().clone(),
-                    BRIDGE_SIGNATURE_2.to_string().to_string().clone(),
+                    bridge_signature(&env, &signing_key(1)),
+                    bridge_signature(&env, &signing_key(2)),
                 ],
+                signer_signatures: None,
+                adr036_signatures: None,
+                signer_epoch: None,
+                attestation_version: None,
+                token_signatures: None,
+                expires_at: None,
             },
             &[],
         )
@@ -1219,9 +7958,15 @@ fn test_bridge_receive() {
                 amount: Uint128::from(0u64),
                 destination_addr: "user1".to_string(),
                 signatures: vec![
-                    BRIDGE_SIGNATURE_1.to_string().clone(),
-                    BRIDGE_SIGNATURE_2.to_string().clone(),
+                    bridge_signature(&env, &signing_key(1)),
+                    bridge_signature(&env, &signing_key(2)),
                 ],
+                signer_signatures: None,
+                adr036_signatures: None,
+                signer_epoch: None,
+                attestation_version: None,
+                token_signatures: None,
+                expires_at: None,
             },
             &[],
         )
@@ -1236,12 +7981,45 @@ fn test_bridge_receive() {
     let res = app.wrap().query_all_balances("user1").unwrap();
     assert_eq!(res.len(), 0);
 
-    // Remove a signer to trigger a threshold too low
+    // A signer can't be removed when the others can't meet the threshold
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            bridge_address.clone(),
+            &ExecuteMsg::RemoveSigner {
+                public_key_base64: public_key_base64(&signing_key(2)),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidConfiguration {
+            reason: "The signer threshold can't be met by the current signers".to_string()
+        }
+    );
+
+    // Remove a signer once another one took its place, its signatures no
+    // longer count toward the threshold
+    app.execute_contract(
+        owner.clone(),
+        bridge_address.clone(),
+        &ExecuteMsg::AddSigner {
+            name: "signer3".to_string(),
+            public_key_base64: public_key_base64(&signing_key(3)),
+            key_type: None,
+            weight: None,
+            contact_url: None,
+        },
+        &[],
+    )
+    .unwrap();
     app.execute_contract(
         owner.clone(),
         bridge_address.clone(),
         &ExecuteMsg::RemoveSigner {
-            public_key_base64: VALID_SIGNER_2.to_string(),
+            public_key_base64: public_key_base64(&signing_key(2)),
         },
         &[],
     )
@@ -1259,9 +8037,15 @@ fn test_bridge_receive() {
                 amount: Uint128::from(1000u64),
                 destination_addr: "user1".to_string(),
                 signatures: vec![
-                    BRIDGE_SIGNATURE_1.to_string().clone(),
-                    BRIDGE_SIGNATURE_2.to_string().clone(),
+                    bridge_signature(&env, &signing_key(1)),
+                    bridge_signature(&env, &signing_key(2)),
                 ],
+                signer_signatures: None,
+                adr036_signatures: None,
+                signer_epoch: None,
+                attestation_version: None,
+                token_signatures: None,
+                expires_at: None,
             },
             &[],
         )
@@ -1278,7 +8062,10 @@ fn test_bridge_receive() {
         bridge_address.clone(),
         &ExecuteMsg::AddSigner {
             name: "signer2".to_string(),
-            public_key_base64: VALID_SIGNER_2.to_string(),
+            public_key_base64: public_key_base64(&signing_key(2)),
+            key_type: None,
+            weight: None,
+            contact_url: None,
         },
         &[],
     )
@@ -1295,9 +8082,15 @@ fn test_bridge_receive() {
             amount: Uint128::from(1000u64),
             destination_addr: "user1".to_string(),
             signatures: vec![
-                BRIDGE_SIGNATURE_1.to_string().clone(),
-                BRIDGE_SIGNATURE_2.to_string().clone(),
+                bridge_signature(&env, &signing_key(1)),
+                bridge_signature(&env, &signing_key(2)),
             ],
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
+            token_signatures: None,
+            expires_at: None,
         },
         &[],
     )
@@ -1323,9 +8116,15 @@ fn test_bridge_receive() {
                 amount: Uint128::from(1000u64),
                 destination_addr: "user1".to_string(),
                 signatures: vec![
-                    BRIDGE_SIGNATURE_1.to_string().clone(),
-                    BRIDGE_SIGNATURE_2.to_string().clone(),
+                    bridge_signature(&env, &signing_key(1)),
+                    bridge_signature(&env, &signing_key(2)),
                 ],
+                signer_signatures: None,
+                adr036_signatures: None,
+                signer_epoch: None,
+                attestation_version: None,
+                token_signatures: None,
+                expires_at: None,
             },
             &[],
         )
@@ -1350,9 +8149,15 @@ fn test_bridge_receive() {
                 amount: Uint128::from(1000u64),
                 destination_addr: "".to_string(),
                 signatures: vec![
-                    BRIDGE_SIGNATURE_1.to_string().clone(),
-                    BRIDGE_SIGNATURE_2.to_string().clone(),
+                    bridge_signature(&env, &signing_key(1)),
+                    bridge_signature(&env, &signing_key(2)),
                 ],
+                signer_signatures: None,
+                adr036_signatures: None,
+                signer_epoch: None,
+                attestation_version: None,
+                token_signatures: None,
+                expires_at: None,
             },
             &[],
         )
@@ -1386,9 +8191,15 @@ fn test_bridge_receive() {
                 amount: Uint128::from(1000u64),
                 destination_addr: "user1".to_string(),
                 signatures: vec![
-                    BRIDGE_SIGNATURE_1.to_string().clone(),
-                    BRIDGE_SIGNATURE_2.to_string().clone(),
+                    bridge_signature(&env, &signing_key(1)),
+                    bridge_signature(&env, &signing_key(2)),
                 ],
+                signer_signatures: None,
+                adr036_signatures: None,
+                signer_epoch: None,
+                attestation_version: None,
+                token_signatures: None,
+                expires_at: None,
             },
             &[],
         )
@@ -1440,13 +8251,18 @@ fn test_bridge_send() {
         )
         .unwrap();
 
+    let env = contract_env(&app, &bridge_address);
+
     // Add a valid signer
     app.execute_contract(
         owner.clone(),
         bridge_address.clone(),
         &ExecuteMsg::AddSigner {
             name: "signer1".to_string(),
-            public_key_base64: VALID_SIGNER_1.to_string(),
+            public_key_base64: public_key_base64(&signing_key(1)),
+            key_type: None,
+            weight: None,
+            contact_url: None,
         },
         &[],
     )
@@ -1458,7 +8274,10 @@ fn test_bridge_send() {
         bridge_address.clone(),
         &ExecuteMsg::AddSigner {
             name: "signer2".to_string(),
-            public_key_base64: VALID_SIGNER_2.to_string(),
+            public_key_base64: public_key_base64(&signing_key(2)),
+            key_type: None,
+            weight: None,
+            contact_url: None,
         },
         &[],
     )
@@ -1477,9 +8296,13 @@ fn test_bridge_send() {
                 decimals: 6,
             },
             signatures: vec![
-                SIGNATURE_1.to_string().clone(),
-                SIGNATURE_2.to_string().clone(),
+                link_token_signature(&env, &signing_key(1)),
+                link_token_signature(&env, &signing_key(2)),
             ],
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
         },
         &[],
     )
@@ -1496,9 +8319,15 @@ fn test_bridge_send() {
             amount: Uint128::from(1000u64),
             destination_addr: "user1".to_string(),
             signatures: vec![
-                BRIDGE_SIGNATURE_1.to_string().clone(),
-                BRIDGE_SIGNATURE_2.to_string().clone(),
+                bridge_signature(&env, &signing_key(1)),
+                bridge_signature(&env, &signing_key(2)),
             ],
+            signer_signatures: None,
+            adr036_signatures: None,
+            signer_epoch: None,
+            attestation_version: None,
+            token_signatures: None,
+            expires_at: None,
         },
         &[],
     )
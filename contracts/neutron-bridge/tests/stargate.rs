@@ -13,10 +13,20 @@ use cw_multi_test::{
 
 use anyhow::{Ok, Result as AnyResult};
 use osmosis_std::types::osmosis::tokenfactory::v1beta1::{
-    MsgBurn, MsgCreateDenom, MsgCreateDenomResponse, MsgMint, MsgSetBeforeSendHook,
-    MsgSetDenomMetadata, MsgSetDenomMetadataResponse,
+    DenomAuthorityMetadata, MsgBurn, MsgChangeAdmin, MsgChangeAdminResponse, MsgCreateDenom,
+    MsgCreateDenomResponse, MsgMint, MsgSetBeforeSendHook, MsgSetDenomMetadata,
+    MsgSetDenomMetadataResponse, QueryDenomAuthorityMetadataRequest,
+    QueryDenomAuthorityMetadataResponse,
 };
 
+/// The maximum length of a TokenFactory subdenom
+const MAX_SUBDENOM_LENGTH: usize = 44;
+
+/// The storage key of the admin of a TokenFactory denom
+fn denom_admin_key(denom: &str) -> Vec<u8> {
+    format!("tokenfactory_denom_admin/{}", denom).into_bytes()
+}
+
 pub type StargateApp<ExecC = Empty, QueryC = Empty> = App<
     BankKeeper,
     MockApi,
@@ -152,17 +162,33 @@ impl Module for MockStargate {
             MsgCreateDenom::TYPE_URL => {
                 let tf_msg: MsgCreateDenom = value.try_into()?;
                 let sender_address = tf_msg.sender.to_string();
+                if tf_msg.subdenom.len() > MAX_SUBDENOM_LENGTH {
+                    return Err(anyhow::anyhow!("subdenom too long"));
+                }
+                let new_token_denom = format!("factory/{}/{}", sender_address, tf_msg.subdenom);
+                if storage.get(&denom_admin_key(&new_token_denom)).is_some() {
+                    return Err(anyhow::anyhow!("denom already exists"));
+                }
+                storage.set(
+                    &denom_admin_key(&new_token_denom),
+                    sender_address.as_bytes(),
+                );
                 let submsg_response = SubMsgResponse {
                     events: vec![],
-                    data: Some(
-                        MsgCreateDenomResponse {
-                            new_token_denom: format!(
-                                "factory/{}/{}",
-                                sender_address, tf_msg.subdenom
-                            ),
-                        }
-                        .into(),
-                    ),
+                    data: Some(MsgCreateDenomResponse { new_token_denom }.into()),
+                };
+                Ok(submsg_response.into())
+            }
+            MsgChangeAdmin::TYPE_URL => {
+                let tf_msg: MsgChangeAdmin = value.try_into()?;
+                let admin = storage.get(&denom_admin_key(&tf_msg.denom));
+                if admin.as_deref() != Some(tf_msg.sender.as_bytes()) {
+                    return Err(anyhow::anyhow!("unauthorized account"));
+                }
+                storage.set(&denom_admin_key(&tf_msg.denom), tf_msg.new_admin.as_bytes());
+                let submsg_response = SubMsgResponse {
+                    events: vec![],
+                    data: Some(MsgChangeAdminResponse {}.into()),
                 };
                 Ok(submsg_response.into())
             }
@@ -221,12 +247,25 @@ impl Module for MockStargate {
     fn query(
         &self,
         _api: &dyn Api,
-        _storage: &dyn Storage,
+        storage: &dyn Storage,
         _querier: &dyn Querier,
         _block: &BlockInfo,
-        _request: Self::QueryT,
+        request: Self::QueryT,
     ) -> AnyResult<Binary> {
-        Ok(Binary::default())
+        match request.path.as_str() {
+            "/osmosis.tokenfactory.v1beta1.Query/DenomAuthorityMetadata" => {
+                let request: QueryDenomAuthorityMetadataRequest = request.data.try_into()?;
+                let admin = storage
+                    .get(&denom_admin_key(&request.denom))
+                    .ok_or_else(|| anyhow::anyhow!("denom does not exist"))?;
+                Ok(to_json_binary(&QueryDenomAuthorityMetadataResponse {
+                    authority_metadata: Some(DenomAuthorityMetadata {
+                        admin: String::from_utf8(admin)?,
+                    }),
+                })?)
+            }
+            _ => Ok(Binary::default()),
+        }
     }
     fn sudo<ExecC, QueryC>(
         &self,